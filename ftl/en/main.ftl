@@ -42,6 +42,7 @@ Identifier = Identifier
 Theoretical = Theoretical
 EnrichmentFactor = Enrichment factor
     .abbreviation = EF
+    .hover = Ratio of the SN-2 and SN-1,2,3 values, the fatty acids above 1 prefer the SN-2 position.
 SelectivityFactor = Selectivity factor
     .abbreviation = SF
 
@@ -57,6 +58,14 @@ StickyColumns = Sticky
     .hover = Number of sticky columns.
 TruncateHeaders = Truncate
     .hover = Truncate header text.
+Plot = Plot
+    .hover = Show as plot.
+ShowLegend = Legend
+    .hover = Show plot legend.
+AllowDrag = Drag
+    .hover = Allow dragging the plot along the axes.
+AllowScroll = Scroll
+    .hover = Allow scrolling the plot along the axes.
 DeltaDegreesOfFreedom = Delta Degrees of Freedom
     .abbreviation = DDOF
     .hover = The divisor used in calculations is N - DDOF, where N represents the number of elements. By default DDOF is zero.
@@ -130,6 +139,24 @@ Composition_EquivalentCarbonNumber_Monospecific = ECN, mono
 Composition_EquivalentCarbonNumber_Stereospecific = ECN, stereo
     .abbreviation = SNC
     .hover = Equivalent carbon number stereospecific composition.
+Composition_CarbonNumber_Monospecific = CN, mono
+    .abbreviation = MCNC
+    .hover = Total carbon number non-stereospecific composition (agregation).
+Composition_CarbonNumber_Positionalspecific = CN, positional
+    .abbreviation = PCNC
+    .hover = Carbon number positional-specific composition (agregation).
+Composition_CarbonNumber_Stereospecific = CN, stereo
+    .abbreviation = SCNC
+    .hover = Carbon number stereospecific composition.
+Composition_PartitionNumber_Monospecific = PN, mono
+    .abbreviation = MPNC
+    .hover = Partition number (PN = CN - 2·DB) non-stereospecific composition (agregation). Lower partition numbers elute first in reversed-phase HPLC.
+Composition_PartitionNumber_Positionalspecific = PN, positional
+    .abbreviation = PPNC
+    .hover = Partition number (PN = CN - 2·DB) positional-specific composition (agregation). Lower partition numbers elute first in reversed-phase HPLC.
+Composition_PartitionNumber_Stereospecific = PN, stereo
+    .abbreviation = SPNC
+    .hover = Partition number (PN = CN - 2·DB) stereospecific composition. Lower partition numbers elute first in reversed-phase HPLC.
 Composition_Species_Monospecific = Species, mono
     .abbreviation = MSC
     .hover = Species non-stereospecific composition (permutation).
@@ -198,6 +225,24 @@ SpearmanRank = Spearman
     .hover = Spearman rank correlation.
 Chaddock = Chaddock
     .hover = Chaddock scale.
+Collection = Collection
+    .hover = Correlate across the samples of the collection. Replicates (`[1]`, `[2]`, ... in the name) are averaged, fatty acids are also correlated with the numeric metadata variables and the treatments (`{"{"}NaCl;SN-2{"}"}` in the name).
+Correction = Correction
+    .hover = Multiple testing correction of the correlation p-values.
+Correction-None = None
+    .hover = No correction, q-values are equal to p-values.
+Correction-Bonferroni = Bonferroni
+    .hover = Bonferroni correction, controls the family-wise error rate.
+Correction-BenjaminiHochberg = Benjamini–Hochberg
+    .hover = Benjamini–Hochberg correction, controls the false discovery rate.
+SignificanceLevel = Significance level
+    .hover = Significance level (α) the q-values are compared with.
+Significance = Significance
+    .hover = Color only the correlations, which are significant at the significance level.
+PValue = p
+    .hover = Two-sided p-value of the correlation (Student's t-test with n - 2 degrees of freedom).
+QValue = q
+    .hover = P-value corrected for multiple testing.
 
 Threshold = Threshold
     .hover = Values greater than or equal to the threshold value are major, values less than the threshold value are minor.
@@ -222,9 +267,205 @@ Sample = Sample
     .hover = Sample.
 Symmetry = Symmetry
     .hover = Symmetry.
+Quality = Quality
+    .hover = Oil quality parameters derived from the triacylglycerol species composition.
+AverageMolecularWeight = Average molecular weight
+    .hover = Average triacylglycerol molecular weight, g/mol.
+SaponificationValue = Saponification value
+    .hover = Saponification value, mg KOH/g.
+EsterValue = Ester value
+    .hover = Ester value (saponification value minus acid value), mg KOH/g. The composition has no free fatty acids, so it equals the saponification value.
+MeanChainLength = Mean chain length
+    .hover = Mean fatty acid carbon number.
+MeanUnsaturation = Mean unsaturation
+    .hover = Mean number of double bounds per triacylglycerol.
 Group = Group
     .hover = Group.
 Value = Value
     .hover = Value.
 Species = Species
     .hover = Species.
+Comparison = Comparison
+    .hover = Compare predicted composition with measured triacylglycerol composition.
+    .empty = Drop a measured triacylglycerol composition file to compare.
+Detach = Detach
+    .hover = Detach measured triacylglycerol composition.
+MeasuredComposition-NoCompositionPane = No composition pane is open. The measured triacylglycerol composition is attached to the first composition pane opened, close this window to discard it.
+Predicted = Predicted
+    .hover = Predicted value.
+Measured = Measured
+    .hover = Measured value.
+Residual = Residual
+    .hover = Measured minus predicted value.
+RootMeanSquareError = RMSE
+    .hover = Root mean square error of residuals.
+Fit = Fit
+    .hover = Fit the sn-2 selectivity of the species model to measured triacylglycerol composition by least squares.
+Selectivity = Selectivity
+    .hover = Fitted sn-2 selectivity ± standard error: 0 is 1,2,3-random, 1 is 1,3-random-2-random distribution.
+CoefficientOfDetermination = R²
+    .hover = Coefficient of determination.
+MassSpectrometry = Mass spectrometry
+    .hover = Adduct ion m/z values, diacylglycerol-like fragment ions and isotopic envelope of triacylglycerol species.
+Ion = Ion
+    .hover = Ion used for the isotopic envelope.
+Ion-Protonated = [M+H]+
+    .hover = Protonated molecule.
+Ion-Ammoniated = [M+NH4]+
+    .hover = Ammonium adduct.
+Ion-Sodiated = [M+Na]+
+    .hover = Sodium adduct.
+Class = Class
+    .hover = Triacylglycerol class.
+Class-Symmetry = Symmetry
+    .hover = Triacylglycerol classes by the identity of the fatty acids in sn-1, sn-2 and sn-3 positions.
+Class-Saturation = Saturation
+    .hover = Triacylglycerol classes by saturated (S) and unsaturated (U) fatty acids in sn-1, sn-2 and sn-3 positions.
+Class-Omega = ω-family
+    .hover = Triacylglycerol classes by saturated (S), ω-3, ω-6, ω-9 and other unsaturated (U) fatty acids in sn-1, sn-2 and sn-3 positions.
+Fragment = Fragment
+    .hover = Diacylglycerol-like fragment ion [M+NH4-RCOOH-NH3]+ after the neutral loss of the fatty acid at the stereospecific number.
+Isotope = Isotope
+    .hover = Isotopic peak abundance relative to the monoisotopic peak, %.
+Parity = Parity
+    .hover = Measured against predicted values, the line is y = x.
+Key = Key
+Coverage = Coverage
+    .hover = The smallest set of species accounting for the level of the total.
+Level = Level
+    .hover = Part of the total accounted for by the species.
+Count = Count
+    .hover = Number of species accounting for the level.
+Cumulative = Cumulative
+    .hover = Cumulative sum of the values in descending order.
+Pareto = Pareto
+    .hover = Values in descending order and their cumulative sum.
+Covered = Covered
+Rest = Rest
+Rank = Rank
+Diff = Diff
+    .hover = Difference between two compositions, two samples or the same sample calculated by two methods.
+Left = Left
+    .hover = Left composition (A).
+Right = Right
+    .hover = Right composition (B).
+Difference = Difference
+    .hover = Absolute difference, B - A.
+RelativeDifference = Relative difference
+    .hover = Relative difference, (B - A) / A.
+LogRatio = Log ratio
+    .hover = Fold change, log₂(B / A).
+Only = Only
+    .hover = Composition in which only the species are above threshold.
+Stereospecific = Add or remove the SN-1 column of a stereospecific analysis. SN-3 is calculated by difference, 2·SN-1,3 - SN-1, without clipping the negative values.
+PrincipalComponentAnalysis = Principal component analysis
+    .hover = Principal component analysis of the fatty acid profiles of the samples.
+Variables = Variables
+    .hover = Stereospecific numbers used as variables, all three are concatenated.
+All = All
+Center = Center
+    .hover = Subtract the mean of each variable.
+Scale = Scale
+    .hover = Divide each variable by its standard deviation (correlation matrix).
+Stem = Stem
+    .hover = Species, cultivar, tissue and treatment fields or, without them, the name without the replicate number.
+Component = Component
+Explained = Explained
+    .hover = Explained variance.
+Scree = Scree
+    .hover = Explained variance of the principal components.
+Scores = Scores
+    .hover = Samples in the principal components space, colored by group.
+Loadings = Loadings
+    .hover = Contributions of the variables (fatty acids) to the principal components.
+HierarchicalClustering = Hierarchical clustering
+    .hover = Clustering of the samples and the fatty acids by their composition.
+Distance = Distance
+    .hover = Distance between two samples (or two fatty acids).
+Distance-Euclidean = Euclidean
+    .hover = Square root of the sum of the squared differences.
+Distance-BrayCurtis = Bray–Curtis
+    .hover = Sum of the absolute differences divided by the sum of the values.
+Distance-Correlation = 1 − r
+    .hover = One minus the Pearson correlation coefficient.
+Linkage = Linkage
+    .hover = Distance between two clusters.
+Linkage-Single = Single
+    .hover = Distance between the nearest members.
+Linkage-Complete = Complete
+    .hover = Distance between the farthest members.
+Linkage-Average = Average
+    .hover = Mean distance between the members (UPGMA).
+Linkage-Ward = Ward
+    .hover = Minimum increase of the within-cluster variance.
+SortByClustering = Sort by clustering
+    .hover = Order the rows of the calculation and correlations tables by the fatty acid dendrogram.
+PositionalDistribution = Positional distribution
+    .hover = Stereospecific numbers of each fatty acid, mean ± standard deviation.
+ShowSamples = Samples
+    .hover = Show the values of each sample over the bars.
+OverlaySamples = Overlay samples
+    .hover = Show the bars of each sample with its own color and legend entry.
+Chart-Radar = Radar
+    .hover = Indices of each sample, normalized by the maximum across the samples.
+Chart-Bars = Bars
+    .hover = Indices grouped by sample, normalized by the maximum across the samples.
+FactorAnalysis = Factor analysis
+    .hover = Positional preference of the fatty acids by the enrichment and selectivity factors.
+Band = Band
+    .hover = Relative band around the neutral enrichment factor (1, or 1/3 if normalized), inside of which the position is random.
+Axis-Carbon = Carbon
+    .hover = Selectivity factor against the chain length.
+Axis-Unsaturation = Unsaturation
+    .hover = Selectivity factor against the number of the double bounds.
+Preference = Preference
+    .hover = Preference of the fatty acid for the SN-2 position.
+Preference-Preferred = Preferred
+    .hover = Enrichment factor above the band, the fatty acid is enriched in the SN-2 position.
+Preference-Random = Random
+    .hover = Enrichment factor inside the band, the fatty acid is distributed randomly.
+Preference-Excluded = Excluded
+    .hover = Enrichment factor below the band, the fatty acid is depleted in the SN-2 position.
+ReferenceRange = Reference range
+    .hover = Literature factor range of the same fatty acid in the plant oils, highlighted if the value is out of it. Hover a range for its source.
+Series = Series
+    .hover = Fatty acids and indices over the ordered samples (days, concentrations) with their trends.
+Variable = Variable
+    .hover = Ordering variable of the samples.
+Treatment = Treatment
+    .hover = First numeric treatment of the name, e.g. 3 of {"{"}Control;3day{"}"}.
+Unordered = Unordered
+    .hover = Number of the samples without the ordering value, they are not in the series.
+NoSeries = At least two levels of the ordering variable are required
+    .hover = Choose the metadata variable or name the samples with the numeric treatments.
+Slope = Slope
+    .hover = Least squares slope over the ordering variable.
+MannKendall = Mann–Kendall
+    .hover = Mann–Kendall trend test of the means, Kendall's τ and the two-sided p-value (bold below α).
+Design = Design
+    .hover = Experimental design fields of the sample, used for the grouping.
+Cultivar = Cultivar
+Tissue = Tissue
+Replicate = Replicate
+Instrument = Instrument
+Batch = Batch
+FillFromName = Fill from the name
+    .hover = Fill the empty fields from the name, e.g. C-108{"{"}-N{"}"}[3] is the species C-108, the treatment -N and the replicate 3.
+GroupAndCalculate = Group and calculate
+    .hover = Partition the selected frames into the groups of replicates and open one calculation pane per group.
+Undo = Undo
+    .hover = Undo the last change (Ctrl+Z).
+Redo = Redo
+    .hover = Redo the last undone change (Ctrl+Shift+Z).
+History = History
+    .hover = Changes of the tables, click to undo (or redo) up to the change.
+Change-AddRow = Add row
+    .hover = Row is added.
+Change-ClearTable = Clear table
+    .hover = All rows of the table are deleted.
+Change-DeleteRow = Delete row
+    .hover = Row is deleted.
+Change-DeleteTable = Delete table
+    .hover = Table is deleted.
+Change-Edit = Edit
+    .hover = Values, labels, fatty acids, order of the rows or metadata are changed.
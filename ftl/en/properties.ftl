@@ -52,6 +52,19 @@ UnsaturationIndex = Unsaturation Index
     .abbreviation = UI
     .hover = Unsaturation Index.
 
+AllylicPositionEquivalent = Allylic position equivalent
+    .abbreviation = APE
+    .hover = Allylic position equivalent.
+BisAllylicPositionEquivalent = Bis-allylic position equivalent
+    .abbreviation = BAPE
+    .hover = Bis-allylic position equivalent.
+PeroxidizabilityIndex = Peroxidizability index
+    .abbreviation = PI
+    .hover = Peroxidizability index.
+CalculatedOxidizability = Calculated oxidizability
+    .abbreviation = COX
+    .hover = Calculated oxidizability value.
+
 IodineValue = Iodine value
     .abbreviation = IV
     .hover = Iodine value
@@ -67,18 +80,38 @@ BiodieselProperties = Biodiesel properties
 CetaneNumber = Cetane number
     .abbreviation = CN
     .hover = Cetane number
+CloudPoint = Cloud point
+    .abbreviation = CP
+    .hover = Cloud point (°C), estimated from the palmitic acid (C16:0) content.
 ColdFilterPluggingPoint = Cold filter plugging point
     .abbreviation = CFPP
     .hover = Cold filter plugging point.
 DegreeOfUnsaturation = Degree of unsaturation
     .abbreviation = DU
     .hover = Degree of unsaturation.
+Density = Density
+    .abbreviation = ρ
+    .hover = Density at 15 °C (kg/m³).
+HigherHeatingValue = Higher heating value
+    .abbreviation = HHV
+    .hover = Higher heating value (MJ/kg).
 IodineValue = Iodine value
     .abbreviation = IV
     .hover = Iodine value
+KinematicViscosity = Kinematic viscosity
+    .abbreviation = ν
+    .hover = Kinematic viscosity at 40 °C (mm²/s).
 LongChainSaturatedFactor = Long Chain Saturated factor
     .abbreviation = LCSF
     .hover = Long Chain Saturated factor.
 OxidationStability = Oxidation stability
     .abbreviation = OS
     .hover = Oxidation stability
+PourPoint = Pour point
+    .abbreviation = PP
+    .hover = Pour point (°C), estimated from the palmitic acid (C16:0) content.
+
+EN14214 = EN 14214
+    .hover = European standard limits for biodiesel.
+ASTMD6751 = ASTM D6751
+    .hover = American standard limits for biodiesel.
@@ -0,0 +1,55 @@
+# References
+
+Reference = { $PluralCategory ->
+    *[one] Reference
+    [other] References
+}
+    .hover = Index formulas, references and example values.
+Formula = Formula
+Example = { $PluralCategory ->
+    *[one] Example
+    [other] Examples
+}
+    .hover = Values calculated on illustrative compositions.
+Sunflower = Sunflower oil
+Linseed = Linseed oil
+Fish = Fish oil
+
+## Variables
+
+Variable-Total = ΣFA
+Variable-Saturated = ΣSFA
+Variable-Monounsaturated = ΣMUFA
+Variable-Polyunsaturated = ΣPUFA
+Variable-Unsaturated = ΣUFA
+Variable-Omega = ΣUFA(n-{ $number })
+Variable-PolyunsaturatedOmega = ΣPUFA(n-{ $number })
+Variable-Delta = ΣUFA(Δ{ $number })
+Variable-Conjugated = ΣCFA
+Variable-Trans = ΣTFA
+Variable-BisAllylic = ΣUFA(BAP={ $number })
+Variable-MoreBisAllylic = ΣUFA(BAP≥{ $number })
+Variable-UnsaturationIndex = Σ(N_i · FA_i)
+Variable-IodineValue = Σ(M(I₂) · N_i / M_i · FA_i)
+Variable-BisAllylicPositions = Σ(BAP_i · FA_i)
+
+## Titles
+
+Reference-AocsCd1c85 = AOCS recommended practice Cd 1c-85
+Reference-Chan2017 = Chan (2017)
+Reference-Chen2004 = Chen (2004)
+Reference-Cosgrove1987 = Cosgrove et al. (1987)
+Reference-Fatemi1980 = Fatemi and Hammond (1980)
+Reference-Fernandes2014 = Fernandes (2014)
+Reference-Filip2011 = Filip (2011)
+Reference-Knothe2002 = Knothe (2002)
+Reference-Kumar2011 = Kumar (2011)
+Reference-Labuda2018 = Labuda (2018)
+Reference-Luczynska2019 = Łuczyńska (2019)
+Reference-MajdoubMathlouthi2015 = Majdoub-Mathlouthi (2015)
+Reference-Mohamed2022 = Mohamed (2022)
+Reference-Paiva2016 = Paiva (2016)
+Reference-RinconCervera2020 = Rincón-Cervera (2020)
+Reference-SantosSilva2002 = Santos-Silva (2002)
+Reference-Senso2007 = Senso (2007)
+Reference-Ulbricht1991 = Ulbricht (1991)
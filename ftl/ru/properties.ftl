@@ -37,3 +37,28 @@ value = значение
    *[nominative] жирная кислота
     [genitive] жирной кислоты
 }
+
+## Biodiesel properties
+
+BiodieselProperties = Свойства биодизеля
+    .hover = Свойства биодизеля.
+CloudPoint = Температура помутнения
+    .abbreviation = ТП
+    .hover = Температура помутнения (°C), оценка по содержанию пальмитиновой кислоты (C16:0).
+Density = Плотность
+    .abbreviation = ρ
+    .hover = Плотность при 15 °C (кг/м³).
+HigherHeatingValue = Высшая теплота сгорания
+    .abbreviation = ВТС
+    .hover = Высшая теплота сгорания (МДж/кг).
+KinematicViscosity = Кинематическая вязкость
+    .abbreviation = ν
+    .hover = Кинематическая вязкость при 40 °C (мм²/с).
+PourPoint = Температура застывания
+    .abbreviation = ТЗ
+    .hover = Температура застывания (°C), оценка по содержанию пальмитиновой кислоты (C16:0).
+
+EN14214 = EN 14214
+    .hover = Европейские нормы для биодизеля.
+ASTMD6751 = ASTM D6751
+    .hover = Американские нормы для биодизеля.
@@ -0,0 +1,88 @@
+# References
+
+Reference = { $PluralCategory ->
+    *[one] Источник
+    [other] Источники
+}
+    .hover = Формулы индексов, источники и примеры значений.
+Formula = Формула
+Example = { $PluralCategory ->
+    *[one] Пример
+    [other] Примеры
+}
+    .hover = Значения, вычисленные для иллюстративных составов.
+Sunflower = Подсолнечное масло
+Linseed = Льняное масло
+Fish = Рыбий жир
+
+## Variables
+
+Variable-Total = ΣЖК
+Variable-Saturated = ΣНЖК
+Variable-Monounsaturated = ΣМНЖК
+Variable-Polyunsaturated = ΣПНЖК
+Variable-Unsaturated = ΣННЖК
+Variable-Omega = ΣННЖК(n-{ $number })
+Variable-PolyunsaturatedOmega = ΣПНЖК(n-{ $number })
+Variable-Delta = ΣННЖК(Δ{ $number })
+Variable-Conjugated = ΣКЖК
+Variable-Trans = ΣТЖК
+Variable-BisAllylic = ΣННЖК(БАП={ $number })
+Variable-MoreBisAllylic = ΣННЖК(БАП≥{ $number })
+Variable-UnsaturationIndex = Σ(N_i · ЖК_i)
+Variable-IodineValue = Σ(M(I₂) · N_i / M_i · ЖК_i)
+Variable-BisAllylicPositions = Σ(БАП_i · ЖК_i)
+
+## Titles
+
+Reference-AocsCd1c85 = Рекомендуемая практика AOCS Cd 1c-85
+Reference-Chan2017 = Chan (2017)
+Reference-Chen2004 = Chen (2004)
+Reference-Cosgrove1987 = Cosgrove и др. (1987)
+Reference-Fatemi1980 = Fatemi и Hammond (1980)
+Reference-Fernandes2014 = Fernandes (2014)
+Reference-Filip2011 = Filip (2011)
+Reference-Knothe2002 = Knothe (2002)
+Reference-Kumar2011 = Kumar (2011)
+Reference-Labuda2018 = Labuda (2018)
+Reference-Luczynska2019 = Łuczyńska (2019)
+Reference-MajdoubMathlouthi2015 = Majdoub-Mathlouthi (2015)
+Reference-Mohamed2022 = Mohamed (2022)
+Reference-Paiva2016 = Paiva (2016)
+Reference-RinconCervera2020 = Rincón-Cervera (2020)
+Reference-SantosSilva2002 = Santos-Silva (2002)
+Reference-Senso2007 = Senso (2007)
+Reference-Ulbricht1991 = Ulbricht (1991)
+
+## Indices
+
+Saturated = Насыщенные
+    .hover = Сумма насыщенных жирных кислот.
+Monounsaturated = Мононенасыщенные
+    .hover = Сумма мононенасыщенных жирных кислот.
+Polyunsaturated = Полиненасыщенные
+    .hover = Сумма полиненасыщенных жирных кислот.
+Unsaturated = Ненасыщенные
+    .hover = Сумма ненасыщенных жирных кислот.
+Unsaturated-9 = Омега -9
+Unsaturated-6 = Омега -6
+Unsaturated-3 = Омега -3
+Unsaturated9 = Дельта 9
+Conjugated = Сопряженные
+Trans = Транс
+EicosapentaenoicAndDocosahexaenoic = Эйкозапентаеновая и докозагексаеновая
+    .hover = Сумма эйкозапентаеновой и докозагексаеновой кислот.
+FishLipidQuality = Качество липидов рыбы
+HealthPromotingIndex = Индекс пользы для здоровья
+HypocholesterolemicToHypercholesterolemic = Гипохолестеринемические к гиперхолестеринемическим
+IndexOfAtherogenicity = Индекс атерогенности
+IndexOfThrombogenicity = Индекс тромбогенности
+LinoleicToAlphaLinolenic = Линолевая к альфа-линоленовой
+Polyunsaturated-6ToPolyunsaturated-3 = Полиненасыщенные омега -6 к омега -3
+PolyunsaturatedToSaturated = Полиненасыщенные к насыщенным
+UnsaturationIndex = Индекс ненасыщенности
+IodineValue = Йодное число
+AllylicPositionEquivalent = Эквивалент аллильных положений
+BisAllylicPositionEquivalent = Эквивалент бис-аллильных положений
+PeroxidizabilityIndex = Индекс пероксидируемости
+CalculatedOxidizability = Расчетная окисляемость
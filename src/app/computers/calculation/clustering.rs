@@ -0,0 +1,363 @@
+use super::pca::variables;
+use crate::{
+    app::states::calculation::settings::{Clustering, Distance, Linkage, Settings, Threshold},
+    r#const::{ORDER, THRESHOLD},
+    utils::{HashedDataFrame, HashedMetaDataFrame},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use indexmap::IndexSet;
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Clustering calculation computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Clustering calculation computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        if key.threshold.filter {
+            lazy_frame = lazy_frame.filter(col(THRESHOLD));
+        }
+        let (labels, observations) = variables(
+            lazy_frame.clone(),
+            key.clustering.stereospecific_numbers,
+            key.frames.len(),
+        )?;
+        // Rows are fatty acids, columns are samples
+        let values: Vec<Vec<_>> = (0..labels.len())
+            .map(|column| {
+                observations
+                    .iter()
+                    .map(|observation| observation[column])
+                    .collect()
+            })
+            .collect();
+        let sample_dendrogram = Dendrogram::new(
+            distances(&observations, key.clustering.distance),
+            key.clustering.linkage,
+        );
+        let label_dendrogram = Dendrogram::new(
+            distances(&values, key.clustering.distance),
+            key.clustering.linkage,
+        );
+        // Fatty acids in the order of the dendrogram, the concatenated
+        // stereospecific numbers are the blocks of the same fatty acids
+        let data_frame = lazy_frame.select([col(LABEL)]).collect()?;
+        let fatty_acids = data_frame[LABEL].str()?;
+        let order = label_dendrogram
+            .order
+            .iter()
+            .filter_map(|index| fatty_acids.get(index % fatty_acids.len().max(1)))
+            .map(ToOwned::to_owned)
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect();
+        Ok(Value {
+            samples: key
+                .frames
+                .iter()
+                .map(|frame| frame.meta.format(" ").to_string())
+                .collect(),
+            labels,
+            values,
+            order,
+            sample_dendrogram,
+            label_dendrogram,
+        })
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Clustering calculation key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) clustering: &'a Clustering,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frame: &'a HashedDataFrame,
+        frames: &'a [HashedMetaDataFrame],
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            frame,
+            frames: match settings.index {
+                Some(index) => &frames[index..=index],
+                None => frames,
+            },
+            clustering: &settings.clustering,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Clustering calculation value
+#[derive(Clone, Debug)]
+pub(crate) struct Value {
+    /// Sample names
+    pub(crate) samples: Vec<String>,
+    /// Fatty acid labels
+    pub(crate) labels: Vec<String>,
+    /// Values, rows are fatty acids, columns are samples
+    pub(crate) values: Vec<Vec<f64>>,
+    /// Fatty acid labels (without stereospecific numbers) in the order of the
+    /// dendrogram
+    pub(crate) order: Vec<String>,
+    pub(crate) sample_dendrogram: Dendrogram,
+    pub(crate) label_dendrogram: Dendrogram,
+}
+
+/// Dendrogram
+///
+/// Leaves are `0..n`, the cluster of the `i`-th merge is `n + i`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Dendrogram {
+    pub(crate) merges: Vec<Merge>,
+    /// Leaves in the order of the dendrogram
+    pub(crate) order: Vec<usize>,
+}
+
+impl Dendrogram {
+    /// Agglomerative hierarchical clustering (Lance–Williams formula)
+    fn new(mut distances: Vec<Vec<f64>>, linkage: Linkage) -> Self {
+        let n = distances.len();
+        if n == 0 {
+            return Self::default();
+        }
+        // Cluster and its size of each slot
+        let mut clusters: Vec<_> = (0..n).map(|index| Some((index, 1))).collect();
+        let mut merges = Vec::with_capacity(n - 1);
+        for step in 0..n - 1 {
+            let mut nearest = None;
+            for i in 0..n {
+                if clusters[i].is_none() {
+                    continue;
+                }
+                for j in i + 1..n {
+                    if clusters[j].is_none() {
+                        continue;
+                    }
+                    let distance = distances[i][j];
+                    if nearest.is_none_or(|(_, _, nearest)| distance < nearest) {
+                        nearest = Some((i, j, distance));
+                    }
+                }
+            }
+            let Some((i, j, distance)) = nearest else {
+                break;
+            };
+            let (Some((left, left_size)), Some((right, right_size))) = (clusters[i], clusters[j])
+            else {
+                break;
+            };
+            for k in 0..n {
+                let Some((_, size)) = clusters[k] else {
+                    continue;
+                };
+                if k == i || k == j {
+                    continue;
+                }
+                let value = linkage.update(
+                    distances[k][i],
+                    distances[k][j],
+                    distance,
+                    [left_size, right_size, size],
+                );
+                distances[k][i] = value;
+                distances[i][k] = value;
+            }
+            clusters[i] = Some((n + step, left_size + right_size));
+            clusters[j] = None;
+            merges.push(Merge {
+                left,
+                right,
+                distance,
+                size: left_size + right_size,
+            });
+        }
+        let mut order = Vec::with_capacity(n);
+        leaves(&merges, n, 2 * n - 2, &mut order);
+        Self { merges, order }
+    }
+}
+
+/// Merge of two clusters
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Merge {
+    pub(crate) left: usize,
+    pub(crate) right: usize,
+    pub(crate) distance: f64,
+    pub(crate) size: usize,
+}
+
+impl Linkage {
+    /// Distance between the cluster `k` and the merged cluster `i ∪ j`
+    ///
+    /// Undefined (infinite) distances stay undefined, the Ward formula would
+    /// turn them into `inf - inf = NaN`.
+    fn update(self, ki: f64, kj: f64, ij: f64, [i, j, k]: [usize; 3]) -> f64 {
+        let [i, j, k] = [i as f64, j as f64, k as f64];
+        match self {
+            Self::Single => ki.min(kj),
+            Self::Complete => ki.max(kj),
+            Self::Average => (i * ki + j * kj) / (i + j),
+            Self::Ward if !(ki.is_finite() && kj.is_finite() && ij.is_finite()) => f64::INFINITY,
+            Self::Ward => (((i + k) * ki * ki + (j + k) * kj * kj - k * ij * ij) / (i + j + k))
+                .max(0.0)
+                .sqrt(),
+        }
+    }
+}
+
+/// Leaves of the cluster in the dendrogram order
+fn leaves(merges: &[Merge], n: usize, cluster: usize, order: &mut Vec<usize>) {
+    if cluster < n {
+        order.push(cluster);
+    } else if let Some(merge) = merges.get(cluster - n) {
+        leaves(merges, n, merge.left, order);
+        leaves(merges, n, merge.right, order);
+    }
+}
+
+/// Pairwise distances of the rows
+fn distances(rows: &[Vec<f64>], distance: Distance) -> Vec<Vec<f64>> {
+    let mut distances = vec![vec![0.0; rows.len()]; rows.len()];
+    for i in 0..rows.len() {
+        for j in i + 1..rows.len() {
+            let value = distance.between(&rows[i], &rows[j]);
+            // Undefined distance is the largest one
+            let value = if value.is_finite() {
+                value
+            } else {
+                f64::INFINITY
+            };
+            distances[i][j] = value;
+            distances[j][i] = value;
+        }
+    }
+    distances
+}
+
+impl Distance {
+    fn between(self, left: &[f64], right: &[f64]) -> f64 {
+        let pairs = || left.iter().zip(right);
+        match self {
+            Self::Euclidean => pairs()
+                .map(|(left, right)| (left - right).powi(2))
+                .sum::<f64>()
+                .sqrt(),
+            Self::BrayCurtis => {
+                let difference: f64 = pairs().map(|(left, right)| (left - right).abs()).sum();
+                let sum: f64 = pairs().map(|(left, right)| left + right).sum();
+                if sum == 0.0 { 0.0 } else { difference / sum }
+            }
+            Self::Correlation => {
+                let n = left.len() as f64;
+                let left_mean = left.iter().sum::<f64>() / n;
+                let right_mean = right.iter().sum::<f64>() / n;
+                let (mut covariance, mut left_variance, mut right_variance) = (0.0, 0.0, 0.0);
+                for (left, right) in pairs() {
+                    covariance += (left - left_mean) * (right - right_mean);
+                    left_variance += (left - left_mean).powi(2);
+                    right_variance += (right - right_mean).powi(2);
+                }
+                1.0 - covariance / (left_variance * right_variance).sqrt()
+            }
+        }
+    }
+}
+
+/// Sort rows by the order of labels, the labels missing in the order are the
+/// last ones
+pub(crate) fn sort(lazy_frame: LazyFrame, order: &[String]) -> PolarsResult<LazyFrame> {
+    if order.is_empty() {
+        return Ok(lazy_frame);
+    }
+    let positions = df! {
+        LABEL => order,
+        ORDER => (0..order.len() as u32).collect::<Vec<_>>(),
+    }?;
+    Ok(lazy_frame
+        .join(
+            positions.lazy(),
+            [col(LABEL)],
+            [col(LABEL)],
+            JoinArgs::new(JoinType::Left).with_maintain_order(MaintainOrderJoin::Left),
+        )
+        .sort_by_exprs(
+            [col(ORDER)],
+            SortMultipleOptions::default()
+                .with_maintain_order(true)
+                .with_nulls_last(true),
+        )
+        .drop(cols([ORDER])))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dendrogram() {
+        let rows = [vec![0.0], vec![10.0], vec![1.0], vec![11.0], vec![5.0]];
+        let distances = distances(&rows, Distance::Euclidean);
+        let dendrogram = Dendrogram::new(distances.clone(), Linkage::Single);
+        assert_eq!(dendrogram.merges.len(), 4);
+        assert_eq!(
+            (dendrogram.merges[0].left, dendrogram.merges[0].right),
+            (0, 2)
+        );
+        assert_eq!(
+            (dendrogram.merges[1].left, dendrogram.merges[1].right),
+            (1, 3)
+        );
+        assert_eq!(dendrogram.order, [0, 2, 4, 1, 3]);
+        let dendrogram = Dendrogram::new(distances, Linkage::Complete);
+        assert_eq!(dendrogram.merges[3].distance, 11.0);
+        assert_eq!(dendrogram.merges[3].size, 5);
+    }
+
+    #[test]
+    fn undefined() {
+        // The last two rows are undefined against all the others
+        let infinity = f64::INFINITY;
+        let distances = vec![
+            vec![0.0, 1.0, infinity, infinity],
+            vec![1.0, 0.0, infinity, infinity],
+            vec![infinity, infinity, 0.0, infinity],
+            vec![infinity, infinity, infinity, 0.0],
+        ];
+        let dendrogram = Dendrogram::new(distances, Linkage::Ward);
+        assert_eq!(dendrogram.merges.len(), 3);
+        assert_eq!(
+            (dendrogram.merges[0].left, dendrogram.merges[0].right),
+            (0, 1)
+        );
+        assert_eq!(dendrogram.merges[0].distance, 1.0);
+        assert!(dendrogram.merges[1].distance.is_infinite());
+        assert!(dendrogram.merges[2].distance.is_infinite());
+    }
+
+    #[test]
+    fn distance() {
+        let [left, right] = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]];
+        assert_eq!(Distance::Euclidean.between(&left, &right), 14f64.sqrt());
+        assert_eq!(Distance::BrayCurtis.between(&left, &right), 6.0 / 18.0);
+        assert!(Distance::Correlation.between(&left, &right).abs() < 1e-12);
+    }
+}
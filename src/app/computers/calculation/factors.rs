@@ -0,0 +1,122 @@
+use super::clustering::sort;
+use crate::{
+    app::{
+        MAX_PRECISION,
+        states::calculation::settings::{Settings, Threshold},
+    },
+    r#const::{CARBON, ENRICHMENT, SELECTIVITY, THRESHOLD, UNSATURATION},
+    utils::{
+        HashedDataFrame,
+        polars::{MeanAndStandardDeviationOptions, mean_and_standard_deviation},
+    },
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Factors calculation computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Factors calculation computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = sort(lazy_frame, key.order)?;
+        if key.threshold.filter {
+            lazy_frame = lazy_frame.filter(col(THRESHOLD));
+        }
+        let r#struct = |name| {
+            col(name)
+                .arr()
+                .to_struct(Some(PlanCallback::new(move |index| {
+                    Ok(format!("{name}[{index}]"))
+                })))
+        };
+        let mut enrichment_factor = FattyAcidExpr::enrichment_factor(
+            col(STEREOSPECIFIC_NUMBERS2),
+            col(STEREOSPECIFIC_NUMBERS123),
+        );
+        let mut selectivity_factor = concat_arr(vec![
+            col(FATTY_ACID).fatty_acid().selectivity_factor(
+                r#struct(STEREOSPECIFIC_NUMBERS2)
+                    .struct_()
+                    .field_by_name("*"),
+                r#struct(STEREOSPECIFIC_NUMBERS123)
+                    .struct_()
+                    .field_by_name("*"),
+            ),
+        ])?;
+        if key.normalize_factors {
+            enrichment_factor = enrichment_factor / lit(3);
+            selectivity_factor = selectivity_factor / lit(3);
+        }
+        // | Label | FattyAcid | Carbon | Unsaturation | Enrichment | Selectivity |
+        // | ---   | ---       | ---    | ---          | ---        | ---         |
+        // | str   | struct    | i64    | i64          | struct[3]  | struct[3]   |
+        lazy_frame = lazy_frame.select([
+            col(LABEL),
+            col(FATTY_ACID),
+            col(FATTY_ACID)
+                .fatty_acid()
+                .carbon()
+                .cast(DataType::Int64)
+                .alias(CARBON),
+            col(FATTY_ACID)
+                .fatty_acid()
+                .unsaturation()
+                .cast(DataType::Int64)
+                .alias(UNSATURATION),
+            mean_and_standard_deviation(enrichment_factor, key).alias(ENRICHMENT),
+            mean_and_standard_deviation(selectivity_factor, key).alias(SELECTIVITY),
+        ]);
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Factors calculation key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) ddof: u8,
+    pub(crate) normalize_factors: bool,
+    pub(crate) order: &'a [String],
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            ddof: settings.ddof,
+            normalize_factors: settings.normalize_factors,
+            order: &settings.order,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+impl From<Key<'_>> for MeanAndStandardDeviationOptions {
+    fn from(key: Key) -> Self {
+        // Factors are not fractions
+        Self {
+            ddof: key.ddof,
+            percent: false,
+            precision: MAX_PRECISION,
+            significant: false,
+        }
+    }
+}
+
+/// Factors calculation value
+type Value = DataFrame;
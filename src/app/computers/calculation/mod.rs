@@ -10,10 +10,14 @@ use lipid::prelude::*;
 use ordered_float::OrderedFloat;
 use polars::prelude::*;
 use polars_ext::prelude::*;
+use tracing::warn;
 
 /// Starts with `STEREOSPECIFIC_NUMBERS`
 const SN_: &str = formatcp!(r#"^{STEREOSPECIFIC_NUMBERS}.+$"#);
 
+/// `STEREOSPECIFIC_NUMBERS1` with optional index
+const SN1_: &str = formatcp!(r#"^{STEREOSPECIFIC_NUMBERS1}(\[\d+\])?$"#);
+
 /// Starts with `STEREOSPECIFIC_NUMBERS123`
 const SN123_: &str = formatcp!(r#"^{STEREOSPECIFIC_NUMBERS123}.*$"#);
 
@@ -23,6 +27,9 @@ const SN13_: &str = formatcp!(r#"^{STEREOSPECIFIC_NUMBERS13}.*$"#);
 /// Starts with `STEREOSPECIFIC_NUMBERS2`
 const SN2_: &str = formatcp!(r#"^{STEREOSPECIFIC_NUMBERS2}.*$"#);
 
+/// `STEREOSPECIFIC_NUMBERS3` with optional index
+const SN3_: &str = formatcp!(r#"^{STEREOSPECIFIC_NUMBERS3}(\[\d+\])?$"#);
+
 /// Starts with `STANDARD`
 const STANDARD_: &str = formatcp!(r#"^{STANDARD}.*$"#);
 
@@ -61,6 +68,8 @@ impl Computer {
             concat_arr(vec![col(SN123_).alias(STEREOSPECIFIC_NUMBERS123)])?,
             concat_arr(vec![col(SN13_).alias(STEREOSPECIFIC_NUMBERS13)])?,
             concat_arr(vec![col(SN2_).alias(STEREOSPECIFIC_NUMBERS2)])?,
+            concat_arr(vec![col(SN1_).alias(STEREOSPECIFIC_NUMBERS1)])?,
+            concat_arr(vec![col(SN3_).alias(STEREOSPECIFIC_NUMBERS3)])?,
             as_struct(vec![
                 concat_arr(vec![
                     col(STANDARD_)
@@ -72,10 +81,12 @@ impl Computer {
             .alias(STANDARD),
             any_horizontal([col(THRESHOLD_).alias(THRESHOLD)])?,
         ]);
-        // | Label | FattyAcid | StereospecificNumbers123 | StereospecificNumbers13 | StereospecificNumbers2 | Standard  | Threshold |
-        // | ----- | --------- | ------------------------ | ----------------------- | ---------------------- | --------- | --------- |
-        // | str   | struct[2] | array[f64, n]            | array[f64, n]           | array[f64, n]          | struct[2] | bool      |
-        HashedDataFrame::new(lazy_frame.collect()?)
+        // | Label | FattyAcid | StereospecificNumbers123 | StereospecificNumbers13 | StereospecificNumbers2 | StereospecificNumbers1 | StereospecificNumbers3 | Standard  | Threshold |
+        // | ----- | --------- | ------------------------ | ----------------------- | ---------------------- | ---------------------- | ---------------------- | --------- | --------- |
+        // | str   | struct[2] | array[f64, n]            | array[f64, n]           | array[f64, n]          | array[f64, n]          | array[f64, n]          | struct[2] | bool      |
+        let data_frame = lazy_frame.collect()?;
+        negative_sn3(&data_frame)?;
+        HashedDataFrame::new(data_frame)
     }
 }
 
@@ -154,6 +165,11 @@ fn compute(data_frame: &DataFrame, key: Key) -> PolarsResult<LazyFrame> {
     } else if schema.contains(STEREOSPECIFIC_NUMBERS12_23) {
         lazy_frame = sn12_23(lazy_frame, key);
     }
+    // Stereospecific only if any SN-1 value is measured
+    let stereospecific = data_frame
+        .column(STEREOSPECIFIC_NUMBERS1)
+        .is_ok_and(|column| column.null_count() < column.len());
+    lazy_frame = sn1_3(lazy_frame, stereospecific);
     Ok(lazy_frame)
 }
 
@@ -268,6 +284,39 @@ fn sn12_23(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
         .drop(cols([STEREOSPECIFIC_NUMBERS12_23]))
 }
 
+/// {3:i} = 2 * {1:i|3:i} - {1:i}
+///
+/// Without stereospecific analysis (no SN-1 values at all) SN-1 and SN-3 are
+/// both equal to SN-1,3. Otherwise the measured SN-1 is kept as is and SN-3 is
+/// not clipped nor renormalized, negative values are reported.
+fn sn1_3(lazy_frame: LazyFrame, stereospecific: bool) -> LazyFrame {
+    if !stereospecific {
+        return lazy_frame.with_columns([
+            col(STEREOSPECIFIC_NUMBERS13).alias(STEREOSPECIFIC_NUMBERS1),
+            col(STEREOSPECIFIC_NUMBERS13).alias(STEREOSPECIFIC_NUMBERS3),
+        ]);
+    }
+    // Calculate SN-3
+    lazy_frame.with_column(
+        (col(STEREOSPECIFIC_NUMBERS13) * lit(2) - col(STEREOSPECIFIC_NUMBERS1))
+            .alias(STEREOSPECIFIC_NUMBERS3),
+    )
+}
+
+/// Reports the fatty acids with the negative SN-3
+fn negative_sn3(data_frame: &DataFrame) -> PolarsResult<()> {
+    let negative = data_frame
+        .clone()
+        .lazy()
+        .filter(col(STEREOSPECIFIC_NUMBERS3).arr().min().lt(lit(0)))
+        .select([col(LABEL), col(FATTY_ACID).fatty_acid().format()])
+        .collect()?;
+    if negative.height() != 0 {
+        warn!(%negative, "2 * SN-1,3 - SN-1 < 0");
+    }
+    Ok(())
+}
+
 // // n = m / M
 // fn to_mole(names: [&str; 2]) -> Expr {
 //     destruct(names) / col("FA").fa().mass()
@@ -284,6 +333,11 @@ fn sn12_23(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
 //     destruct(names) / to_mass(names).sum()
 // }
 
+pub(crate) mod clustering;
+pub(crate) mod factors;
+pub(crate) mod pca;
+pub(crate) mod plot;
+pub(crate) mod series;
 pub(crate) mod sum;
 pub(crate) mod table;
 
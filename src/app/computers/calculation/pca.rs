@@ -0,0 +1,312 @@
+use crate::{
+    app::states::calculation::settings::{
+        Pca, STEREOSPECIFIC_NUMBERS, Settings, StereospecificNumbers, Threshold,
+    },
+    r#const::{COMPONENT, CUMULATIVE, EIGENVALUE, EXPLAINED, GROUP, NAME, THRESHOLD},
+    utils::{HashedDataFrame, HashedMetaDataFrame, metadata::sample, statistics::symmetric_eigen},
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Principal component analysis computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Principal component analysis computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        if key.threshold.filter {
+            lazy_frame = lazy_frame.filter(col(THRESHOLD));
+        }
+        let (names, observations) =
+            variables(lazy_frame, key.pca.stereospecific_numbers, key.frames.len())?;
+        let components = Components::new(observations, key.pca.center, key.pca.scale);
+        let count = components
+            .values
+            .len()
+            .min(key.frames.len())
+            .min(names.len());
+        let component = |index: usize| format!("PC{}", index + 1);
+        // | Component | Eigenvalue | Explained | Cumulative |
+        // | ---       | ---        | ---       | ---        |
+        // | str       | f64        | f64       | f64        |
+        let total: f64 = components.values.iter().sum();
+        let explained: Vec<_> = components.values[..count]
+            .iter()
+            .map(|value| value / total * 100.0)
+            .collect();
+        let scree = df! {
+            COMPONENT => (0..count).map(component).collect::<Vec<_>>(),
+            EIGENVALUE => &components.values[..count],
+            EXPLAINED => &explained,
+            CUMULATIVE => explained
+                .iter()
+                .scan(0.0, |sum, value| {
+                    *sum += value;
+                    Some(*sum)
+                })
+                .collect::<Vec<_>>(),
+        }?;
+        // | Name | Group | PC1 | PC2 | ... |
+        // | ---  | ---   | --- | --- | ... |
+        // | str  | str   | f64 | f64 | ... |
+        let mut scores = df! {
+            NAME => key
+                .frames
+                .iter()
+                .map(|frame| frame.meta.format(" ").to_string())
+                .collect::<Vec<_>>(),
+            GROUP => key
+                .frames
+                .iter()
+                .map(|frame| group(frame, &key.pca.group))
+                .collect::<Vec<_>>(),
+        }?;
+        // | Label | PC1 | PC2 | ... |
+        // | ---   | --- | --- | ... |
+        // | str   | f64 | f64 | ... |
+        let mut loadings = df! {
+            LABEL => &names,
+        }?;
+        for index in 0..count {
+            scores.with_column(Column::new(
+                component(index).into(),
+                components
+                    .scores
+                    .iter()
+                    .map(|score| score[index])
+                    .collect::<Vec<_>>(),
+            ))?;
+            loadings.with_column(Column::new(
+                component(index).into(),
+                components
+                    .vectors
+                    .iter()
+                    .map(|vector| vector[index])
+                    .collect::<Vec<_>>(),
+            ))?;
+        }
+        Ok(Value {
+            scree,
+            scores,
+            loadings,
+        })
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Principal component analysis key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) pca: &'a Pca,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frame: &'a HashedDataFrame,
+        frames: &'a [HashedMetaDataFrame],
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            frame,
+            frames: match settings.index {
+                Some(index) => &frames[index..=index],
+                None => frames,
+            },
+            pca: &settings.pca,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Principal component analysis value
+#[derive(Clone, Debug)]
+pub(crate) struct Value {
+    pub(crate) scree: DataFrame,
+    pub(crate) scores: DataFrame,
+    pub(crate) loadings: DataFrame,
+}
+
+/// Variable names and observations (rows are samples, columns are variables)
+///
+/// `None` stereospecific numbers are all stereospecific numbers concatenated.
+pub(crate) fn variables(
+    lazy_frame: LazyFrame,
+    stereospecific_numbers: Option<StereospecificNumbers>,
+    samples: usize,
+) -> PolarsResult<(Vec<String>, Vec<Vec<f64>>)> {
+    let concatenated = stereospecific_numbers.is_none();
+    let stereospecific_numbers = match stereospecific_numbers {
+        Some(stereospecific_numbers) => vec![stereospecific_numbers],
+        None => STEREOSPECIFIC_NUMBERS.to_vec(),
+    };
+    let mut names = Vec::new();
+    let mut observations = vec![Vec::new(); samples];
+    for stereospecific_numbers in stereospecific_numbers {
+        let name = stereospecific_numbers.to_string();
+        let data_frame = lazy_frame
+            .clone()
+            .select([col(LABEL), col(name.as_str())])
+            .explode(
+                cols([&*name]),
+                ExplodeOptions {
+                    empty_as_null: true,
+                    keep_nulls: true,
+                },
+            )
+            .collect()?;
+        let labels = data_frame[LABEL].str()?;
+        let values = data_frame[&*name].f64()?;
+        for row in (0..data_frame.height()).step_by(samples.max(1)) {
+            let label = labels.get(row).unwrap_or_default();
+            names.push(if concatenated {
+                format!("{label} {}", abbreviation(stereospecific_numbers))
+            } else {
+                label.to_owned()
+            });
+            for (index, observation) in observations.iter_mut().enumerate() {
+                // Missing fatty acid is absent
+                observation.push(values.get(row + index).unwrap_or_default());
+            }
+        }
+    }
+    Ok((names, observations))
+}
+
+fn abbreviation(stereospecific_numbers: StereospecificNumbers) -> &'static str {
+    match stereospecific_numbers {
+        StereospecificNumbers::OneAndTwoAndTree => "SN-1,2,3",
+        StereospecificNumbers::OneAndThree => "SN-1,3",
+        StereospecificNumbers::Two => "SN-2",
+    }
+}
+
+/// Group of the sample: the design fields or the name stem (without replicate
+/// number), or the metadata value
+fn group(frame: &HashedMetaDataFrame, key: &Option<String>) -> String {
+    match key {
+        Some(key) => frame.meta.get(key.as_str()).cloned().unwrap_or_default(),
+        None => sample(frame),
+    }
+}
+
+/// Principal components
+struct Components {
+    /// Eigenvalues of the covariance matrix (variances of the components)
+    values: Vec<f64>,
+    /// Eigenvectors of the covariance matrix (loadings), rows are variables
+    vectors: Vec<Vec<f64>>,
+    /// Projections of the observations (scores), rows are observations
+    scores: Vec<Vec<f64>>,
+}
+
+impl Components {
+    fn new(mut observations: Vec<Vec<f64>>, center: bool, scale: bool) -> Self {
+        let rows = observations.len();
+        let columns = observations.first().map_or(0, Vec::len);
+        let ddof = rows.saturating_sub(1).max(1) as f64;
+        for column in 0..columns {
+            let mean = observations.iter().map(|row| row[column]).sum::<f64>() / rows as f64;
+            let standard_deviation = (observations
+                .iter()
+                .map(|row| (row[column] - mean).powi(2))
+                .sum::<f64>()
+                / ddof)
+                .sqrt();
+            for row in &mut observations {
+                if center {
+                    row[column] -= mean;
+                }
+                if scale {
+                    // Constant variable carries no information
+                    row[column] = if standard_deviation > 0.0 {
+                        row[column] / standard_deviation
+                    } else {
+                        0.0
+                    };
+                }
+            }
+        }
+        // Covariance
+        let mut covariance = vec![vec![0.0; columns]; columns];
+        for row in 0..columns {
+            for column in row..columns {
+                let value = observations
+                    .iter()
+                    .map(|observation| observation[row] * observation[column])
+                    .sum::<f64>()
+                    / ddof;
+                covariance[row][column] = value;
+                covariance[column][row] = value;
+            }
+        }
+        let (mut values, mut vectors) = symmetric_eigen(covariance);
+        for value in &mut values {
+            *value = value.max(0.0);
+        }
+        // Sign convention: the largest loading of each component is positive
+        for component in 0..columns {
+            let sign = vectors
+                .iter()
+                .map(|vector| vector[component])
+                .max_by(|left, right| left.abs().total_cmp(&right.abs()))
+                .map_or(1.0, f64::signum);
+            for vector in &mut vectors {
+                vector[component] *= sign;
+            }
+        }
+        let scores = observations
+            .iter()
+            .map(|observation| {
+                (0..columns)
+                    .map(|component| {
+                        observation
+                            .iter()
+                            .zip(&vectors)
+                            .map(|(value, vector)| value * vector[component])
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            values,
+            vectors,
+            scores,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn components() {
+        let observations = vec![
+            vec![1.0, 2.0, 0.0],
+            vec![2.0, 4.0, 0.0],
+            vec![3.0, 6.0, 0.0],
+        ];
+        let components = Components::new(observations, true, false);
+        assert!((components.values[0] - 5.0).abs() < 1e-12);
+        assert!(components.values[1].abs() < 1e-12);
+        assert!((components.vectors[1][0] - 2.0 / 5f64.sqrt()).abs() < 1e-12);
+        assert!((components.scores[1][0]).abs() < 1e-12);
+        assert!((components.scores[2][0] - 5f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,107 @@
+use super::clustering::sort;
+use crate::{
+    app::{
+        MAX_PRECISION,
+        states::calculation::settings::{Settings, Threshold},
+    },
+    r#const::{ENRICHMENT, THRESHOLD},
+    utils::{
+        HashedDataFrame,
+        polars::{MeanAndStandardDeviationOptions, mean_and_standard_deviation},
+    },
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Plot calculation computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Plot calculation computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = sort(lazy_frame, key.order)?;
+        if key.threshold.filter {
+            lazy_frame = lazy_frame.filter(col(THRESHOLD));
+        }
+        let mut enrichment_factor = FattyAcidExpr::enrichment_factor(
+            col(STEREOSPECIFIC_NUMBERS2),
+            col(STEREOSPECIFIC_NUMBERS123),
+        );
+        if key.normalize_factors {
+            enrichment_factor = enrichment_factor / lit(3);
+        }
+        // | Label | StereospecificNumbers123 | StereospecificNumbers13 | StereospecificNumbers2 | Enrichment |
+        // | ---   | ---                      | ---                     | ---                    | ---        |
+        // | str   | struct[3]                | struct[3]               | struct[3]              | struct[3]  |
+        lazy_frame = lazy_frame.select([
+            col(LABEL),
+            mean_and_standard_deviation(col(STEREOSPECIFIC_NUMBERS123), key)
+                .alias(STEREOSPECIFIC_NUMBERS123),
+            mean_and_standard_deviation(col(STEREOSPECIFIC_NUMBERS13), key)
+                .alias(STEREOSPECIFIC_NUMBERS13),
+            mean_and_standard_deviation(col(STEREOSPECIFIC_NUMBERS2), key)
+                .alias(STEREOSPECIFIC_NUMBERS2),
+            // Enrichment factor is not a fraction
+            mean_and_standard_deviation(
+                enrichment_factor,
+                MeanAndStandardDeviationOptions {
+                    percent: false,
+                    ..key.into()
+                },
+            )
+            .alias(ENRICHMENT),
+        ]);
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Plot calculation key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) ddof: u8,
+    pub(crate) normalize_factors: bool,
+    pub(crate) order: &'a [String],
+    pub(crate) percent: bool,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            ddof: settings.ddof,
+            normalize_factors: settings.normalize_factors,
+            order: &settings.order,
+            percent: settings.percent,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+impl From<Key<'_>> for MeanAndStandardDeviationOptions {
+    fn from(key: Key) -> Self {
+        Self {
+            ddof: key.ddof,
+            percent: key.percent,
+            precision: MAX_PRECISION,
+            significant: false,
+        }
+    }
+}
+
+/// Plot calculation value
+type Value = DataFrame;
@@ -0,0 +1,203 @@
+use super::sum::properties::{Computer as PropertiesComputer, Key as PropertiesKey};
+use crate::{
+    app::{
+        MAX_PRECISION,
+        states::calculation::settings::{Indices, Series, Settings, Threshold},
+    },
+    r#const::{NAME, SAMPLE, THRESHOLD},
+    utils::{
+        HashedDataFrame, HashedMetaDataFrame,
+        metadata::ordering,
+        statistics::{mann_kendall, slope},
+    },
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Series calculation computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Series calculation computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        // Levels of the ordering variable, the samples without the ordering
+        // value are not in the series
+        let orderings: Vec<_> = key
+            .frames
+            .iter()
+            .map(|frame| ordering(frame, key.series.variable.as_deref()))
+            .collect();
+        let mut levels: Vec<_> = orderings.iter().flatten().copied().collect();
+        levels.sort_by(f64::total_cmp);
+        levels.dedup();
+        let name = key.series.stereospecific_numbers.to_string();
+        let trends = if key.series.indices {
+            // | Name | StereospecificNumbers123 | ... |
+            // | ---  | ---                      | ... |
+            // | str  | struct[3]                | ... |
+            let data_frame = PropertiesComputer.compute(PropertiesKey {
+                frame: key.frame,
+                ddof: key.ddof,
+                indices: key.indices,
+                precision: MAX_PRECISION,
+                significant: false,
+                threshold_filter: key.threshold.filter,
+            });
+            let samples = data_frame[name.as_str()].struct_()?.field_by_name(SAMPLE)?;
+            trends(
+                data_frame[NAME].str()?,
+                samples.array()?,
+                &orderings,
+                &levels,
+                key,
+            )?
+        } else {
+            let mut lazy_frame = key.frame.data_frame.clone().lazy();
+            if key.threshold.filter {
+                lazy_frame = lazy_frame.filter(col(THRESHOLD));
+            }
+            // | Label | StereospecificNumbers |
+            // | ---   | ---                   |
+            // | str   | array[f64, n]         |
+            let data_frame = lazy_frame
+                .select([col(LABEL), col(name.as_str())])
+                .collect()?;
+            trends(
+                data_frame[LABEL].str()?,
+                data_frame[name.as_str()].array()?,
+                &orderings,
+                &levels,
+                key,
+            )?
+        };
+        Ok(Value {
+            unordered: orderings
+                .iter()
+                .filter(|ordering| ordering.is_none())
+                .count(),
+            levels,
+            trends,
+        })
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Series calculation key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) ddof: u8,
+    pub(crate) indices: &'a Indices,
+    pub(crate) series: &'a Series,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frame: &'a HashedDataFrame,
+        frames: &'a [HashedMetaDataFrame],
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            frame,
+            frames: match settings.index {
+                Some(index) => &frames[index..=index],
+                None => frames,
+            },
+            ddof: settings.ddof,
+            indices: &settings.indices,
+            series: &settings.series,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Series calculation value
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Value {
+    /// Levels of the ordering variable (ascending)
+    pub(crate) levels: Vec<f64>,
+    /// Number of the samples without the ordering value
+    pub(crate) unordered: usize,
+    pub(crate) trends: Vec<Trend>,
+}
+
+/// Trend of the fatty acid (or the index) over the ordering variable
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Trend {
+    pub(crate) name: String,
+    /// Means of each level
+    pub(crate) means: Vec<Option<f64>>,
+    /// Standard deviations of each level
+    pub(crate) standard_deviations: Vec<Option<f64>>,
+    /// Least squares slope of the samples
+    pub(crate) slope: Option<f64>,
+    /// Mann–Kendall tau and p-value of the means
+    pub(crate) mann_kendall: Option<(f64, f64)>,
+}
+
+fn trends(
+    names: &StringChunked,
+    samples: &ArrayChunked,
+    orderings: &[Option<f64>],
+    levels: &[f64],
+    key: Key,
+) -> PolarsResult<Vec<Trend>> {
+    let mut trends = Vec::with_capacity(names.len());
+    for (row, name) in names.iter().enumerate() {
+        let mut points = Vec::new();
+        if let Some(sample) = samples.get_as_series(row) {
+            for (value, ordering) in sample.f64()?.iter().zip(orderings) {
+                if let (Some(value), Some(ordering)) = (value, ordering)
+                    && value.is_finite()
+                {
+                    points.push([*ordering, value]);
+                }
+            }
+        }
+        let mut means = Vec::with_capacity(levels.len());
+        let mut standard_deviations = Vec::with_capacity(levels.len());
+        for level in levels {
+            let values: Vec<_> = points
+                .iter()
+                .filter(|[ordering, _]| ordering == level)
+                .map(|[_, value]| *value)
+                .collect();
+            let n = values.len() as f64;
+            let mean = (!values.is_empty()).then(|| values.iter().sum::<f64>() / n);
+            let standard_deviation = mean.and_then(|mean| {
+                let ddof = key.ddof as f64;
+                (n > ddof).then(|| {
+                    (values
+                        .iter()
+                        .map(|value| (value - mean).powi(2))
+                        .sum::<f64>()
+                        / (n - ddof))
+                        .sqrt()
+                })
+            });
+            means.push(mean);
+            standard_deviations.push(standard_deviation);
+        }
+        let ordered: Vec<_> = means.iter().flatten().copied().collect();
+        trends.push(Trend {
+            name: name.unwrap_or_default().to_owned(),
+            means,
+            standard_deviations,
+            slope: slope(&points),
+            mann_kendall: mann_kendall(&ordered),
+        });
+    }
+    Ok(trends)
+}
@@ -1,16 +1,29 @@
 use crate::{
-    app::states::calculation::settings::{Correlation, Settings, StereospecificNumbers, Threshold},
-    r#const::{SAMPLE, THRESHOLD},
-    utils::HashedDataFrame,
+    app::{
+        computers::calculation::clustering::sort,
+        states::calculation::settings::{
+            Correction, Correlation, Settings, StereospecificNumbers, Threshold,
+        },
+    },
+    r#const::{
+        CORRELATION, COUNT, P_VALUE, Q_VALUE, SAMPLE, THRESHOLD,
+        design::{BATCH, REPLICATE},
+    },
+    utils::{
+        HashedDataFrame, HashedMetaDataFrame,
+        metadata::{frame_treatments, sample},
+        statistics::{benjamini_hochberg, bonferroni, correlation_p_value},
+    },
 };
 use const_format::formatcp;
 use egui::util::cache::{ComputerMut, FrameCache};
+use indexmap::IndexMap;
 use lipid::prelude::*;
 use polars::prelude::*;
 use polars_ext::prelude::*;
+use std::collections::HashMap;
 use tracing::instrument;
 
-const CORRELATION: &str = "Correlation";
 const LABEL1: &str = formatcp!("{LABEL}[1]");
 const LABEL2: &str = formatcp!("{LABEL}[2]");
 const SAMPLE1: &str = formatcp!("{SAMPLE}[1]");
@@ -27,6 +40,7 @@ impl Computer {
     #[instrument(skip(self), err)]
     fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
         let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = sort(lazy_frame, key.order)?;
         lazy_frame = filter_and_sort(lazy_frame, key);
         lazy_frame = compute(lazy_frame, key)?;
         lazy_frame.collect()
@@ -43,8 +57,12 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash, PartialEq)]
 pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) frames: &'a [HashedMetaDataFrame],
     pub(crate) chaddock: bool,
+    pub(crate) collection: bool,
+    pub(crate) correction: Correction,
     pub(crate) correlation: Correlation,
+    pub(crate) order: &'a [String],
     pub(crate) precision: usize,
     pub(crate) significant: bool,
     pub(crate) stereospecific_numbers: StereospecificNumbers,
@@ -52,11 +70,22 @@ pub(crate) struct Key<'a> {
 }
 
 impl<'a> Key<'a> {
-    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+    pub(crate) fn new(
+        frame: &'a HashedDataFrame,
+        frames: &'a [HashedMetaDataFrame],
+        settings: &'a Settings,
+    ) -> Self {
         Self {
             frame,
+            frames: match settings.index {
+                Some(index) => &frames[index..=index],
+                None => frames,
+            },
             chaddock: settings.chaddock,
+            collection: settings.collection,
+            correction: settings.correction,
             correlation: settings.correlation,
+            order: &settings.order,
             precision: settings.precision,
             significant: settings.significant,
             stereospecific_numbers: settings.stereospecific_numbers,
@@ -85,14 +114,17 @@ fn filter_and_sort(lazy_frame: LazyFrame, key: Key) -> LazyFrame {
 }
 
 fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    // Labels
-    // Нужны отфильтрованные и отсортированные метки.
-    let labels = lazy_frame.clone().select([col(LABEL)]).collect()?;
     // Select
     lazy_frame = lazy_frame.select([
         col(LABEL),
         col(key.stereospecific_numbers.to_string()).alias(SAMPLE),
     ]);
+    if key.collection {
+        lazy_frame = collection(lazy_frame, key.frames)?;
+    }
+    // Labels
+    // Нужны отфильтрованные и отсортированные метки.
+    let labels = lazy_frame.clone().select([col(LABEL)]).collect()?;
     // Cross join
     // Установить maintain_order
     lazy_frame = lazy_frame
@@ -121,16 +153,37 @@ fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
                 keep_nulls: true,
             },
         );
-    // Correlation and format
-    lazy_frame =
-        lazy_frame
-            .group_by_stable([col(LABEL1), col(LABEL2)])
-            .agg([match key.correlation {
+    // Correlation and count of pairs
+    let mut data_frame = lazy_frame
+        .group_by_stable([col(LABEL1), col(LABEL2)])
+        .agg([
+            match key.correlation {
                 Correlation::Pearson => pearson_corr(col(SAMPLE1), col(SAMPLE2)),
                 Correlation::SpearmanRank => spearman_rank_corr(col(SAMPLE1), col(SAMPLE2), false),
             }
-            .precision(key.precision, key.significant)
-            .alias(CORRELATION)]);
+            .alias(CORRELATION),
+            col(SAMPLE1)
+                .is_not_null()
+                .and(col(SAMPLE2).is_not_null())
+                .sum()
+                .cast(DataType::UInt32)
+                .alias(COUNT),
+        ])
+        .collect()?;
+    // Significance
+    significance(&mut data_frame, key.correction)?;
+    // Format
+    lazy_frame = data_frame.lazy().select([
+        col(LABEL1),
+        col(LABEL2),
+        as_struct(vec![
+            col(CORRELATION).precision(key.precision, key.significant),
+            col(COUNT),
+            col(P_VALUE),
+            col(Q_VALUE),
+        ])
+        .alias(CORRELATION),
+    ]);
     // Pivot
     lazy_frame = lazy_frame.pivot(
         by_name([LABEL2], true),
@@ -144,3 +197,141 @@ fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     // Переименовываем `LABEL[1]` в `LABEL`
     Ok(lazy_frame.rename([LABEL1], [LABEL], true))
 }
+
+/// Collection.
+///
+/// Samples are the frames with the same design fields or name stem (without
+/// replicate number), the replicates of each sample are averaged. The numeric
+/// metadata variables and the treatments (from the treatment field or the
+/// name, e.g. `{NaCl;SN-2}`) are appended as
+/// additional rows, so the fatty acids are correlated with them too.
+fn collection(lazy_frame: LazyFrame, frames: &[HashedMetaDataFrame]) -> PolarsResult<LazyFrame> {
+    // Samples
+    let mut samples = IndexMap::<_, Vec<_>>::new();
+    for (index, frame) in frames.iter().enumerate() {
+        samples.entry(sample(frame)).or_default().push(index);
+    }
+    let mean = |value: &dyn Fn(usize) -> Option<f64>| -> Vec<Option<f64>> {
+        samples
+            .values()
+            .map(|indices| {
+                let values: Vec<_> = indices.iter().filter_map(|&index| value(index)).collect();
+                (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+            })
+            .collect()
+    };
+    // Fatty acids
+    let data_frame = lazy_frame
+        .explode(
+            cols([SAMPLE]),
+            ExplodeOptions {
+                empty_as_null: true,
+                keep_nulls: true,
+            },
+        )
+        .collect()?;
+    let labels = data_frame[LABEL].str()?;
+    let values = data_frame[SAMPLE].f64()?;
+    let mut names = Vec::new();
+    let mut rows = Vec::new();
+    for row in (0..data_frame.height()).step_by(frames.len().max(1)) {
+        names.push(labels.get(row).map(ToOwned::to_owned));
+        rows.push(mean(&|index| values.get(row + index)));
+    }
+    // Variables
+    let mut variables = IndexMap::<_, Vec<_>>::new();
+    for (index, frame) in frames.iter().enumerate() {
+        for (key, value) in frame.meta.iter() {
+            // Replicate and batch numbers are design keys, not variables
+            if [REPLICATE, BATCH].contains(&key.as_str()) {
+                continue;
+            }
+            if let Ok(value) = value.trim().parse::<f64>() {
+                variables
+                    .entry(key.clone())
+                    .or_insert_with(|| vec![None; frames.len()])[index] = Some(value);
+            }
+        }
+        for treatment in frame_treatments(frame) {
+            variables
+                .entry(treatment.to_owned())
+                .or_insert_with(|| vec![Some(0.0); frames.len()])[index] = Some(1.0);
+        }
+    }
+    for (name, values) in variables {
+        // Constant variables can not be correlated
+        let mut unique = values.iter().flatten();
+        let first = unique.next();
+        if unique.all(|value| Some(value) == first) {
+            continue;
+        }
+        names.push(Some(name));
+        rows.push(mean(&|index| values[index]));
+    }
+    let data_frame = df! {
+        LABEL => names,
+        SAMPLE => rows
+            .into_iter()
+            .map(|row| Series::new(PlSmallStr::EMPTY, row))
+            .collect::<Vec<_>>(),
+    }?;
+    Ok(data_frame.lazy())
+}
+
+/// P-values and q-values (corrected for multiple testing).
+///
+/// The matrix is symmetric, so each pair of labels is tested once and the
+/// mirrored pair (matched by the swapped labels, not by position, because
+/// duplicate or null labels collapse into one group) takes the same values,
+/// the diagonal is not tested.
+fn significance(data_frame: &mut DataFrame, correction: Correction) -> PolarsResult<()> {
+    let labels1 = data_frame[LABEL1].str()?;
+    let labels2 = data_frame[LABEL2].str()?;
+    let indices: HashMap<_, _> = labels1
+        .iter()
+        .zip(labels2)
+        .enumerate()
+        .map(|(index, labels)| (labels, index))
+        .collect();
+    let correlations = data_frame[CORRELATION].f64()?;
+    let counts = data_frame[COUNT].u32()?;
+    let mut p_values = vec![None; data_frame.height()];
+    let mut pairs = Vec::new();
+    for (index, ((label1, label2), (correlation, count))) in labels1
+        .iter()
+        .zip(labels2)
+        .zip(correlations.iter().zip(counts))
+        .enumerate()
+    {
+        if label1 == label2 {
+            continue;
+        }
+        let mirror = indices.get(&(label2, label1)).copied();
+        // Tested once, by the first of the pair
+        if mirror.is_some_and(|mirror| mirror < index) {
+            continue;
+        }
+        p_values[index] = correlation
+            .zip(count)
+            .and_then(|(correlation, count)| correlation_p_value(correlation, count));
+        pairs.push((index, mirror));
+    }
+    let tested: Vec<_> = pairs.iter().map(|&(index, _)| p_values[index]).collect();
+    let corrected = match correction {
+        Correction::None => tested,
+        Correction::Bonferroni => bonferroni(&tested),
+        Correction::BenjaminiHochberg => benjamini_hochberg(&tested),
+    };
+    let mut q_values = vec![None; data_frame.height()];
+    for ((index, mirror), q_value) in pairs.into_iter().zip(corrected) {
+        q_values[index] = q_value;
+        // Mirror
+        if let Some(mirror) = mirror {
+            p_values[mirror] = p_values[index];
+            q_values[mirror] = q_value;
+        }
+    }
+    data_frame.with_column(Column::new(P_VALUE.into(), p_values))?;
+    data_frame.with_column(Column::new(Q_VALUE.into(), q_values))?;
+    Ok(())
+}
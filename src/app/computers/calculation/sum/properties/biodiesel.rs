@@ -1,23 +1,35 @@
 use crate::{
     app::states::calculation::settings::Settings,
-    r#const::{MEAN, NAME, SAMPLE, STANDARD_DEVIATION, THRESHOLD},
+    r#const::{MEAN, NAME, SAMPLE, STANDARD_DEVIATION, THRESHOLD, relative_atomic_mass::CH2},
     utils::HashedDataFrame,
 };
 use egui::util::cache::{ComputerMut, FrameCache};
 use lipid::prelude::*;
 use polars::prelude::*;
 use polars_ext::prelude::*;
+use std::{
+    f64::consts::E,
+    fmt::{self, Display, Formatter},
+};
 use tracing::instrument;
 
-const BIODIESEL_PROPERTIES: [&str; 6] = [
+const BIODIESEL_PROPERTIES: [&str; 11] = [
     "CetaneNumber",
+    "CloudPoint",
     "ColdFilterPluggingPoint",
     "DegreeOfUnsaturation",
+    "Density",
+    "HigherHeatingValue",
     "IodineValue",
+    "KinematicViscosity",
     "LongChainSaturatedFactor",
     "OxidationStability",
+    "PourPoint",
 ];
 
+/// Biodiesel standards
+pub(crate) const STANDARDS: [&str; 2] = ["EN14214", "ASTMD6751"];
+
 /// Calculation biodiesel properties computed
 pub(crate) type Computed = FrameCache<Value, Computer>;
 
@@ -95,11 +107,16 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
                 .try_map(|property| -> PolarsResult<_> {
                     let array = eval_arr(col(stereospecific_numbers), |expr| match property {
                         "CetaneNumber" => cetane_number(expr),
+                        "CloudPoint" => cloud_point(expr),
                         "ColdFilterPluggingPoint" => cold_filter_plugging_point(expr),
                         "DegreeOfUnsaturation" => degree_of_unsaturation(expr),
+                        "Density" => density(expr),
+                        "HigherHeatingValue" => higher_heating_value(expr),
                         "IodineValue" => iodine_value(expr),
+                        "KinematicViscosity" => kinematic_viscosity(expr),
                         "LongChainSaturatedFactor" => long_chain_saturated_factor(expr),
                         "OxidationStability" => oxidation_stability(expr),
+                        "PourPoint" => pour_point(expr),
                         _ => unreachable!(),
                     })?;
                     Ok(as_struct(vec![
@@ -137,6 +154,13 @@ fn cetane_number(expr: Expr) -> Expr {
     col(FATTY_ACID).fatty_acid().cetane_number(expr)
 }
 
+/// CP = 0.526 * C16:0 - 4.992 (°C)
+///
+/// [Sarin et al. (2009)](https://doi.org/10.1021/ef900337g)
+fn cloud_point(expr: Expr) -> Expr {
+    lit(0.526) * palmitic(expr) - lit(4.992)
+}
+
 fn cold_filter_plugging_point(expr: Expr) -> Expr {
     col(FATTY_ACID)
         .fatty_acid()
@@ -147,10 +171,37 @@ fn degree_of_unsaturation(expr: Expr) -> Expr {
     col(FATTY_ACID).fatty_acid().degree_of_unsaturation(expr)
 }
 
+/// ρ_i = 0.8463 + 4.9 / M_i + 0.0118 * N_i (g/cm³, 20 °C), ρ = Σ z_i * ρ_i
+///
+/// Reduced to 15 °C with dρ/dT = -0.00072 g/(cm³·K), result in kg/m³.
+///
+/// [Ramírez-Verduzco et al. (2012)](https://doi.org/10.1016/j.fuel.2011.09.045)
+fn density(expr: Expr) -> Expr {
+    let density = lit(0.8463) + lit(4.9) / molar_mass() + lit(0.0118) * double_bounds();
+    ((expr * density).sum() + lit(5.0 * 0.00072)) * lit(1000)
+}
+
+/// δ_i = 46.19 - 1794 / M_i - 0.21 * N_i (MJ/kg), δ = Σ z_i * δ_i
+///
+/// [Ramírez-Verduzco et al. (2012)](https://doi.org/10.1016/j.fuel.2011.09.045)
+fn higher_heating_value(expr: Expr) -> Expr {
+    let higher_heating_value = lit(46.19) - lit(1794) / molar_mass() - lit(0.21) * double_bounds();
+    (expr * higher_heating_value).sum()
+}
+
 fn iodine_value(expr: Expr) -> Expr {
     BiodieselProperties::iodine_value(col(FATTY_ACID).fatty_acid(), expr)
 }
 
+/// ln(ν_i) = -12.503 + 2.496 * ln(M_i) - 0.178 * N_i (mm²/s, 40 °C), ln(ν) = Σ z_i * ln(ν_i)
+///
+/// [Ramírez-Verduzco et al. (2012)](https://doi.org/10.1016/j.fuel.2011.09.045)
+fn kinematic_viscosity(expr: Expr) -> Expr {
+    let logarithm =
+        lit(-12.503) + lit(2.496) * molar_mass().log(lit(E)) - lit(0.178) * double_bounds();
+    (expr * logarithm).sum().exp()
+}
+
 fn long_chain_saturated_factor(expr: Expr) -> Expr {
     col(FATTY_ACID)
         .fatty_acid()
@@ -160,3 +211,111 @@ fn long_chain_saturated_factor(expr: Expr) -> Expr {
 fn oxidation_stability(expr: Expr) -> Expr {
     col(FATTY_ACID).fatty_acid().oxidation_stability(expr)
 }
+
+/// PP = 0.571 * C16:0 - 12.240 (°C)
+///
+/// [Sarin et al. (2009)](https://doi.org/10.1021/ef900337g)
+fn pour_point(expr: Expr) -> Expr {
+    lit(0.571) * palmitic(expr) - lit(12.240)
+}
+
+/// Palmitic acid, C16:0 (%)
+fn palmitic(expr: Expr) -> Expr {
+    let palmitic = col(FATTY_ACID)
+        .fatty_acid()
+        .carbon()
+        .eq(lit(16))
+        .and(col(FATTY_ACID).fatty_acid().is_saturated());
+    (expr * palmitic.cast(DataType::Float64)).sum() * lit(100)
+}
+
+/// Fatty acid methyl ester molar mass
+fn molar_mass() -> Expr {
+    col(FATTY_ACID).fatty_acid().relative_atomic_mass(None) + lit(CH2)
+}
+
+fn double_bounds() -> Expr {
+    col(FATTY_ACID)
+        .fatty_acid()
+        .double_bounds_unsaturation()
+        .cast(DataType::Float64)
+}
+
+/// Biodiesel standard limits
+///
+/// EN 14214:2012, ASTM D6751-12
+pub(crate) fn limits(name: &str) -> [Option<Limit>; 2] {
+    match name {
+        "CetaneNumber" => [Some(Limit::min(51.0)), Some(Limit::min(47.0))],
+        "Density" => [Some(Limit::new(860.0, 900.0)), None],
+        "IodineValue" => [Some(Limit::max(120.0)), None],
+        "KinematicViscosity" => [Some(Limit::new(3.5, 5.0)), Some(Limit::new(1.9, 6.0))],
+        "OxidationStability" => [Some(Limit::min(8.0)), Some(Limit::min(3.0))],
+        _ => [None, None],
+    }
+}
+
+/// Limit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Limit {
+    pub(crate) min: Option<f64>,
+    pub(crate) max: Option<f64>,
+}
+
+impl Limit {
+    const fn new(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    const fn min(min: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    const fn max(max: f64) -> Self {
+        Self {
+            min: None,
+            max: Some(max),
+        }
+    }
+
+    pub(crate) fn contains(&self, value: f64) -> bool {
+        self.min.is_none_or(|min| min <= value) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.min {
+            Some(min) => write!(f, "[{min};")?,
+            None => f.write_str("[-∞;")?,
+        }
+        match self.max {
+            Some(max) => write!(f, "{max}]"),
+            None => f.write_str("+∞]"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn limit() {
+        let [en14214, astm_d6751] = limits("KinematicViscosity");
+        assert_eq!(en14214.map(|limit| limit.contains(4.2)), Some(true));
+        assert_eq!(en14214.map(|limit| limit.contains(5.5)), Some(false));
+        assert_eq!(astm_d6751.map(|limit| limit.contains(5.5)), Some(true));
+        assert_eq!(
+            limits("CetaneNumber")[0].map(|limit| limit.to_string()),
+            Some("[51;+∞]".to_owned()),
+        );
+        assert_eq!(limits("PourPoint"), [None, None]);
+    }
+}
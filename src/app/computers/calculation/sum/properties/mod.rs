@@ -10,6 +10,10 @@ use polars_ext::prelude::*;
 use std::num::NonZeroI8;
 use tracing::instrument;
 
+const ALLYLIC_POSITIONS: &str = "AllylicPositions";
+const BIS_ALLYLIC_POSITIONS: &str = "BisAllylicPositions";
+const OXIDIZABILITY: &str = "Oxidizability";
+
 /// Calculation properties computed
 pub(crate) type Computed = FrameCache<Value, Computer>;
 
@@ -20,7 +24,10 @@ pub(crate) struct Computer;
 impl Computer {
     #[instrument(skip(self), err)]
     fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
-        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        let mut data_frame = key.frame.data_frame.clone();
+        // Positions
+        data_frame.hstack_mut(&positions(&data_frame)?)?;
+        let mut lazy_frame = data_frame.lazy();
         // Filter
         lazy_frame = filter(lazy_frame, key);
         // Compute
@@ -84,6 +91,8 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
         STEREOSPECIFIC_NUMBERS123,
         STEREOSPECIFIC_NUMBERS13,
         STEREOSPECIFIC_NUMBERS2,
+        STEREOSPECIFIC_NUMBERS1,
+        STEREOSPECIFIC_NUMBERS3,
     ] {
         let expr = concat_arr(
             key.indices
@@ -118,6 +127,10 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
                         "PolyunsaturatedToSaturated" => polyunsaturated_to_saturated(expr),
                         "UnsaturationIndex" => unsaturation_index(expr),
                         "IodineValue" => iodine_value(expr),
+                        "AllylicPositionEquivalent" => allylic_position_equivalent(expr),
+                        "BisAllylicPositionEquivalent" => bis_allylic_position_equivalent(expr),
+                        "PeroxidizabilityIndex" => peroxidizability_index(expr),
+                        "CalculatedOxidizability" => calculated_oxidizability(expr),
                         _ => unreachable!(),
                     })?;
                     Ok(as_struct(vec![
@@ -229,4 +242,212 @@ fn iodine_value(expr: Expr) -> Expr {
     (expr * col(FATTY_ACID).fatty_acid().iodine_value()).sum()
 }
 
+/// APE = Σ (2 * A_monoenoic + 4 * A_polyenoic)
+///
+/// Knothe (2002)
+fn allylic_position_equivalent(expr: Expr) -> Expr {
+    (expr * lit(100) * allylic_positions()).sum()
+}
+
+/// BAPE = Σ (N - 1) * A_N
+///
+/// Knothe (2002)
+fn bis_allylic_position_equivalent(expr: Expr) -> Expr {
+    (expr * lit(100) * bis_allylic_positions()).sum()
+}
+
+/// PI = 0.025 * A_1 + A_2 + 2 * A_3 + 4 * A_4 + 6 * A_5 + 8 * A_6
+///
+/// Cosgrove et al. (1987)
+fn peroxidizability_index(expr: Expr) -> Expr {
+    (expr * lit(100) * peroxidizability()).sum()
+}
+
+/// COX = (A_1 + 10.3 * A_2 + 21.6 * A_3) / 100
+///
+/// Fatemi and Hammond (1980)
+fn calculated_oxidizability(expr: Expr) -> Expr {
+    (expr * col(OXIDIZABILITY)).sum()
+}
+
+/// Allylic positions of the fatty acid
+///
+/// Four for polyenoic fatty acids with bis-allylic positions, otherwise the
+/// number of allylic positions (two for monoenoic and conjugated fatty acids).
+fn allylic_positions() -> Expr {
+    when(col(BIS_ALLYLIC_POSITIONS).gt(lit(0)))
+        .then(lit(4.0))
+        .otherwise(col(ALLYLIC_POSITIONS).cast(DataType::Float64))
+}
+
+/// Bis-allylic positions of the fatty acid
+///
+/// `N - 1` for methylene interrupted, zero for conjugated fatty acids.
+fn bis_allylic_positions() -> Expr {
+    col(BIS_ALLYLIC_POSITIONS).cast(DataType::Float64)
+}
+
+/// Peroxidizability of the fatty acid
+///
+/// The weights of the dienoic to hexaenoic fatty acids are assigned by the
+/// number of bis-allylic positions, conjugated fatty acids are weighted as
+/// monoenoic.
+fn peroxidizability() -> Expr {
+    let bis_allylic = col(BIS_ALLYLIC_POSITIONS);
+    when(bis_allylic.clone().eq(lit(1)))
+        .then(lit(1.0))
+        .when(bis_allylic.clone().eq(lit(2)))
+        .then(lit(2.0))
+        .when(bis_allylic.clone().eq(lit(3)))
+        .then(lit(4.0))
+        .when(bis_allylic.clone().eq(lit(4)))
+        .then(lit(6.0))
+        .when(bis_allylic.gt_eq(lit(5)))
+        .then(lit(8.0))
+        .when(col(ALLYLIC_POSITIONS).gt(lit(0)))
+        .then(lit(0.025))
+        .otherwise(lit(0.0))
+}
+
+/// Relative oxidizability of the fatty acid (Fatemi and Hammond, 1980)
+///
+/// The weights are assigned by the number of bis-allylic positions, polyenoic
+/// fatty acids with more than two are weighted as trienoic, conjugated fatty
+/// acids as monoenoic.
+pub(crate) fn oxidizability(fatty_acid: &FattyAcid) -> f64 {
+    Positions::from(fatty_acid).oxidizability()
+}
+
+/// Allylic and bis-allylic positions and relative oxidizability of each fatty
+/// acid
+fn positions(data_frame: &DataFrame) -> PolarsResult<[Column; 3]> {
+    let fatty_acid = data_frame.try_fatty_acid()?;
+    let mut allylic = Vec::with_capacity(data_frame.height());
+    let mut bis_allylic = Vec::with_capacity(data_frame.height());
+    let mut oxidizability = Vec::with_capacity(data_frame.height());
+    for row in 0..data_frame.height() {
+        let positions = fatty_acid
+            .get(row)?
+            .map(|fatty_acid| Positions::from(&fatty_acid));
+        allylic.push(positions.map(|positions| positions.allylic));
+        bis_allylic.push(positions.map(|positions| positions.bis_allylic));
+        oxidizability.push(positions.map(Positions::oxidizability));
+    }
+    Ok([
+        Column::new(ALLYLIC_POSITIONS.into(), allylic),
+        Column::new(BIS_ALLYLIC_POSITIONS.into(), bis_allylic),
+        Column::new(OXIDIZABILITY.into(), oxidizability),
+    ])
+}
+
+/// Allylic positions
+///
+/// A methylene group next to one double bound is allylic, between two double
+/// bounds is bis-allylic.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Positions {
+    allylic: u8,
+    bis_allylic: u8,
+}
+
+impl Positions {
+    /// Positions of the olefinic double bounds with the given indices
+    fn new(carbon: u8, indices: &[u8]) -> Self {
+        let bound = |index: u8| indices.contains(&index);
+        let mut positions = Self::default();
+        for carbon in 2..=carbon {
+            // Skip carbons of the double bounds
+            if bound(carbon) || bound(carbon - 1) {
+                continue;
+            }
+            let before = carbon > 2 && bound(carbon - 2);
+            let after = bound(carbon + 1);
+            match (before, after) {
+                (true, true) => positions.bis_allylic += 1,
+                (true, false) | (false, true) => positions.allylic += 1,
+                (false, false) => {}
+            }
+        }
+        positions
+    }
+
+    /// Positions of the double bounds with unknown indices, assumed methylene
+    /// interrupted
+    fn methylene_interrupted(bounds: u8) -> Self {
+        Self {
+            allylic: if bounds > 0 { 2 } else { 0 },
+            bis_allylic: bounds.saturating_sub(1),
+        }
+    }
+
+    /// Relative oxidizability
+    fn oxidizability(self) -> f64 {
+        match self.bis_allylic {
+            1 => 10.3,
+            2.. => 21.6,
+            0 if self.allylic > 0 => 1.0,
+            0 => 0.0,
+        }
+    }
+}
+
+impl From<&FattyAcid> for Positions {
+    fn from(fatty_acid: &FattyAcid) -> Self {
+        let olefinic = fatty_acid
+            .unsaturated
+            .iter()
+            .filter(|unsaturated| unsaturated.triple != Some(true));
+        match olefinic
+            .clone()
+            .map(|unsaturated| unsaturated.index)
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(indices) => Self::new(fatty_acid.carbon, &indices),
+            None => Self::methylene_interrupted(olefinic.count() as _),
+        }
+    }
+}
+
 pub(crate) mod biodiesel;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let positions = |allylic, bis_allylic| Positions {
+            allylic,
+            bis_allylic,
+        };
+        // Palmitic
+        assert_eq!(Positions::new(16, &[]), positions(0, 0));
+        // Oleic
+        assert_eq!(Positions::new(18, &[9]), positions(2, 0));
+        // Linoleic
+        assert_eq!(Positions::new(18, &[9, 12]), positions(2, 1));
+        // α-Linolenic
+        assert_eq!(Positions::new(18, &[9, 12, 15]), positions(2, 2));
+        // Rumenic (conjugated linoleic)
+        assert_eq!(Positions::new(18, &[9, 11]), positions(2, 0));
+        // Punicic
+        assert_eq!(Positions::new(18, &[9, 11, 13]), positions(2, 0));
+        // Sciadonic (non methylene interrupted)
+        assert_eq!(Positions::new(20, &[5, 11, 14]), positions(4, 1));
+        // Unknown indices
+        assert_eq!(Positions::methylene_interrupted(3), positions(2, 2));
+    }
+
+    #[test]
+    fn oxidizability() {
+        let oxidizability = |carbon, indices| Positions::new(carbon, indices).oxidizability();
+        assert_eq!(oxidizability(16, &[]), 0.0);
+        assert_eq!(oxidizability(18, &[9]), 1.0);
+        assert_eq!(oxidizability(18, &[9, 12]), 10.3);
+        assert_eq!(oxidizability(18, &[9, 12, 15]), 21.6);
+        assert_eq!(oxidizability(20, &[5, 8, 11, 14, 17]), 21.6);
+        // Conjugated as monoenoic
+        assert_eq!(oxidizability(18, &[9, 11]), 1.0);
+        assert_eq!(oxidizability(18, &[9, 11, 13]), 1.0);
+    }
+}
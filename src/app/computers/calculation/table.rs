@@ -1,5 +1,8 @@
 use crate::{
-    app::states::calculation::settings::{Settings, Threshold},
+    app::{
+        computers::calculation::clustering::sort,
+        states::calculation::settings::{Settings, Threshold},
+    },
     r#const::*,
     utils::{
         HashedDataFrame,
@@ -122,6 +125,14 @@ const SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
             PlSmallStr::from_static(STEREOSPECIFIC_NUMBERS13),
             DataType::Array(Box::new(DataType::Float64), 0),
         ),
+        Field::new(
+            PlSmallStr::from_static(STEREOSPECIFIC_NUMBERS1),
+            DataType::Array(Box::new(DataType::Float64), 0),
+        ),
+        Field::new(
+            PlSmallStr::from_static(STEREOSPECIFIC_NUMBERS3),
+            DataType::Array(Box::new(DataType::Float64), 0),
+        ),
         Field::new(
             PlSmallStr::from_static(STANDARD),
             DataType::Struct(vec![
@@ -136,10 +147,12 @@ const SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
     ]))
 });
 
-const STEREOSPECIFIC_NUMBERS: [&str; 3] = [
+const STEREOSPECIFIC_NUMBERS: [&str; 5] = [
     STEREOSPECIFIC_NUMBERS123,
     STEREOSPECIFIC_NUMBERS2,
     STEREOSPECIFIC_NUMBERS13,
+    STEREOSPECIFIC_NUMBERS1,
+    STEREOSPECIFIC_NUMBERS3,
 ];
 
 /// Table calculation computed
@@ -155,6 +168,7 @@ impl Computer {
         schema(&key.frame)?;
         println!("T: {:?}", key);
         let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        lazy_frame = sort(lazy_frame, key.order)?;
         lazy_frame = filter_and_sort(lazy_frame, key);
         lazy_frame = format(lazy_frame, key)?;
         let data_frame = lazy_frame.collect()?;
@@ -174,6 +188,7 @@ pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
     pub(crate) ddof: u8,
     pub(crate) normalize_factors: bool,
+    pub(crate) order: &'a [String],
     pub(crate) percent: bool,
     pub(crate) precision: usize,
     pub(crate) significant: bool,
@@ -186,6 +201,7 @@ impl<'a> Key<'a> {
             frame,
             ddof: settings.ddof,
             normalize_factors: settings.normalize_factors,
+            order: &settings.order,
             percent: settings.percent,
             precision: settings.precision,
             significant: settings.significant,
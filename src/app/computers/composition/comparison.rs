@@ -0,0 +1,117 @@
+use super::{composition_key, label};
+use crate::{
+    app::states::{
+        calculation::settings::Threshold,
+        composition::settings::{Composition, Settings},
+    },
+    r#const::{KEY, MEASURED, PREDICTED, RESIDUAL, THRESHOLD, VALUE},
+    utils::HashedDataFrame,
+};
+use egui::{
+    emath::OrderedFloat,
+    util::cache::{ComputerMut, FrameCache},
+};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Comparison composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Comparison composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        // | Threshold | Label     | Triacylglycerol | Value         |
+        // | ---       | ---       | ---             | ---           |
+        // | bool      | struct[3] | struct[3]       | array[f64, n] |
+        let mut predicted = key.predicted.data_frame.clone().lazy();
+        if key.threshold.filter {
+            predicted = predicted.filter(col(THRESHOLD));
+        }
+        let predicted = compute(predicted, col(VALUE).arr().mean(), PREDICTED, key)?;
+        // | Label     | Triacylglycerol | Value |
+        // | ---       | ---             | ---   |
+        // | struct[3] | struct[3]       | f64   |
+        let measured = key.measured.data_frame.clone().lazy();
+        let measured = compute(measured, col(VALUE).cast(DataType::Float64), MEASURED, key)?;
+        let lazy_frame = predicted
+            .join(
+                measured,
+                [col(KEY)],
+                [col(KEY)],
+                JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+            )
+            .select([
+                col(KEY),
+                col(PREDICTED).fill_null(0),
+                col(MEASURED).fill_null(0),
+            ])
+            .with_column((col(MEASURED) - col(PREDICTED)).alias(RESIDUAL))
+            .sort_by_exprs(
+                [col(MEASURED), col(PREDICTED)],
+                SortMultipleOptions::default()
+                    .with_maintain_order(true)
+                    .with_order_descending(true),
+            );
+        // | Key | Predicted | Measured | Residual |
+        // | --- | ---       | ---      | ---      |
+        // | str | f64       | f64      | f64      |
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Comparison composition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) predicted: &'a HashedDataFrame,
+    pub(crate) measured: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+    pub(crate) adduct: OrderedFloat<f64>,
+    pub(crate) percent: bool,
+    pub(crate) round_mass: u32,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        predicted: &'a HashedDataFrame,
+        measured: &'a HashedDataFrame,
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            predicted,
+            measured,
+            composition: settings.comparison,
+            adduct: OrderedFloat(settings.adduct),
+            percent: settings.percent,
+            round_mass: settings.round_mass,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Comparison composition value
+type Value = DataFrame;
+
+/// Sums values by composition key and normalizes them
+fn compute(lazy_frame: LazyFrame, value: Expr, name: &str, key: Key) -> PolarsResult<LazyFrame> {
+    let expr = composition_key(key.composition, key.adduct.0, key.round_mass);
+    let mut normalized = col(name) / col(name).sum();
+    if key.percent {
+        normalized = normalized * lit(100.0);
+    }
+    Ok(lazy_frame
+        .select([label(expr, key.composition)?.alias(KEY), value.alias(name)])
+        .group_by([col(KEY)])
+        .agg([col(name).sum()])
+        .with_column(normalized.alias(name)))
+}
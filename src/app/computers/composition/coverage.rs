@@ -0,0 +1,129 @@
+use super::{composition_key, label};
+use crate::{
+    app::states::{
+        calculation::settings::Threshold,
+        composition::settings::{Composition, Settings},
+    },
+    r#const::{CUMULATIVE, KEY, THRESHOLD, VALUE},
+    utils::HashedDataFrame,
+};
+use egui::{
+    emath::OrderedFloat,
+    util::cache::{ComputerMut, FrameCache},
+};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Coverage composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Coverage composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        // | Threshold | Label     | Triacylglycerol | Value         |
+        // | ---       | ---       | ---             | ---           |
+        // | bool      | struct[3] | struct[3]       | array[f64, n] |
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        if key.threshold.filter {
+            lazy_frame = lazy_frame.filter(col(THRESHOLD));
+        }
+        lazy_frame = compute(lazy_frame, key)?;
+        // | Key | Value | Cumulative |
+        // | --- | ---   | ---        |
+        // | str | f64   | f64        |
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Coverage composition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+    pub(crate) adduct: OrderedFloat<f64>,
+    pub(crate) percent: bool,
+    pub(crate) round_mass: u32,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            composition: settings.coverage.composition,
+            adduct: OrderedFloat(settings.adduct),
+            percent: settings.percent,
+            round_mass: settings.round_mass,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Coverage composition value
+type Value = DataFrame;
+
+/// Sums mean values by composition key, sorts them in descending order and
+/// accumulates
+fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    let expr = composition_key(key.composition, key.adduct.0, key.round_mass);
+    let mut normalized = col(VALUE) / col(VALUE).sum();
+    if key.percent {
+        normalized = normalized * lit(100.0);
+    }
+    Ok(lazy_frame
+        .select([
+            label(expr, key.composition)?.alias(KEY),
+            col(VALUE).arr().mean(),
+        ])
+        .group_by([col(KEY)])
+        .agg([col(VALUE).sum()])
+        .with_column(normalized)
+        .sort_by_exprs(
+            [col(VALUE), col(KEY)],
+            SortMultipleOptions::default()
+                .with_maintain_order(true)
+                .with_order_descending_multi([true, false]),
+        )
+        .with_column(col(VALUE).cum_sum(false).alias(CUMULATIVE)))
+}
+
+/// Number of the first rows which cumulative value reaches the level
+///
+/// The level is a fraction of the total.
+pub(crate) fn count(data_frame: &DataFrame, level: f64) -> PolarsResult<usize> {
+    let values = data_frame[VALUE].f64()?;
+    let cumulative = data_frame[CUMULATIVE].f64()?;
+    let total = values.sum().unwrap_or_default();
+    Ok(cumulative
+        .iter()
+        .position(|cumulative| cumulative.is_some_and(|cumulative| cumulative >= level * total))
+        .map_or(data_frame.height(), |position| position + 1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coverage() -> PolarsResult<()> {
+        let data_frame = df! {
+            VALUE => [50.0, 30.0, 15.0, 5.0],
+            CUMULATIVE => [50.0, 80.0, 95.0, 100.0],
+        }?;
+        assert_eq!(count(&data_frame, 0.5)?, 1);
+        assert_eq!(count(&data_frame, 0.9)?, 3);
+        assert_eq!(count(&data_frame, 0.95)?, 3);
+        assert_eq!(count(&data_frame, 0.99)?, 4);
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use super::{composition_key, label};
+use crate::{
+    app::states::composition::settings::{Composition, Settings},
+    r#const::{
+        DIFFERENCE, KEY, LEFT, LOG_RATIO, ONLY, RELATIVE_DIFFERENCE, RIGHT, THRESHOLD, VALUE,
+    },
+    utils::HashedDataFrame,
+};
+use egui::{
+    emath::OrderedFloat,
+    util::cache::{ComputerMut, FrameCache},
+};
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Diff composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Diff composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        // | Threshold | Label     | Triacylglycerol | Value         |
+        // | ---       | ---       | ---             | ---           |
+        // | bool      | struct[3] | struct[3]       | array[f64, n] |
+        let left = compute(key.left.data_frame.clone().lazy(), LEFT, key)?;
+        let right = compute(key.right.data_frame.clone().lazy(), RIGHT, key)?;
+        let threshold = |name: &str| format!("{name}{THRESHOLD}");
+        let lazy_frame = left
+            .join(
+                right,
+                [col(KEY)],
+                [col(KEY)],
+                JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+            )
+            .select([
+                col(KEY),
+                col(LEFT).fill_null(0),
+                col(RIGHT).fill_null(0),
+                col(threshold(LEFT)).fill_null(false),
+                col(threshold(RIGHT)).fill_null(false),
+            ])
+            .with_columns([
+                (col(RIGHT) - col(LEFT)).alias(DIFFERENCE),
+                when(col(LEFT).neq(0))
+                    .then(lit(100.0) * (col(RIGHT) - col(LEFT)) / col(LEFT))
+                    .otherwise(lit(NULL).cast(DataType::Float64))
+                    .alias(RELATIVE_DIFFERENCE),
+                when(col(LEFT).neq(0).and(col(RIGHT).neq(0)))
+                    .then((col(RIGHT) / col(LEFT)).log(lit(2.0)))
+                    .otherwise(lit(NULL).cast(DataType::Float64))
+                    .alias(LOG_RATIO),
+                when(col(threshold(LEFT)).and(col(threshold(RIGHT)).not()))
+                    .then(lit(LEFT))
+                    .when(col(threshold(RIGHT)).and(col(threshold(LEFT)).not()))
+                    .then(lit(RIGHT))
+                    .otherwise(lit(NULL).cast(DataType::String))
+                    .alias(ONLY),
+            ])
+            .select([
+                col(KEY),
+                col(LEFT),
+                col(RIGHT),
+                col(DIFFERENCE),
+                col(RELATIVE_DIFFERENCE),
+                col(LOG_RATIO),
+                col(ONLY),
+            ])
+            .sort_by_exprs(
+                [col(DIFFERENCE).abs(), col(KEY)],
+                SortMultipleOptions::default()
+                    .with_maintain_order(true)
+                    .with_order_descending_multi([true, false]),
+            );
+        // | Key | Left | Right | Difference | RelativeDifference | LogRatio | Only |
+        // | --- | ---  | ---   | ---        | ---                | ---      | ---  |
+        // | str | f64  | f64   | f64        | f64                | f64      | str  |
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Diff composition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) left: &'a HashedDataFrame,
+    pub(crate) right: &'a HashedDataFrame,
+    pub(crate) composition: Composition,
+    pub(crate) adduct: OrderedFloat<f64>,
+    pub(crate) percent: bool,
+    pub(crate) round_mass: u32,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        left: &'a HashedDataFrame,
+        right: &'a HashedDataFrame,
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            composition: settings.diff.composition,
+            adduct: OrderedFloat(settings.adduct),
+            percent: settings.percent,
+            round_mass: settings.round_mass,
+        }
+    }
+}
+
+/// Diff composition value
+type Value = DataFrame;
+
+/// Sums mean values by composition key, normalizes them and marks keys with
+/// any species above threshold
+fn compute(lazy_frame: LazyFrame, name: &str, key: Key) -> PolarsResult<LazyFrame> {
+    let expr = composition_key(key.composition, key.adduct.0, key.round_mass);
+    let mut normalized = col(name) / col(name).sum();
+    if key.percent {
+        normalized = normalized * lit(100.0);
+    }
+    Ok(lazy_frame
+        .select([
+            label(expr, key.composition)?.alias(KEY),
+            col(VALUE).arr().mean().alias(name),
+            col(THRESHOLD).alias(format!("{name}{THRESHOLD}")),
+        ])
+        .group_by([col(KEY)])
+        .agg([col(name).sum(), col(format!("{name}{THRESHOLD}")).any(true)])
+        .with_column(normalized.alias(name)))
+}
@@ -0,0 +1,419 @@
+use super::{composition_key, label};
+use crate::{
+    app::states::composition::settings::{Composition, Settings},
+    r#const::{
+        COEFFICIENT_OF_DETERMINATION, KEY, NAME, ROOT_MEAN_SQUARE_ERROR, SELECTIVITY,
+        STANDARD_ERROR, VALUE,
+    },
+    utils::{HashedDataFrame, HashedMetaDataFrame},
+};
+use egui::{
+    emath::OrderedFloat,
+    util::cache::{ComputerMut, FrameCache},
+};
+use indexmap::IndexMap;
+use lipid::prelude::*;
+use polars::prelude::*;
+use std::{iter::zip, ops::RangeInclusive};
+use tracing::instrument;
+
+const INDEX: &str = "Index";
+
+/// Selectivity search range
+const RANGE: RangeInclusive<f64> = -1.0..=2.0;
+
+/// Selectivity search tolerance
+const TOLERANCE: f64 = 1e-9;
+
+/// Fit composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Fit composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let frames = match key.index {
+            Some(index) => &key.frames[index..=index],
+            None => key.frames,
+        };
+        let measured = measured(key)?;
+        let mut names = Vec::with_capacity(frames.len());
+        let mut fits = Vec::with_capacity(frames.len());
+        for frame in frames {
+            let model = Model::new(frame.data.data_frame.clone().lazy(), &measured, key)?;
+            names.push(frame.meta.format(" ").to_string());
+            fits.push(model.fit());
+        }
+        let scale = if key.percent { 100.0 } else { 1.0 };
+        // | Name | Selectivity | StandardError | RootMeanSquareError | CoefficientOfDetermination |
+        // | ---  | ---         | ---           | ---                 | ---                        |
+        // | str  | f64         | f64           | f64                 | f64                        |
+        df! {
+            NAME => names,
+            SELECTIVITY => fits.iter().map(|fit| fit.selectivity).collect::<Vec<_>>(),
+            STANDARD_ERROR => fits.iter().map(|fit| fit.standard_error).collect::<Vec<_>>(),
+            ROOT_MEAN_SQUARE_ERROR => fits
+                .iter()
+                .map(|fit| fit.root_mean_square_error * scale)
+                .collect::<Vec<_>>(),
+            COEFFICIENT_OF_DETERMINATION => fits
+                .iter()
+                .map(|fit| fit.coefficient_of_determination)
+                .collect::<Vec<_>>(),
+        }
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Fit composition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frames: &'a [HashedMetaDataFrame],
+    pub(crate) measured: &'a HashedDataFrame,
+    pub(crate) index: Option<usize>,
+    pub(crate) composition: Composition,
+    pub(crate) adduct: OrderedFloat<f64>,
+    pub(crate) percent: bool,
+    pub(crate) round_mass: u32,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(
+        frames: &'a [HashedMetaDataFrame],
+        measured: &'a HashedDataFrame,
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            frames,
+            measured,
+            index: settings.index,
+            composition: settings.comparison,
+            adduct: OrderedFloat(settings.adduct),
+            percent: settings.percent,
+            round_mass: settings.round_mass,
+        }
+    }
+}
+
+/// Fit composition value
+type Value = DataFrame;
+
+/// Measured values normalized by composition key
+fn measured(key: Key) -> PolarsResult<IndexMap<String, f64>> {
+    let data_frame = key
+        .measured
+        .data_frame
+        .clone()
+        .lazy()
+        .select([
+            label(
+                composition_key(key.composition, key.adduct.0, key.round_mass),
+                key.composition,
+            )?
+            .alias(KEY),
+            col(VALUE).cast(DataType::Float64),
+        ])
+        .group_by_stable([col(KEY)])
+        .agg([col(VALUE).sum()])
+        .collect()?;
+    let keys = data_frame[KEY].str()?;
+    let values = data_frame[VALUE].f64()?;
+    let sum = values.sum().unwrap_or_default();
+    Ok(zip(keys, values)
+        .filter_map(|(key, value)| Some((key?.to_owned(), value? / sum)))
+        .collect())
+}
+
+/// Generalized non-random sn-2 selectivity model
+///
+/// Stereospecific positions are mixed between 1,2,3-random (selectivity 0)
+/// and 1,3-random-2-random (selectivity 1) distributions:
+/// `sn-i = (1 - s) * sn-1,2,3 + s * sn-i`.
+#[derive(Debug)]
+struct Model {
+    stereospecific_numbers123: Vec<f64>,
+    stereospecific_numbers1: Vec<f64>,
+    stereospecific_numbers2: Vec<f64>,
+    stereospecific_numbers3: Vec<f64>,
+    /// Fatty acid indices and composition key index of each species
+    species: Vec<([usize; 3], usize)>,
+    measured: Vec<f64>,
+}
+
+impl Model {
+    fn new(
+        lazy_frame: LazyFrame,
+        measured: &IndexMap<String, f64>,
+        key: Key,
+    ) -> PolarsResult<Self> {
+        // Without stereospecific analysis SN-1 and SN-3 are equal to SN-1,3
+        let schema = lazy_frame.collect_schema()?;
+        let (stereospecific_numbers1, stereospecific_numbers3) =
+            if schema.contains(STEREOSPECIFIC_NUMBERS1) && schema.contains(STEREOSPECIFIC_NUMBERS3)
+            {
+                (STEREOSPECIFIC_NUMBERS1, STEREOSPECIFIC_NUMBERS3)
+            } else {
+                (STEREOSPECIFIC_NUMBERS13, STEREOSPECIFIC_NUMBERS13)
+            };
+        // Fatty acids
+        let value = |name| col(name).cast(DataType::Float64).fill_null(0);
+        let data_frame = lazy_frame
+            .clone()
+            .select([
+                value(STEREOSPECIFIC_NUMBERS123),
+                value(stereospecific_numbers1).alias(STEREOSPECIFIC_NUMBERS1),
+                value(STEREOSPECIFIC_NUMBERS2),
+                value(stereospecific_numbers3).alias(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .collect()?;
+        let values = |name| -> PolarsResult<Vec<f64>> {
+            Ok(normalize(
+                data_frame[name]
+                    .f64()?
+                    .iter()
+                    .map(Option::unwrap_or_default)
+                    .collect(),
+            ))
+        };
+        // Species
+        let indexed = lazy_frame
+            .select([col(LABEL), col(FATTY_ACID)])
+            .with_row_index(INDEX, None);
+        let stereospecific_number = |name| {
+            indexed
+                .clone()
+                .select([as_struct(vec![col(INDEX), col(LABEL), col(FATTY_ACID)]).alias(name)])
+        };
+        let field = |name, field| col(name).struct_().field_by_name(field).alias(name);
+        let data_frame = stereospecific_number(STEREOSPECIFIC_NUMBERS1)
+            .cross_join(stereospecific_number(STEREOSPECIFIC_NUMBERS2), None)
+            .cross_join(stereospecific_number(STEREOSPECIFIC_NUMBERS3), None)
+            .select([
+                as_struct(vec![
+                    field(STEREOSPECIFIC_NUMBERS1, LABEL),
+                    field(STEREOSPECIFIC_NUMBERS2, LABEL),
+                    field(STEREOSPECIFIC_NUMBERS3, LABEL),
+                ])
+                .alias(LABEL),
+                as_struct(vec![
+                    field(STEREOSPECIFIC_NUMBERS1, FATTY_ACID),
+                    field(STEREOSPECIFIC_NUMBERS2, FATTY_ACID),
+                    field(STEREOSPECIFIC_NUMBERS3, FATTY_ACID),
+                ])
+                .alias(TRIACYLGLYCEROL),
+                field(STEREOSPECIFIC_NUMBERS1, INDEX),
+                field(STEREOSPECIFIC_NUMBERS2, INDEX),
+                field(STEREOSPECIFIC_NUMBERS3, INDEX),
+            ])
+            .select([
+                label(
+                    composition_key(key.composition, key.adduct.0, key.round_mass),
+                    key.composition,
+                )?
+                .alias(KEY),
+                col(STEREOSPECIFIC_NUMBERS1),
+                col(STEREOSPECIFIC_NUMBERS2),
+                col(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .collect()?;
+        let mut keys: IndexMap<_, _> = measured.clone();
+        let mut species = Vec::with_capacity(data_frame.height());
+        let stereospecific_numbers = [
+            data_frame[STEREOSPECIFIC_NUMBERS1].idx()?,
+            data_frame[STEREOSPECIFIC_NUMBERS2].idx()?,
+            data_frame[STEREOSPECIFIC_NUMBERS3].idx()?,
+        ];
+        for (row, key) in data_frame[KEY].str()?.iter().enumerate() {
+            let (Some(key), Some(sn1), Some(sn2), Some(sn3)) = (
+                key,
+                stereospecific_numbers[0].get(row),
+                stereospecific_numbers[1].get(row),
+                stereospecific_numbers[2].get(row),
+            ) else {
+                continue;
+            };
+            let entry = keys.entry(key.to_owned());
+            let index = entry.index();
+            entry.or_default();
+            species.push(([sn1 as _, sn2 as _, sn3 as _], index));
+        }
+        Ok(Self {
+            stereospecific_numbers123: values(STEREOSPECIFIC_NUMBERS123)?,
+            stereospecific_numbers1: values(STEREOSPECIFIC_NUMBERS1)?,
+            stereospecific_numbers2: values(STEREOSPECIFIC_NUMBERS2)?,
+            stereospecific_numbers3: values(STEREOSPECIFIC_NUMBERS3)?,
+            species,
+            measured: keys.into_values().collect(),
+        })
+    }
+
+    /// Predicted values by composition key
+    fn predict(&self, selectivity: f64) -> Vec<f64> {
+        let mix = |values: &[f64]| {
+            normalize(
+                zip(&self.stereospecific_numbers123, values)
+                    .map(|(random, value)| {
+                        ((1.0 - selectivity) * random + selectivity * value).max(0.0)
+                    })
+                    .collect(),
+            )
+        };
+        let stereospecific_numbers1 = mix(&self.stereospecific_numbers1);
+        let stereospecific_numbers2 = mix(&self.stereospecific_numbers2);
+        let stereospecific_numbers3 = mix(&self.stereospecific_numbers3);
+        let mut predicted = vec![0.0; self.measured.len()];
+        for &([sn1, sn2, sn3], index) in &self.species {
+            predicted[index] += stereospecific_numbers1[sn1]
+                * stereospecific_numbers2[sn2]
+                * stereospecific_numbers3[sn3];
+        }
+        normalize(predicted)
+    }
+
+    fn residuals(&self, selectivity: f64) -> Vec<f64> {
+        zip(&self.measured, self.predict(selectivity))
+            .map(|(measured, predicted)| measured - predicted)
+            .collect()
+    }
+
+    fn residual_sum_of_squares(&self, selectivity: f64) -> f64 {
+        self.residuals(selectivity)
+            .iter()
+            .map(|residual| residual * residual)
+            .sum()
+    }
+
+    /// Least squares fit
+    fn fit(&self) -> Fit {
+        let selectivity = minimize(|selectivity| self.residual_sum_of_squares(selectivity));
+        let residual_sum_of_squares = self.residual_sum_of_squares(selectivity);
+        let count = self.measured.len();
+        // Standard error from the numerical jacobian
+        let step = 1e-6;
+        let jacobian = zip(
+            self.residuals(selectivity + step),
+            self.residuals(selectivity - step),
+        )
+        .map(|(forward, backward)| ((forward - backward) / (2.0 * step)).powi(2))
+        .sum::<f64>();
+        let standard_error = (count > 1 && jacobian > 0.0)
+            .then(|| (residual_sum_of_squares / (count - 1) as f64 / jacobian).sqrt());
+        let mean = self.measured.iter().sum::<f64>() / count as f64;
+        let total_sum_of_squares = self
+            .measured
+            .iter()
+            .map(|measured| (measured - mean).powi(2))
+            .sum::<f64>();
+        Fit {
+            selectivity,
+            standard_error,
+            root_mean_square_error: (residual_sum_of_squares / count as f64).sqrt(),
+            coefficient_of_determination: 1.0 - residual_sum_of_squares / total_sum_of_squares,
+        }
+    }
+}
+
+/// Fit
+#[derive(Clone, Copy, Debug)]
+struct Fit {
+    selectivity: f64,
+    standard_error: Option<f64>,
+    root_mean_square_error: f64,
+    coefficient_of_determination: f64,
+}
+
+/// Golden section search
+fn minimize(f: impl Fn(f64) -> f64) -> f64 {
+    const INVERSE_PHI: f64 = 0.618_033_988_749_894_8;
+    let (mut a, mut b) = RANGE.into_inner();
+    let mut c = b - INVERSE_PHI * (b - a);
+    let mut d = a + INVERSE_PHI * (b - a);
+    while (b - a).abs() > TOLERANCE {
+        if f(c) < f(d) {
+            b = d;
+        } else {
+            a = c;
+        }
+        c = b - INVERSE_PHI * (b - a);
+        d = a + INVERSE_PHI * (b - a);
+    }
+    (a + b) / 2.0
+}
+
+fn normalize(mut values: Vec<f64>) -> Vec<f64> {
+    let sum: f64 = values.iter().sum();
+    if sum > 0.0 {
+        for value in &mut values {
+            *value /= sum;
+        }
+    }
+    values
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn model() -> Model {
+        // Two fatty acids, species keyed by stereospecific composition
+        let mut species = Vec::new();
+        for sn1 in 0..2 {
+            for sn2 in 0..2 {
+                for sn3 in 0..2 {
+                    species.push(([sn1, sn2, sn3], sn1 * 4 + sn2 * 2 + sn3));
+                }
+            }
+        }
+        Model {
+            stereospecific_numbers123: vec![0.5, 0.5],
+            stereospecific_numbers1: vec![0.65, 0.35],
+            stereospecific_numbers2: vec![0.2, 0.8],
+            stereospecific_numbers3: vec![0.65, 0.35],
+            species,
+            measured: vec![0.0; 8],
+        }
+    }
+
+    #[test]
+    fn fit() {
+        let mut model = model();
+        for selectivity in [0.4, 1.0, 1.3] {
+            model.measured = model.predict(selectivity);
+            let fit = model.fit();
+            assert!((fit.selectivity - selectivity).abs() < 1e-6);
+            assert!(fit.root_mean_square_error < 1e-9);
+            assert!((fit.coefficient_of_determination - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn stereospecific() {
+        let mut model = model();
+        model.stereospecific_numbers1 = vec![0.9, 0.1];
+        model.stereospecific_numbers3 = vec![0.4, 0.6];
+        model.measured = model.predict(0.7);
+        let fit = model.fit();
+        assert!((fit.selectivity - 0.7).abs() < 1e-6);
+        // [aab] and [baa] are distinct with SN-1 differing from SN-3
+        let predicted = model.predict(1.0);
+        assert!((predicted[0b001] - 0.9 * 0.2 * 0.6).abs() < 1e-12);
+        assert!((predicted[0b100] - 0.1 * 0.2 * 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn random() {
+        let model = model();
+        let predicted = model.predict(0.0);
+        for value in predicted {
+            assert!((value - 0.125).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{
+    app::states::{
+        calculation::settings::Threshold,
+        composition::settings::{Ion, Settings},
+    },
+    r#const::{
+        SPECIES, THRESHOLD, VALUE,
+        relative_atomic_mass::{C, ELECTRON, H, N, NA, NH4, O},
+    },
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::Add,
+};
+use tracing::instrument;
+
+pub(crate) const FORMULA: &str = "Formula";
+pub(crate) const PROTONATED: &str = "[M+H]+";
+pub(crate) const AMMONIATED: &str = "[M+NH4]+";
+pub(crate) const SODIATED: &str = "[M+Na]+";
+/// Diacylglycerol-like fragments `[M+NH4-RCOOH-NH3]+` by stereospecific number
+pub(crate) const FRAGMENTS: [&str; 3] = [
+    "[M+NH4-R1COOH-NH3]+",
+    "[M+NH4-R2COOH-NH3]+",
+    "[M+NH4-R3COOH-NH3]+",
+];
+/// Isotopic peaks relative to the monoisotopic one
+pub(crate) const ISOTOPES: [&str; 4] = ["M+1", "M+2", "M+3", "M+4"];
+
+const CARBON: [&str; 3] = ["Carbon1", "Carbon2", "Carbon3"];
+const UNSATURATION: [&str; 3] = ["Unsaturation1", "Unsaturation2", "Unsaturation3"];
+
+/// Natural isotopic abundances by nominal mass shift
+const CARBON_ABUNDANCES: &[f64] = &[0.9893, 0.0107];
+const HYDROGEN_ABUNDANCES: &[f64] = &[0.999885, 0.000115];
+const NITROGEN_ABUNDANCES: &[f64] = &[0.99636, 0.00364];
+const OXYGEN_ABUNDANCES: &[f64] = &[0.99757, 0.00038, 0.00205];
+const SODIUM_ABUNDANCES: &[f64] = &[1.0];
+
+/// Mass spectrometry composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Mass spectrometry composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        // | Threshold | Label     | Triacylglycerol | Value         |
+        // | ---       | ---       | ---             | ---           |
+        // | bool      | struct[3] | struct[3]       | array[f64, n] |
+        if key.threshold.filter {
+            lazy_frame = lazy_frame.filter(col(THRESHOLD));
+        }
+        let data_frame = compute(lazy_frame, key)?.collect()?;
+        // | Species | Formula | Value | [M+H]+ | [M+NH4]+ | [M+Na]+ | [M+NH4-R1COOH-NH3]+ | ... | M+1 | ... |
+        // | ---     | ---     | ---   | ---    | ---      | ---     | ---                 | ... | --- | ... |
+        // | str     | str     | f64   | f64    | f64      | f64     | f64                 | ... | f64 | ... |
+        ions(data_frame, key)
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Mass spectrometry composition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) ion: Ion,
+    pub(crate) percent: bool,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            ion: settings.ion,
+            percent: settings.percent,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Mass spectrometry composition value
+type Value = DataFrame;
+
+fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    let label = col(LABEL).triacylglycerol();
+    let triacylglycerol = col(TRIACYLGLYCEROL).triacylglycerol();
+    let stereospecific_numbers = [
+        triacylglycerol.clone().stereospecific_number1(),
+        triacylglycerol.clone().stereospecific_number2(),
+        triacylglycerol.stereospecific_number3(),
+    ];
+    let mut value = col(VALUE).arr().mean();
+    if key.percent {
+        value = value * lit(100.0);
+    }
+    let mut exprs = vec![
+        format_str(
+            "[{}; {}; {}]",
+            [
+                label.clone().stereospecific_number1(),
+                label.clone().stereospecific_number2(),
+                label.stereospecific_number3(),
+            ],
+        )?
+        .alias(SPECIES),
+        value.alias(VALUE),
+    ];
+    for (index, stereospecific_number) in stereospecific_numbers.into_iter().enumerate() {
+        exprs.push(
+            stereospecific_number
+                .clone()
+                .fatty_acid()
+                .carbon()
+                .cast(DataType::Int64)
+                .alias(CARBON[index]),
+        );
+        exprs.push(
+            stereospecific_number
+                .fatty_acid()
+                .unsaturation()
+                .cast(DataType::Int64)
+                .alias(UNSATURATION[index]),
+        );
+    }
+    Ok(lazy_frame.select(exprs).sort_by_exprs(
+        [col(VALUE)],
+        SortMultipleOptions::default()
+            .with_order_descending(true)
+            .with_nulls_last(true),
+    ))
+}
+
+/// Ion m/z values and isotopic envelopes
+fn ions(data_frame: DataFrame, key: Key) -> PolarsResult<DataFrame> {
+    let carbons = [
+        data_frame[CARBON[0]].i64()?,
+        data_frame[CARBON[1]].i64()?,
+        data_frame[CARBON[2]].i64()?,
+    ];
+    let unsaturations = [
+        data_frame[UNSATURATION[0]].i64()?,
+        data_frame[UNSATURATION[1]].i64()?,
+        data_frame[UNSATURATION[2]].i64()?,
+    ];
+    let height = data_frame.height();
+    let mut formulas = Vec::with_capacity(height);
+    let mut protonated = Vec::with_capacity(height);
+    let mut ammoniated = Vec::with_capacity(height);
+    let mut sodiated = Vec::with_capacity(height);
+    let mut fragments: [_; 3] = std::array::from_fn(|_| Vec::with_capacity(height));
+    let mut isotopes: [_; 4] = std::array::from_fn(|_| Vec::with_capacity(height));
+    for row in 0..height {
+        let fatty_acids = [0, 1, 2].map(|index| {
+            Some(Formula::fatty_acid(
+                carbons[index].get(row)?,
+                unsaturations[index].get(row)?,
+            ))
+        });
+        let [Some(sn1), Some(sn2), Some(sn3)] = fatty_acids else {
+            formulas.push(None);
+            protonated.push(None);
+            ammoniated.push(None);
+            sodiated.push(None);
+            fragments.iter_mut().for_each(|values| values.push(None));
+            isotopes.iter_mut().for_each(|values| values.push(None));
+            continue;
+        };
+        let formula = Formula::triacylglycerol([sn1, sn2, sn3]);
+        let mass = formula.mass();
+        formulas.push(Some(formula.to_string()));
+        protonated.push(Some(mass + H - ELECTRON));
+        ammoniated.push(Some(mass + NH4 - ELECTRON));
+        sodiated.push(Some(mass + NA - ELECTRON));
+        // [M+NH4-RCOOH-NH3]+ = [M+H-RCOOH]+
+        for (values, fatty_acid) in fragments.iter_mut().zip([sn1, sn2, sn3]) {
+            values.push(Some(mass + H - fatty_acid.mass() - ELECTRON));
+        }
+        let envelope = (formula + adduct(key.ion)).isotopes();
+        for (values, abundance) in isotopes.iter_mut().zip(&envelope[1..]) {
+            values.push(Some(abundance / envelope[0] * 100.0));
+        }
+    }
+    let mut output = data_frame.select([SPECIES])?;
+    output.with_column(Column::new(FORMULA.into(), formulas))?;
+    output.with_column(data_frame[VALUE].clone())?;
+    output.with_column(Column::new(PROTONATED.into(), protonated))?;
+    output.with_column(Column::new(AMMONIATED.into(), ammoniated))?;
+    output.with_column(Column::new(SODIATED.into(), sodiated))?;
+    for (name, values) in FRAGMENTS.into_iter().zip(fragments) {
+        output.with_column(Column::new(name.into(), values))?;
+    }
+    for (name, values) in ISOTOPES.into_iter().zip(isotopes) {
+        output.with_column(Column::new(name.into(), values))?;
+    }
+    Ok(output)
+}
+
+/// Adduct formula of the ion
+fn adduct(ion: Ion) -> Formula {
+    match ion {
+        Ion::Protonated => Formula::HYDROGEN,
+        Ion::Ammoniated => Formula::AMMONIUM,
+        Ion::Sodiated => Formula::SODIUM,
+    }
+}
+
+/// Elemental formula
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Formula {
+    carbon: u32,
+    hydrogen: u32,
+    nitrogen: u32,
+    oxygen: u32,
+    sodium: u32,
+}
+
+impl Formula {
+    const AMMONIUM: Self = Self {
+        carbon: 0,
+        hydrogen: 4,
+        nitrogen: 1,
+        oxygen: 0,
+        sodium: 0,
+    };
+
+    const HYDROGEN: Self = Self {
+        carbon: 0,
+        hydrogen: 1,
+        nitrogen: 0,
+        oxygen: 0,
+        sodium: 0,
+    };
+
+    const SODIUM: Self = Self {
+        carbon: 0,
+        hydrogen: 0,
+        nitrogen: 0,
+        oxygen: 0,
+        sodium: 1,
+    };
+
+    /// Fatty acid `CnH(2n-2u)O2`
+    fn fatty_acid(carbon: i64, unsaturation: i64) -> Self {
+        Self {
+            carbon: carbon as _,
+            hydrogen: (2 * carbon - 2 * unsaturation).max(0) as _,
+            oxygen: 2,
+            ..Default::default()
+        }
+    }
+
+    /// Triacylglycerol: glycerol `C3H8O3` esterified with three fatty acids
+    /// minus three water molecules
+    fn triacylglycerol(fatty_acids: [Self; 3]) -> Self {
+        let glycerol = Self {
+            carbon: 3,
+            hydrogen: 8,
+            oxygen: 3,
+            ..Default::default()
+        };
+        let mut formula = fatty_acids.into_iter().fold(glycerol, Add::add);
+        formula.hydrogen -= 6;
+        formula.oxygen -= 3;
+        formula
+    }
+
+    /// Monoisotopic mass
+    fn mass(&self) -> f64 {
+        self.carbon as f64 * C
+            + self.hydrogen as f64 * H
+            + self.nitrogen as f64 * N
+            + self.oxygen as f64 * O
+            + self.sodium as f64 * NA
+    }
+
+    /// Isotopic envelope by nominal mass shift
+    fn isotopes(&self) -> [f64; 5] {
+        let mut envelope = [0.0; 5];
+        envelope[0] = 1.0;
+        for (count, abundances) in [
+            (self.carbon, CARBON_ABUNDANCES),
+            (self.hydrogen, HYDROGEN_ABUNDANCES),
+            (self.nitrogen, NITROGEN_ABUNDANCES),
+            (self.oxygen, OXYGEN_ABUNDANCES),
+            (self.sodium, SODIUM_ABUNDANCES),
+        ] {
+            for _ in 0..count {
+                let mut convolved = [0.0; 5];
+                for (shift, abundance) in abundances.iter().enumerate() {
+                    for index in 0..envelope.len() - shift {
+                        convolved[index + shift] += envelope[index] * abundance;
+                    }
+                }
+                envelope = convolved;
+            }
+        }
+        envelope
+    }
+}
+
+impl Add for Formula {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            carbon: self.carbon + rhs.carbon,
+            hydrogen: self.hydrogen + rhs.hydrogen,
+            nitrogen: self.nitrogen + rhs.nitrogen,
+            oxygen: self.oxygen + rhs.oxygen,
+            sodium: self.sodium + rhs.sodium,
+        }
+    }
+}
+
+/// Hill notation
+impl Display for Formula {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (symbol, count) in [
+            ("C", self.carbon),
+            ("H", self.hydrogen),
+            ("N", self.nitrogen),
+            ("Na", self.sodium),
+            ("O", self.oxygen),
+        ] {
+            match count {
+                0 => {}
+                1 => f.write_str(symbol)?,
+                _ => write!(f, "{symbol}{count}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tripalmitin() {
+        let palmitic = Formula::fatty_acid(16, 0);
+        let formula = Formula::triacylglycerol([palmitic; 3]);
+        assert_eq!(formula.to_string(), "C51H98O6");
+        assert!((formula.mass() - 806.7363).abs() < 1e-3);
+        assert!((formula.mass() + NH4 - ELECTRON - 824.7701).abs() < 1e-3);
+    }
+
+    #[test]
+    fn isotopes() {
+        let envelope = Formula::triacylglycerol([Formula::fatty_acid(18, 1); 3]).isotopes();
+        assert!((envelope.iter().sum::<f64>() - 1.0).abs() < 1e-2);
+        assert!(envelope[0] > envelope[1]);
+    }
+}
@@ -2,10 +2,11 @@ use crate::{
     app::states::{
         calculation::settings::Threshold,
         composition::settings::{
-            Composition, ECN_MONO, ECN_POSITIONAL, ECN_STEREO, MASS_MONO, MASS_POSITIONAL,
-            MASS_STEREO, Order, SPECIES_MONO, SPECIES_POSITIONAL, SPECIES_STEREO, Settings, Sort,
-            TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO, UNSATURATION_POSITIONAL,
-            UNSATURATION_STEREO,
+            CN_MONO, CN_POSITIONAL, CN_STEREO, Composition, ECN_MONO, ECN_POSITIONAL, ECN_STEREO,
+            Filter, MASS_MONO, MASS_POSITIONAL, MASS_STEREO, Order, PN_MONO, PN_POSITIONAL,
+            PN_STEREO, SPECIES_MONO, SPECIES_POSITIONAL, SPECIES_STEREO, Settings, Sort,
+            Stereospecificity, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO,
+            UNSATURATION_POSITIONAL, UNSATURATION_STEREO,
         },
     },
     r#const::{KEY, KEYS, SPECIES, THRESHOLD, VALUE, VALUES},
@@ -19,7 +20,7 @@ use egui::{
 use lipid::prelude::*;
 use polars::prelude::*;
 use polars_ext::expr::eval_arr;
-use std::{convert::identity, sync::LazyLock};
+use std::{collections::BTreeMap, convert::identity, sync::LazyLock};
 use tracing::instrument;
 
 /// Starts with `KEY`
@@ -70,6 +71,7 @@ pub(crate) struct Key<'a> {
     pub(crate) index: Option<usize>,
     pub(crate) adduct: OrderedFloat<f64>,
     pub(crate) compositions: &'a Vec<Composition>,
+    pub(crate) filters: &'a BTreeMap<Composition, Filter>,
     pub(crate) ddof: u8,
     pub(crate) order: Order,
     pub(crate) round_mass: u32,
@@ -84,6 +86,7 @@ impl<'a> Key<'a> {
             index: settings.index,
             adduct: OrderedFloat(settings.adduct),
             compositions: &settings.compositions,
+            filters: &settings.filters,
             ddof: settings.ddof,
             order: settings.order,
             round_mass: settings.round_mass,
@@ -115,157 +118,16 @@ fn compose(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     // Composition
     for (index, composition) in key.compositions.iter().enumerate() {
         lazy_frame = lazy_frame.with_column(
-            match *composition {
-                MASS_MONO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|_| {
-                        col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .relative_atomic_mass(Some(lit(key.adduct.0)))
-                            .round(key.round_mass, RoundMode::HalfToEven)
-                    })
-                    .alias("MMC"),
-                MASS_POSITIONAL => {
-                    let sn13 = (col(TRIACYLGLYCEROL)
-                        .triacylglycerol()
-                        .stereospecific_number1()
-                        .fatty_acid()
-                        .relative_atomic_mass(None)
-                        + col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .stereospecific_number3()
-                            .fatty_acid()
-                            .relative_atomic_mass(None))
-                    .round(key.round_mass, RoundMode::HalfToEven);
-                    as_struct(vec![
-                        sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
-                        col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .stereospecific_number2()
-                            .fatty_acid()
-                            .relative_atomic_mass(None)
-                            .round(key.round_mass, RoundMode::HalfToEven)
-                            .alias(STEREOSPECIFIC_NUMBERS2),
-                        sn13.alias(STEREOSPECIFIC_NUMBERS3),
-                    ])
-                    .alias("MPC")
-                }
-                MASS_STEREO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|expr| {
-                        expr.fatty_acid()
-                            .relative_atomic_mass(None)
-                            .round(key.round_mass, RoundMode::HalfToEven)
-                    })
-                    .alias("MSC"),
-                ECN_MONO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|_| {
-                        col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .equivalent_carbon_number()
-                    })
-                    .alias("NMC"),
-                ECN_POSITIONAL => {
-                    let sn13 = col(TRIACYLGLYCEROL)
-                        .triacylglycerol()
-                        .stereospecific_number1()
-                        .fatty_acid()
-                        .equivalent_carbon_number()
-                        + col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .stereospecific_number3()
-                            .fatty_acid()
-                            .equivalent_carbon_number();
-                    as_struct(vec![
-                        sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
-                        col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .stereospecific_number2()
-                            .fatty_acid()
-                            .equivalent_carbon_number()
-                            .alias(STEREOSPECIFIC_NUMBERS2),
-                        sn13.alias(STEREOSPECIFIC_NUMBERS3),
-                    ])
-                    .alias("NPC")
-                }
-                ECN_STEREO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|expr| expr.fatty_acid().equivalent_carbon_number())
-                    .alias("NSC"),
-                SPECIES_MONO => col(LABEL)
-                    .triacylglycerol()
-                    .non_stereospecific(identity)
-                    .alias("SMC"),
-                SPECIES_POSITIONAL => col(LABEL)
-                    .triacylglycerol()
-                    .positional(identity)
-                    .alias("SPC"),
-                SPECIES_STEREO => col(LABEL).alias("SSC"),
-                TYPE_MONO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .non_stereospecific(|expr| expr.fatty_acid().is_saturated().not())
-                    .triacylglycerol()
-                    .map(|expr| expr.fatty_acid().r#type())
-                    .alias("TMC"),
-                TYPE_POSITIONAL => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .positional(|expr| expr.fatty_acid().is_saturated().not())
-                    .triacylglycerol()
-                    .map(|expr| expr.fatty_acid().r#type())
-                    .alias("TPC"),
-                TYPE_STEREO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|expr| expr.fatty_acid().r#type())
-                    .alias("TSC"),
-                UNSATURATION_MONO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|_| col(TRIACYLGLYCEROL).triacylglycerol().unsaturation())
-                    .alias("UMC"),
-                UNSATURATION_POSITIONAL => {
-                    let sn13 = col(TRIACYLGLYCEROL)
-                        .triacylglycerol()
-                        .stereospecific_number1()
-                        .fatty_acid()
-                        .unsaturation()
-                        + col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .stereospecific_number3()
-                            .fatty_acid()
-                            .unsaturation();
-                    as_struct(vec![
-                        sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
-                        col(TRIACYLGLYCEROL)
-                            .triacylglycerol()
-                            .stereospecific_number2()
-                            .fatty_acid()
-                            .unsaturation()
-                            .alias(STEREOSPECIFIC_NUMBERS2),
-                        sn13.alias(STEREOSPECIFIC_NUMBERS3),
-                    ])
-                    .alias("UPC")
-                }
-                UNSATURATION_STEREO => col(TRIACYLGLYCEROL)
-                    .triacylglycerol()
-                    .map(|expr| expr.fatty_acid().unsaturation())
-                    .alias("USC"),
-            }
-            .alias(format!("Key{index}")),
-        );
-        lazy_frame = lazy_frame.with_column(
-            eval_arr(col(VALUE), |mut expr| {
-                {
-                    if key.threshold.filter {
-                        expr = expr.filter(col(THRESHOLD));
-                    }
-                    expr
-                }
-                .sum()
-            })?
-            .over([as_struct(vec![col(format!("^Key[0-{index}]$"))])])
-            .alias(format!("Value{index}")),
+            composition_key(*composition, key.adduct.0, key.round_mass)
+                .alias(format!("Key{index}")),
         );
     }
+    let filters: Vec<_> = key
+        .compositions
+        .iter()
+        .map(|composition| key.filters.get(composition))
+        .collect();
+    lazy_frame = values(lazy_frame, &filters, key.threshold)?;
     println!("OG 2: {}", lazy_frame.clone().collect().unwrap());
     // Group
     lazy_frame = lazy_frame
@@ -297,6 +159,282 @@ fn compose(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     Ok(lazy_frame)
 }
 
+/// Values of each composition level
+///
+/// The keys of all levels are filtered before the values are summed, so the
+/// value of each level is the sum of its shown species. The values are
+/// filtered level by level.
+fn values(
+    mut lazy_frame: LazyFrame,
+    filters: &[Option<&Filter>],
+    threshold: &Threshold,
+) -> PolarsResult<LazyFrame> {
+    // Filter by key
+    for (index, filter) in filters.iter().enumerate() {
+        if let Some(filter) = filter {
+            lazy_frame = lazy_frame.filter(filter_key(col(format!("Key{index}")), filter));
+        }
+    }
+    for (index, filter) in filters.iter().enumerate() {
+        lazy_frame = lazy_frame.with_column(
+            eval_arr(col(VALUE), |mut expr| {
+                {
+                    if threshold.filter {
+                        expr = expr.filter(col(THRESHOLD));
+                    }
+                    expr
+                }
+                .sum()
+            })?
+            .over([as_struct(vec![col(format!("^Key[0-{index}]$"))])])
+            .alias(format!("Value{index}")),
+        );
+        // Filter by value
+        if let Some(filter) = filter
+            && filter.value > 0.0
+        {
+            lazy_frame = lazy_frame.filter(
+                col(format!("Value{index}"))
+                    .arr()
+                    .mean()
+                    .gt_eq(lit(filter.value)),
+            );
+        }
+    }
+    Ok(lazy_frame)
+}
+
+/// Key expression of the composition
+pub(crate) fn composition_key(composition: Composition, adduct: f64, round_mass: u32) -> Expr {
+    match composition {
+        MASS_MONO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|_| {
+                col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .relative_atomic_mass(Some(lit(adduct)))
+                    .round(round_mass, RoundMode::HalfToEven)
+            })
+            .alias("MMC"),
+        MASS_POSITIONAL => {
+            let sn13 = (col(TRIACYLGLYCEROL)
+                .triacylglycerol()
+                .stereospecific_number1()
+                .fatty_acid()
+                .relative_atomic_mass(None)
+                + col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .stereospecific_number3()
+                    .fatty_acid()
+                    .relative_atomic_mass(None))
+            .round(round_mass, RoundMode::HalfToEven);
+            as_struct(vec![
+                sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
+                col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .stereospecific_number2()
+                    .fatty_acid()
+                    .relative_atomic_mass(None)
+                    .round(round_mass, RoundMode::HalfToEven)
+                    .alias(STEREOSPECIFIC_NUMBERS2),
+                sn13.alias(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .alias("MPC")
+        }
+        MASS_STEREO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|expr| {
+                expr.fatty_acid()
+                    .relative_atomic_mass(None)
+                    .round(round_mass, RoundMode::HalfToEven)
+            })
+            .alias("MSC"),
+        ECN_MONO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|_| {
+                col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .equivalent_carbon_number()
+            })
+            .alias("NMC"),
+        ECN_POSITIONAL => {
+            let sn13 = col(TRIACYLGLYCEROL)
+                .triacylglycerol()
+                .stereospecific_number1()
+                .fatty_acid()
+                .equivalent_carbon_number()
+                + col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .stereospecific_number3()
+                    .fatty_acid()
+                    .equivalent_carbon_number();
+            as_struct(vec![
+                sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
+                col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .stereospecific_number2()
+                    .fatty_acid()
+                    .equivalent_carbon_number()
+                    .alias(STEREOSPECIFIC_NUMBERS2),
+                sn13.alias(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .alias("NPC")
+        }
+        ECN_STEREO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|expr| expr.fatty_acid().equivalent_carbon_number())
+            .alias("NSC"),
+        CN_MONO => mono(carbon_number).alias("CNMC"),
+        CN_POSITIONAL => positional(carbon_number).alias("CNPC"),
+        CN_STEREO => stereo(carbon_number).alias("CNSC"),
+        PN_MONO => mono(partition_number).alias("PNMC"),
+        PN_POSITIONAL => positional(partition_number).alias("PNPC"),
+        PN_STEREO => stereo(partition_number).alias("PNSC"),
+        SPECIES_MONO => col(LABEL)
+            .triacylglycerol()
+            .non_stereospecific(identity)
+            .alias("SMC"),
+        SPECIES_POSITIONAL => col(LABEL)
+            .triacylglycerol()
+            .positional(identity)
+            .alias("SPC"),
+        SPECIES_STEREO => col(LABEL).alias("SSC"),
+        TYPE_MONO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .non_stereospecific(|expr| expr.fatty_acid().is_saturated().not())
+            .triacylglycerol()
+            .map(|expr| expr.fatty_acid().r#type())
+            .alias("TMC"),
+        TYPE_POSITIONAL => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .positional(|expr| expr.fatty_acid().is_saturated().not())
+            .triacylglycerol()
+            .map(|expr| expr.fatty_acid().r#type())
+            .alias("TPC"),
+        TYPE_STEREO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|expr| expr.fatty_acid().r#type())
+            .alias("TSC"),
+        UNSATURATION_MONO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|_| col(TRIACYLGLYCEROL).triacylglycerol().unsaturation())
+            .alias("UMC"),
+        UNSATURATION_POSITIONAL => {
+            let sn13 = col(TRIACYLGLYCEROL)
+                .triacylglycerol()
+                .stereospecific_number1()
+                .fatty_acid()
+                .unsaturation()
+                + col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .stereospecific_number3()
+                    .fatty_acid()
+                    .unsaturation();
+            as_struct(vec![
+                sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
+                col(TRIACYLGLYCEROL)
+                    .triacylglycerol()
+                    .stereospecific_number2()
+                    .fatty_acid()
+                    .unsaturation()
+                    .alias(STEREOSPECIFIC_NUMBERS2),
+                sn13.alias(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .alias("UPC")
+        }
+        UNSATURATION_STEREO => col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(|expr| expr.fatty_acid().unsaturation())
+            .alias("USC"),
+    }
+}
+
+/// Fatty acid carbon number
+fn carbon_number(expr: Expr) -> Expr {
+    expr.fatty_acid().carbon().cast(DataType::Int64)
+}
+
+/// Fatty acid partition number (`PN = CN - 2 * DB`)
+///
+/// The plain partition number, without the empirical corrections for the
+/// double bound positions and geometry. Species with the lower partition
+/// number elute first in reversed-phase HPLC, the elution order within the
+/// same partition number is not predicted.
+fn partition_number(expr: Expr) -> Expr {
+    carbon_number(expr.clone())
+        - lit(2)
+            * expr
+                .fatty_acid()
+                .double_bounds_unsaturation()
+                .cast(DataType::Int64)
+}
+
+/// Sum over all stereospecific numbers
+fn mono(f: fn(Expr) -> Expr) -> Expr {
+    col(TRIACYLGLYCEROL).triacylglycerol().map(move |_| {
+        col(TRIACYLGLYCEROL)
+            .triacylglycerol()
+            .map(f)
+            .triacylglycerol()
+            .sum()
+    })
+}
+
+/// Sum over sn-1 and sn-3, sn-2 apart
+fn positional(f: fn(Expr) -> Expr) -> Expr {
+    let triacylglycerol = col(TRIACYLGLYCEROL).triacylglycerol();
+    let sn13 = f(triacylglycerol.clone().stereospecific_number1())
+        + f(triacylglycerol.clone().stereospecific_number3());
+    as_struct(vec![
+        sn13.clone().alias(STEREOSPECIFIC_NUMBERS1),
+        f(triacylglycerol.stereospecific_number2()).alias(STEREOSPECIFIC_NUMBERS2),
+        sn13.alias(STEREOSPECIFIC_NUMBERS3),
+    ])
+}
+
+/// Each stereospecific number apart
+fn stereo(f: fn(Expr) -> Expr) -> Expr {
+    col(TRIACYLGLYCEROL).triacylglycerol().map(f)
+}
+
+/// Text label of the composition key
+pub(crate) fn label(expr: Expr, composition: Composition) -> PolarsResult<Expr> {
+    let triacylglycerol = expr.triacylglycerol();
+    let args = [
+        triacylglycerol.clone().stereospecific_number1(),
+        triacylglycerol.clone().stereospecific_number2(),
+        triacylglycerol.clone().stereospecific_number3(),
+    ];
+    Ok(match composition {
+        MASS_MONO | ECN_MONO | CN_MONO | PN_MONO | UNSATURATION_MONO => triacylglycerol
+            .stereospecific_number1()
+            .cast(DataType::String),
+        _ => match composition.stereospecificity() {
+            Some(Stereospecificity::Stereo) => format_str("[{}; {}; {}]", args)?,
+            Some(Stereospecificity::Positional) => format_str("[{}/2; {}; {}/2]", args)?,
+            None => format_str("[{}/3; {}/3; {}/3]", args)?,
+        },
+    })
+}
+
+/// Excludes key values per stereospecific number
+fn filter_key(expr: Expr, filter: &Filter) -> Expr {
+    let triacylglycerol = expr.triacylglycerol();
+    let stereospecific_numbers = [
+        triacylglycerol.clone().stereospecific_number1(),
+        triacylglycerol.clone().stereospecific_number2(),
+        triacylglycerol.stereospecific_number3(),
+    ];
+    let mut predicate = lit(true);
+    for (index, stereospecific_number) in stereospecific_numbers.into_iter().enumerate() {
+        let value = stereospecific_number.cast(DataType::String);
+        for excluded in filter.excluded(index) {
+            predicate = predicate.and(value.clone().neq_missing(lit(excluded)));
+        }
+    }
+    predicate
+}
+
 fn sort(mut lazy_frame: LazyFrame, key: Key) -> LazyFrame {
     let mut sort_options = SortMultipleOptions::default();
     if let Order::Descending = key.order {
@@ -327,7 +465,66 @@ fn sort(mut lazy_frame: LazyFrame, key: Key) -> LazyFrame {
     lazy_frame
 }
 
+pub(crate) mod comparison;
+pub(crate) mod coverage;
+pub(crate) mod diff;
+pub(crate) mod fit;
+pub(crate) mod mass_spectrometry;
+pub(crate) mod plot;
 pub(crate) mod species;
 pub(crate) mod sum;
 pub(crate) mod table;
 pub(crate) mod unique;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn values() -> PolarsResult<()> {
+        let data_frame = df! {
+            "Mono" => ["A", "A", "B"],
+            "1" => ["P", "P", "O"],
+            "2" => ["P", "O", "O"],
+            "3" => ["P", "P", "O"],
+            VALUE => [
+                Series::new(PlSmallStr::EMPTY, [0.2]),
+                Series::new(PlSmallStr::EMPTY, [0.3]),
+                Series::new(PlSmallStr::EMPTY, [0.5]),
+            ],
+        }?;
+        let lazy_frame = data_frame.lazy().select([
+            as_struct(vec![
+                col("Mono").alias(STEREOSPECIFIC_NUMBERS1),
+                col("Mono").alias(STEREOSPECIFIC_NUMBERS2),
+                col("Mono").alias(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .alias("Key0"),
+            as_struct(vec![
+                col("1").alias(STEREOSPECIFIC_NUMBERS1),
+                col("2").alias(STEREOSPECIFIC_NUMBERS2),
+                col("3").alias(STEREOSPECIFIC_NUMBERS3),
+            ])
+            .alias("Key1"),
+            col(VALUE).cast(DataType::Array(Box::new(DataType::Float64), 1)),
+            lit(true).alias(THRESHOLD),
+        ]);
+        // Exclude P in SN-2 on the second level
+        let mut filter = Filter::new();
+        filter.key.insert("P".to_owned(), [false, true, false]);
+        let data_frame =
+            super::values(lazy_frame, &[None, Some(&filter)], &Threshold::new())?.collect()?;
+        assert_eq!(data_frame.height(), 2);
+        let value = |name: &str, row| -> PolarsResult<Option<f64>> {
+            Ok(data_frame[name].array()?.get_as_series(row).and_then(|series| {
+                series.f64().ok().and_then(|values| values.get(0))
+            }))
+        };
+        // [P; P; P] is excluded from the first level too
+        assert_eq!(value("Value0", 0)?, Some(0.3));
+        assert_eq!(value("Value1", 0)?, Some(0.3));
+        assert_eq!(value("Value0", 1)?, Some(0.5));
+        assert_eq!(value("Value1", 1)?, Some(0.5));
+        Ok(())
+    }
+}
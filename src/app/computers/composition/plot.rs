@@ -0,0 +1,131 @@
+use super::label;
+use crate::{
+    app::states::{
+        calculation::settings::Threshold,
+        composition::settings::{
+            CN_MONO, Composition, ECN_MONO, MASS_MONO, PN_MONO, Settings, UNSATURATION_MONO,
+        },
+    },
+    r#const::{KEY, KEYS, MEAN, POSITION, STACK, STANDARD_DEVIATION, THRESHOLD, VALUE, VALUES},
+    utils::HashedDataFrame,
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Plot composition computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Plot composition computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        // | Threshold | Keys      | Values              | Species         |
+        // | ---       | ---       | ---                 | ---             |
+        // | bool      | struct[n] | list[array[f64, m]] | list[struct[4]] |
+        lazy_frame = compute(lazy_frame, key)?;
+        // | Position | Key | Stack | Value     |
+        // | ---      | --- | ---   | ---       |
+        // | f64      | str | str   | struct[2] |
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Plot composition key
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) compositions: &'a Vec<Composition>,
+    pub(crate) ddof: u8,
+    pub(crate) percent: bool,
+    pub(crate) threshold: &'a Threshold,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(data_frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame: data_frame,
+            compositions: &settings.compositions,
+            ddof: settings.ddof,
+            percent: settings.percent,
+            threshold: &settings.threshold,
+        }
+    }
+}
+
+/// Plot composition value
+type Value = DataFrame;
+
+fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    if key.threshold.filter {
+        lazy_frame = lazy_frame.filter(col(THRESHOLD));
+    }
+    // Bars are keyed by the first composition, stacks by the next one
+    let stack = if key.compositions.len() > 1 { 1 } else { 0 };
+    lazy_frame = lazy_frame.select([
+        position(keys(0), key.compositions[0]).alias(POSITION),
+        label(keys(0), key.compositions[0])?.alias(KEY),
+        if stack > 0 {
+            label(keys(stack), key.compositions[stack])?
+        } else {
+            lit(NULL).cast(DataType::String)
+        }
+        .alias(STACK),
+        values(stack).alias(VALUE),
+    ]);
+    // Deeper compositions repeat the same values
+    lazy_frame = lazy_frame
+        .group_by_stable([col(KEY), col(STACK)])
+        .agg([col(POSITION).first(), col(VALUE).first()]);
+    Ok(lazy_frame.select([
+        col(POSITION),
+        col(KEY),
+        col(STACK),
+        statistics(col(VALUE), key).alias(VALUE),
+    ]))
+}
+
+fn keys(index: usize) -> Expr {
+    col(KEYS).struct_().field_by_index(index as _)
+}
+
+fn values(index: usize) -> Expr {
+    col(VALUES).list().get(lit(index as IdxSize), false)
+}
+
+/// Numeric position for mass, equivalent carbon number, carbon number,
+/// partition number and unsaturation compositions, null for categorical ones
+fn position(expr: Expr, composition: Composition) -> Expr {
+    match composition {
+        MASS_MONO | ECN_MONO | CN_MONO | PN_MONO | UNSATURATION_MONO => expr
+            .triacylglycerol()
+            .stereospecific_number1()
+            .cast(DataType::Float64),
+        _ => lit(NULL).cast(DataType::Float64),
+    }
+}
+
+/// Replicate mean and standard deviation
+fn statistics(array: Expr, key: Key) -> Expr {
+    let mut mean = array.clone().arr().mean();
+    let mut standard_deviation = array.arr().std(key.ddof);
+    if key.percent {
+        mean = mean * lit(100.0);
+        standard_deviation = standard_deviation * lit(100.0);
+    }
+    as_struct(vec![
+        mean.alias(MEAN),
+        standard_deviation.alias(STANDARD_DEVIATION),
+    ])
+}
@@ -113,7 +113,15 @@ fn indexed(lazy_frame: LazyFrame, index: usize) -> LazyFrame {
     ])
 }
 
-fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+fn compute(mut lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    // Without stereospecific analysis SN-1 and SN-3 are equal to SN-1,3
+    let schema = lazy_frame.collect_schema()?;
+    if !schema.contains(STEREOSPECIFIC_NUMBERS1) || !schema.contains(STEREOSPECIFIC_NUMBERS3) {
+        lazy_frame = lazy_frame.with_columns([
+            col(STEREOSPECIFIC_NUMBERS13).alias(STEREOSPECIFIC_NUMBERS1),
+            col(STEREOSPECIFIC_NUMBERS13).alias(STEREOSPECIFIC_NUMBERS3),
+        ]);
+    }
     match key.method {
         Method::Gunstone => gunstone::compute(lazy_frame, key.discriminants),
         Method::MartinezForce => martinez_force::compute(lazy_frame),
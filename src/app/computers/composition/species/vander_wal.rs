@@ -7,7 +7,9 @@ use polars::prelude::*;
 // [aab] = 2*[a_{13}]*[a_2]*[b13]
 // [aba] = [a13]^2*[b2]
 // `2*[a_{13}]` - потому что зеркальные ([abc]=[cba], [aab]=[baa]).
-// SSC: [abc] = [a_{13}]*[b_2]*[c_{13}]
+// SSC: [abc] = [a_1]*[b_2]*[c_3]
+// `[a_1]` and `[c_3]` are equal to `[a_{13}]` and `[c_{13}]` without
+// stereospecific analysis.
 pub(super) fn compute(mut lazy_frame: LazyFrame) -> PolarsResult<LazyFrame> {
     // Cartesian product (TAG from FA)
     lazy_frame = lazy_frame
@@ -15,7 +17,7 @@ pub(super) fn compute(mut lazy_frame: LazyFrame) -> PolarsResult<LazyFrame> {
         .select([as_struct(vec![
             col(LABEL),
             col(FATTY_ACID),
-            col(STEREOSPECIFIC_NUMBERS13).alias("Value"),
+            col(STEREOSPECIFIC_NUMBERS1).alias("Value"),
         ])
         .alias(STEREOSPECIFIC_NUMBERS1)])
         .cross_join(
@@ -31,7 +33,7 @@ pub(super) fn compute(mut lazy_frame: LazyFrame) -> PolarsResult<LazyFrame> {
             lazy_frame.clone().select([as_struct(vec![
                 col(LABEL),
                 col(FATTY_ACID),
-                col(STEREOSPECIFIC_NUMBERS13).alias("Value"),
+                col(STEREOSPECIFIC_NUMBERS3).alias("Value"),
             ])
             .alias(STEREOSPECIFIC_NUMBERS3)]),
             None,
@@ -1 +1,2 @@
+pub(crate) mod quality;
 pub(crate) mod symmetry;
@@ -0,0 +1,193 @@
+use crate::{
+    app::states::composition::settings::Settings,
+    r#const::{NAME, VALUE},
+    utils::{
+        HashedDataFrame,
+        polars::{MeanAndStandardDeviationOptions, mean_and_standard_deviation},
+    },
+};
+use egui::util::cache::{ComputerMut, FrameCache};
+use lipid::prelude::*;
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use tracing::instrument;
+
+const QUALITY_PARAMETERS: [&str; 6] = [
+    "AverageMolecularWeight",
+    "SaponificationValue",
+    "EsterValue",
+    "MeanChainLength",
+    "MeanUnsaturation",
+    "IodineValue",
+];
+
+/// Relative molecular mass of potassium hydroxide (mg/mmol)
+const KOH: f64 = 56.1056;
+
+/// Standard atomic weight of carbon
+const C: f64 = 12.011;
+
+/// Standard atomic weight of hydrogen
+const H: f64 = 1.008;
+
+/// Standard atomic weight of oxygen
+const O: f64 = 15.999;
+
+/// Relative molecular mass of iodine (I₂)
+const I2: f64 = 253.809;
+
+/// Composition quality sum computed
+pub(crate) type Computed = FrameCache<Value, Computer>;
+
+/// Composition quality sum computer
+#[derive(Default)]
+pub(crate) struct Computer;
+
+impl Computer {
+    #[instrument(skip(self), err)]
+    fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
+        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        // | Label     | Triacylglycerol | Value         |
+        // | ---       | ---             | ---           |
+        // | struct[3] | struct[3]       | array[f64, n] |
+        lazy_frame = compute(lazy_frame, key)?;
+        // | Name | Value     |
+        // | ---  | ---       |
+        // | str  | struct[3] |
+        lazy_frame.collect()
+    }
+}
+
+impl ComputerMut<Key<'_>, Value> for Computer {
+    fn compute(&mut self, key: Key) -> Value {
+        self.try_compute(key).unwrap()
+    }
+}
+
+/// Composition quality sum key
+#[derive(Clone, Copy, Debug, Hash, PartialEq)]
+pub(crate) struct Key<'a> {
+    pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) ddof: u8,
+    pub(crate) precision: usize,
+    pub(crate) significant: bool,
+}
+
+impl<'a> Key<'a> {
+    pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
+        Self {
+            frame,
+            ddof: settings.ddof,
+            precision: settings.precision,
+            significant: settings.significant,
+        }
+    }
+}
+
+impl From<Key<'_>> for MeanAndStandardDeviationOptions {
+    fn from(key: Key) -> Self {
+        Self {
+            ddof: key.ddof,
+            percent: false,
+            precision: key.precision,
+            significant: key.significant,
+        }
+    }
+}
+
+/// Composition quality sum value
+type Value = DataFrame;
+
+fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
+    let value = concat_arr(
+        QUALITY_PARAMETERS
+            .try_map(|parameter| -> PolarsResult<_> {
+                let array = eval_arr(col(VALUE), |expr| match parameter {
+                    "AverageMolecularWeight" => average_molecular_weight(expr),
+                    "SaponificationValue" => saponification_value(expr),
+                    "EsterValue" => ester_value(expr),
+                    "MeanChainLength" => mean_chain_length(expr),
+                    "MeanUnsaturation" => mean_unsaturation(expr),
+                    "IodineValue" => iodine_value(expr),
+                    _ => unreachable!(),
+                })?;
+                Ok(mean_and_standard_deviation(array, key))
+            })?
+            .to_vec(),
+    )?
+    .explode(ExplodeOptions {
+        empty_as_null: true,
+        keep_nulls: true,
+    })
+    .alias(VALUE);
+    Ok(lazy_frame.select([
+        lit(Series::from_iter(QUALITY_PARAMETERS).with_name(PlSmallStr::from_static(NAME))),
+        value,
+    ]))
+}
+
+/// M = Σ x_i * M_i
+///
+/// With the standard atomic weights, not the monoisotopic masses.
+fn average_molecular_weight(expr: Expr) -> Expr {
+    mean(expr, molar_mass())
+}
+
+/// SV = 3 * M(KOH) * 1000 / M (mg KOH/g)
+fn saponification_value(expr: Expr) -> Expr {
+    lit(3.0 * KOH * 1000.0) / average_molecular_weight(expr)
+}
+
+/// EV = SV - AV (mg KOH/g)
+///
+/// The composition is a pure triacylglycerol profile without free fatty acids,
+/// the acid value is zero and the ester value equals the saponification value.
+fn ester_value(expr: Expr) -> Expr {
+    saponification_value(expr)
+}
+
+/// Mean fatty acid carbon number
+fn mean_chain_length(expr: Expr) -> Expr {
+    mean(expr, carbon() / lit(3))
+}
+
+/// Mean double bounds per triacylglycerol
+fn mean_unsaturation(expr: Expr) -> Expr {
+    mean(expr, unsaturation())
+}
+
+/// IV = 100 * M(I₂) * U / M (g I₂/100 g)
+fn iodine_value(expr: Expr) -> Expr {
+    lit(100.0 * I2) * mean_unsaturation(expr.clone()) / average_molecular_weight(expr)
+}
+
+/// Weighted by the triacylglycerol mole fractions
+fn mean(expr: Expr, values: Expr) -> Expr {
+    (expr.clone() * values).sum() / expr.sum()
+}
+
+/// C₍₃₊ₙ₎H₍₂₊₂ₙ₋₂ᵤ₎O₆
+///
+/// Glycerol and three fatty acids without three water molecules, `n` is the
+/// sum of the fatty acid carbons and `u` is the triacylglycerol unsaturation.
+fn molar_mass() -> Expr {
+    lit(C) * (lit(3.0) + carbon())
+        + lit(H) * (lit(2.0) + lit(2.0) * carbon() - lit(2.0) * unsaturation())
+        + lit(6.0 * O)
+}
+
+fn carbon() -> Expr {
+    col(TRIACYLGLYCEROL)
+        .triacylglycerol()
+        .map(|expr| expr.fatty_acid().carbon())
+        .triacylglycerol()
+        .sum()
+        .cast(DataType::Float64)
+}
+
+fn unsaturation() -> Expr {
+    col(TRIACYLGLYCEROL)
+        .triacylglycerol()
+        .unsaturation()
+        .cast(DataType::Float64)
+}
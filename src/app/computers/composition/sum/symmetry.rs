@@ -1,5 +1,5 @@
 use crate::{
-    app::states::composition::settings::{Order, Settings, Sort},
+    app::states::composition::settings::{Class, Order, Settings, Sort},
     r#const::{GROUP, TRIACYLGLYCEROLS, VALUE},
     utils::{
         HashedDataFrame,
@@ -10,6 +10,7 @@ use egui::util::cache::{ComputerMut, FrameCache};
 use lipid::prelude::*;
 use polars::prelude::*;
 use polars_ext::prelude::*;
+use std::num::NonZeroI8;
 use tracing::instrument;
 
 /// Composition symmetry sum computed
@@ -46,6 +47,7 @@ impl ComputerMut<Key<'_>, Value> for Computer {
 #[derive(Clone, Copy, Debug, Hash, PartialEq)]
 pub(crate) struct Key<'a> {
     pub(crate) frame: &'a HashedDataFrame,
+    pub(crate) class: Class,
     pub(crate) ddof: u8,
     pub(crate) order: Order,
     pub(crate) percent: bool,
@@ -58,6 +60,7 @@ impl<'a> Key<'a> {
     pub(crate) fn new(frame: &'a HashedDataFrame, settings: &'a Settings) -> Self {
         Self {
             frame,
+            class: settings.class,
             ddof: settings.ddof,
             order: settings.order,
             percent: settings.percent,
@@ -83,29 +86,14 @@ impl From<Key<'_>> for MeanAndStandardDeviationOptions {
 type Value = DataFrame;
 
 fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
-    let sn1 = col(LABEL).triacylglycerol().stereospecific_number1();
-    let sn2 = col(LABEL).triacylglycerol().stereospecific_number2();
-    let sn3 = col(LABEL).triacylglycerol().stereospecific_number3();
+    let group = match key.class {
+        Class::Symmetry => symmetry(),
+        Class::Saturation => saturation(),
+        Class::Omega => omega(),
+    };
     // Group, format, sort
     Ok(lazy_frame
-        .group_by([
-            when(sn1.clone().eq(sn2.clone()).and(sn2.clone().eq(sn3.clone())))
-                .then(lit("AAA"))
-                .when(
-                    sn1.clone()
-                        .eq(sn3.clone())
-                        .and(sn2.clone().neq(sn3.clone())),
-                )
-                .then(lit("ABA"))
-                .when(
-                    sn1.clone()
-                        .neq(sn3.clone())
-                        .and(sn1.eq(sn2.clone()).or(sn2.eq(sn3))),
-                )
-                .then(lit("AAB(BAA)"))
-                .otherwise(lit("ABC"))
-                .alias(GROUP),
-        ])
+        .group_by([group.alias(GROUP)])
         .agg([
             mean_and_standard_deviation(eval_arr(col(VALUE), |element| element.sum())?, key)
                 .alias(VALUE),
@@ -121,6 +109,80 @@ fn compute(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
         .sort([GROUP], SortMultipleOptions::new()))
 }
 
+/// AAA, ABA, AAB(BAA), ABC
+fn symmetry() -> Expr {
+    let sn1 = col(LABEL).triacylglycerol().stereospecific_number1();
+    let sn2 = col(LABEL).triacylglycerol().stereospecific_number2();
+    let sn3 = col(LABEL).triacylglycerol().stereospecific_number3();
+    when(sn1.clone().eq(sn2.clone()).and(sn2.clone().eq(sn3.clone())))
+        .then(lit("AAA"))
+        .when(
+            sn1.clone()
+                .eq(sn3.clone())
+                .and(sn2.clone().neq(sn3.clone())),
+        )
+        .then(lit("ABA"))
+        .when(
+            sn1.clone()
+                .neq(sn3.clone())
+                .and(sn1.eq(sn2.clone()).or(sn2.eq(sn3))),
+        )
+        .then(lit("AAB(BAA)"))
+        .otherwise(lit("ABC"))
+}
+
+/// SSS, SSU(USS), SUS, USU, UUS(SUU), UUU
+fn saturation() -> Expr {
+    let group = positional(
+        |expr| {
+            when(expr.fatty_acid().is_saturated())
+                .then(lit("S"))
+                .otherwise(lit("U"))
+        },
+        "",
+    );
+    when(group.clone().eq(lit("SSU")))
+        .then(lit("SSU(USS)"))
+        .when(group.clone().eq(lit("SUU")))
+        .then(lit("UUS(SUU)"))
+        .otherwise(group)
+}
+
+/// ω-family of the fatty acid in each position: S for saturated, ω-3, ω-6 or
+/// ω-9 by the double bound nearest to the methyl end, U for other unsaturated
+fn omega() -> Expr {
+    positional(
+        |expr| {
+            when(expr.clone().fatty_acid().is_saturated())
+                .then(lit("S"))
+                .when(expr.clone().fatty_acid().is_unsaturated(NonZeroI8::new(-3)))
+                .then(lit("ω-3"))
+                .when(expr.clone().fatty_acid().is_unsaturated(NonZeroI8::new(-6)))
+                .then(lit("ω-6"))
+                .when(expr.fatty_acid().is_unsaturated(NonZeroI8::new(-9)))
+                .then(lit("ω-9"))
+                .otherwise(lit("U"))
+        },
+        "-",
+    )
+}
+
+/// Classes of the fatty acids in sn-1, sn-2 and sn-3 positions (sn-1 and sn-3
+/// are not distinguished)
+fn positional(class: impl Fn(Expr) -> Expr, separator: &str) -> Expr {
+    let triacylglycerol = col(TRIACYLGLYCEROL).triacylglycerol();
+    let sn1 = class(triacylglycerol.clone().stereospecific_number1());
+    let sn2 = class(triacylglycerol.clone().stereospecific_number2());
+    let sn3 = class(triacylglycerol.stereospecific_number3());
+    when(sn1.clone().lt_eq(sn3.clone()))
+        .then(concat_str(
+            [sn1.clone(), sn2.clone(), sn3.clone()],
+            separator,
+            false,
+        ))
+        .otherwise(concat_str([sn3, sn2, sn1], separator, false))
+}
+
 // as_struct(vec![
 //     sample
 //         .clone()
@@ -1,9 +1,12 @@
 use crate::{
-    app::states::{
-        calculation::settings::Threshold,
-        composition::settings::{Composition, Settings, Stereospecificity},
+    app::{
+        computers::calculation::sum::properties::oxidizability,
+        states::{
+            calculation::settings::Threshold,
+            composition::settings::{Composition, Settings, Stereospecificity},
+        },
     },
-    r#const::{KEY, KEYS, SPECIES, THRESHOLD, VALUE, VALUES},
+    r#const::{CALCULATED_OXIDIZABILITY, KEY, KEYS, SPECIES, THRESHOLD, VALUE, VALUES},
     utils::{
         HashedDataFrame,
         polars::{MeanAndStandardDeviationOptions, mean_and_standard_deviation},
@@ -23,15 +26,17 @@ pub(crate) struct Computer;
 
 impl Computer {
     fn try_compute(&mut self, key: Key) -> PolarsResult<Value> {
-        let mut lazy_frame = key.frame.data_frame.clone().lazy();
+        let mut data_frame = key.frame.data_frame.clone();
         // | Threshold | Keys      | Values              | Species         |
         // | ---       | ---       | ---                 | ---             |
         // | bool      | struct[1] | list[array[f64, 3]] | list[struct[4]] |
+        calculated_oxidizability(&mut data_frame)?;
+        let mut lazy_frame = data_frame.lazy();
         lazy_frame = filter_and_sort(lazy_frame, key);
         lazy_frame = compute(lazy_frame, key)?;
         // | Threshold | Index | Key[n] | Value[n]  | Species         |
         // | ---       | ---   | ---    | ---       | ---             |
-        // | bool      | u32   | str    | struct[3] | list[struct[4]] |
+        // | bool      | u32   | str    | struct[3] | list[struct[5]] |
         let data_frame = lazy_frame.collect()?;
         HashedDataFrame::new(data_frame)
     }
@@ -274,10 +279,61 @@ fn species(key: Key) -> PolarsResult<Expr> {
         },
         // Value
         mean_and_standard_deviation(element().struct_().field_by_name(VALUE), key).alias(VALUE),
+        // Calculated oxidizability
+        element()
+            .struct_()
+            .field_by_name(CALCULATED_OXIDIZABILITY)
+            .precision(key.precision, key.significant),
     ]));
     Ok(expr)
 }
 
+/// Adds the calculated oxidizability to each species
+///
+/// The mean of the relative oxidizabilities of the fatty acids, weighted by
+/// their allylic and bis-allylic positions as in the calculated oxidizability
+/// index.
+fn calculated_oxidizability(data_frame: &mut DataFrame) -> PolarsResult<()> {
+    let mut species = Vec::with_capacity(data_frame.height());
+    for series in data_frame[SPECIES].list()? {
+        let Some(series) = series else {
+            species.push(None);
+            continue;
+        };
+        let triacylglycerol = series.struct_()?.field_by_name(TRIACYLGLYCEROL)?;
+        let triacylglycerol = triacylglycerol.struct_()?;
+        let stereospecific_numbers = [
+            triacylglycerol.field_by_name(STEREOSPECIFIC_NUMBERS1)?,
+            triacylglycerol.field_by_name(STEREOSPECIFIC_NUMBERS2)?,
+            triacylglycerol.field_by_name(STEREOSPECIFIC_NUMBERS3)?,
+        ];
+        let fatty_acids = [
+            stereospecific_numbers[0].try_fatty_acid()?,
+            stereospecific_numbers[1].try_fatty_acid()?,
+            stereospecific_numbers[2].try_fatty_acid()?,
+        ];
+        let mut values = Vec::with_capacity(series.len());
+        for row in 0..series.len() {
+            let mut sum = Some(0.0);
+            for fatty_acid in &fatty_acids {
+                let value = fatty_acid
+                    .get(row)?
+                    .map(|fatty_acid| oxidizability(&fatty_acid));
+                sum = sum.zip(value).map(|(sum, value)| sum + value);
+            }
+            values.push(sum.map(|sum| sum / 3.0));
+        }
+        let mut fields = series.struct_()?.fields_as_series();
+        fields.push(Series::new(CALCULATED_OXIDIZABILITY.into(), values));
+        species.push(Some(
+            StructChunked::from_series(series.name().clone(), series.len(), fields.iter())?
+                .into_series(),
+        ));
+    }
+    data_frame.with_column(Column::new(SPECIES.into(), species))?;
+    Ok(())
+}
+
 /// Sum
 fn sum(lazy_frame: LazyFrame, key: Key) -> PolarsResult<LazyFrame> {
     Ok(lazy_frame.select([mean_and_standard_deviation(
@@ -1,9 +1,22 @@
-use crate::{app::identifiers::CONFIGURE, utils::HashedMetaDataFrame};
-use egui::{CentralPanel, Color32, Id, Label, MenuBar, RichText, ScrollArea, TopBottomPanel, Ui};
+use crate::{
+    app::{
+        identifiers::{CALCULATE, CONFIGURE},
+        widgets::DesignWidget,
+    },
+    utils::{
+        HashedMetaDataFrame,
+        metadata::{Design, field, sample},
+    },
+};
+use egui::{
+    CentralPanel, Color32, ComboBox, Grid, Id, Label, MenuBar, RichText, ScrollArea,
+    TopBottomPanel, Ui,
+};
 use egui_dnd::dnd;
 use egui_l20n::{ResponseExt, UiExt as _};
-use egui_phosphor::regular::{CHECK, DOTS_SIX_VERTICAL, NOTE_PENCIL, TRASH};
-use metadata::egui::MetadataWidget;
+use egui_phosphor::regular::{CALCULATOR, CHECK, DOTS_SIX_VERTICAL, NOTE_PENCIL, TAG, TRASH};
+use indexmap::IndexMap;
+use metadata::{NAME, egui::MetadataWidget};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -12,6 +25,10 @@ use std::collections::HashSet;
 pub struct Data {
     pub frames: Vec<HashedMetaDataFrame>,
     pub selected: HashSet<HashedMetaDataFrame>,
+    /// Metadata key of the replicate group, `None` is the sample (design
+    /// fields or name stem)
+    #[serde(default)]
+    pub group: Option<String>,
 }
 
 impl Data {
@@ -22,7 +39,25 @@ impl Data {
             .collect()
     }
 
-    pub fn add(&mut self, frame: HashedMetaDataFrame) {
+    /// Selected frames partitioned into the groups of replicates
+    pub fn groups(&self) -> IndexMap<String, Vec<HashedMetaDataFrame>> {
+        let mut groups = IndexMap::<_, Vec<_>>::new();
+        for frame in self.selected() {
+            let group = match &self.group {
+                Some(key) => field(&frame.meta, key).unwrap_or_default().to_owned(),
+                None => sample(&frame),
+            };
+            groups.entry(group).or_default().push(frame);
+        }
+        groups
+    }
+
+    /// Adds the frame, the empty experimental design fields are filled from
+    /// its name
+    pub fn add(&mut self, mut frame: HashedMetaDataFrame) {
+        if let Some(name) = frame.meta.get(NAME).cloned() {
+            Design::parse(&name).fill(&mut frame.meta);
+        }
         if !self.frames.contains(&frame) {
             self.frames.push(frame);
         }
@@ -84,6 +119,63 @@ impl Data {
             }
         });
         ui.separator();
+        // Group and calculate
+        ui.add_enabled_ui(enabled, |ui| {
+            ui.menu_button(RichText::new(CALCULATOR).heading(), |ui| {
+                self.group_and_calculate(ui);
+            })
+            .response
+            .on_hover_localized("GroupAndCalculate.hover");
+        });
+        ui.separator();
+    }
+
+    fn group_and_calculate(&mut self, ui: &mut Ui) {
+        // Group
+        let mut keys: Vec<_> = self
+            .selected
+            .iter()
+            .flat_map(|frame| frame.meta.keys().cloned())
+            .collect();
+        keys.sort();
+        keys.dedup();
+        ui.horizontal(|ui| {
+            let stem = ui.localize("Stem");
+            ui.label(ui.localize("Group"));
+            ComboBox::from_id_salt("Group")
+                .selected_text(self.group.as_deref().unwrap_or(&stem))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.group, None, stem.as_str())
+                        .on_hover_localized("Stem.hover");
+                    for key in keys {
+                        ui.selectable_value(&mut self.group, Some(key.clone()), key);
+                    }
+                });
+        });
+        ui.separator();
+        // Groups
+        let groups = self.groups();
+        Grid::new(ui.auto_id_with("Groups"))
+            .striped(true)
+            .show(ui, |ui| {
+                for (group, frames) in &groups {
+                    ui.label(group);
+                    ui.label(frames.len().to_string());
+                    ui.end_row();
+                }
+            });
+        ui.separator();
+        if ui
+            .button((
+                RichText::new(CALCULATOR),
+                RichText::new(ui.localize("GroupAndCalculate")),
+            ))
+            .clicked()
+        {
+            let groups: Vec<_> = groups.into_values().collect();
+            ui.data_mut(|data| data.insert_temp(Id::new(CALCULATE), groups));
+            ui.close();
+        }
     }
 
     fn central(&mut self, ui: &mut Ui) {
@@ -113,6 +205,20 @@ impl Data {
                         self.selected.insert(frame.clone());
                     }
                 }
+                // Design
+                ui.menu_button(TAG, |ui| {
+                    let mut meta = frame.meta.clone();
+                    if DesignWidget::new(&mut meta).show(ui).changed() {
+                        // The hash of the frame depends on the metadata
+                        let selected = self.selected.remove(frame);
+                        frame.meta = meta;
+                        if selected {
+                            self.selected.insert(frame.clone());
+                        }
+                    }
+                })
+                .response
+                .on_hover_localized("Design.hover");
             });
         });
     }
@@ -25,7 +25,7 @@ use egui_ext::{DroppedFileExt as _, HoveredFileExt, LightDarkButton};
 use egui_l20n::prelude::*;
 use egui_phosphor::{
     Variant, add_to_fonts,
-    regular::{INFO, PLUS, SLIDERS_HORIZONTAL},
+    regular::{INFO, PLUS, SLIDERS_HORIZONTAL, WARNING},
 };
 use egui_tiles::{Tile, Tree};
 use egui_tiles_ext::{HORIZONTAL, TreeExt as _, VERTICAL};
@@ -65,6 +65,9 @@ pub struct App {
     data: Data,
     // Panes
     tree: Tree<Pane>,
+    // Measured composition waiting for a composition pane
+    #[serde(skip)]
+    measured: Option<HashedMetaDataFrame>,
 }
 
 impl Default for App {
@@ -74,6 +77,7 @@ impl Default for App {
             reactive: true,
             tree: Tree::empty("CentralTree"),
             data: Default::default(),
+            measured: None,
         }
     }
 }
@@ -224,6 +228,7 @@ impl App {
     fn windows(&mut self, ctx: &Context, state: &mut State) {
         self.about_window(ctx, state);
         self.settings_window(ctx, state);
+        self.measured_window(ctx);
     }
 
     fn about_window(&mut self, ctx: &Context, state: &mut State) {
@@ -239,6 +244,22 @@ impl App {
                 state.settings.show(ui);
             });
     }
+
+    fn measured_window(&mut self, ctx: &Context) {
+        if self.measured.is_none() {
+            return;
+        }
+        let mut open = true;
+        Window::new(format!("{WARNING} Measured composition"))
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label(ui.localize("MeasuredComposition-NoCompositionPane"));
+            });
+        // Closing the window discards the measured composition
+        if !open {
+            self.measured = None;
+        }
+    }
 }
 
 // Copy/Paste, Drag&Drop
@@ -266,6 +287,13 @@ impl App {
             ctx.data_mut(|data| data.remove_temp::<Vec<HashedMetaDataFrame>>(Id::new(CALCULATE)))
         {
             self.tree.insert_pane::<VERTICAL>(Pane::calculation(frames));
+        } else if let Some(groups) = ctx
+            .data_mut(|data| data.remove_temp::<Vec<Vec<HashedMetaDataFrame>>>(Id::new(CALCULATE)))
+        {
+            // One calculation pane per group of replicates
+            for frames in groups {
+                self.tree.insert_pane::<VERTICAL>(Pane::calculation(frames));
+            }
         }
     }
 
@@ -276,11 +304,32 @@ impl App {
             self.tree
                 .insert_pane::<HORIZONTAL>(Pane::composition(frames));
         } else if let Some(frame) =
-            ctx.data_mut(|data| data.remove_temp::<MetaDataFrame>(Id::new(COMPOSE)))
+            ctx.data_mut(|data| data.remove_temp::<HashedMetaDataFrame>(Id::new(COMPOSE)))
         {
-            tracing::error!("COMPOSE");
-            // self.tree
-            //     .insert_pane::<HORIZONTAL>(Pane::composition(frame));
+            self.measured = Some(frame);
+        }
+        // Attach measured composition to the active composition pane or to the
+        // first one, it waits for a composition pane to be opened otherwise
+        if self.measured.is_some() {
+            let is_composition =
+                |tile: Option<&Tile<Pane>>| matches!(tile, Some(Tile::Pane(Pane::Composition(_))));
+            let id = self
+                .tree
+                .active_tiles()
+                .into_iter()
+                .find(|&id| is_composition(self.tree.tiles.get(id)))
+                .or_else(|| {
+                    self.tree
+                        .tiles
+                        .iter()
+                        .find_map(|(&id, tile)| is_composition(Some(tile)).then_some(id))
+                });
+            if let Some(id) = id
+                && let Some(Tile::Pane(Pane::Composition(pane))) = self.tree.tiles.get_mut(id)
+                && let Some(frame) = self.measured.take()
+            {
+                pane.attach(frame);
+            }
         }
     }
 
@@ -419,7 +468,13 @@ impl App {
             })?;
         // let frame = ron::de::from_bytes::<HashedMetaDataFrame>(&bytes)?;
         let schema = frame.data.schema();
-        if CONFIGURATION.matches_schema(schema).is_ok_and(|cast| !cast) {
+        // Optional stereospecific SN-1 column
+        let mut configuration = schema.as_ref().clone();
+        configuration.shift_remove(STEREOSPECIFIC_NUMBERS1);
+        if CONFIGURATION
+            .matches_schema(&configuration)
+            .is_ok_and(|cast| !cast)
+        {
             info!("CONFIGURATION");
             self.data.add(frame);
         } else if COMPOSITION.matches_schema(schema).is_ok_and(|cast| !cast) {
@@ -0,0 +1,186 @@
+use crate::app::{
+    computers::calculation::clustering::{Dendrogram, Value},
+    states::calculation::settings::Settings,
+};
+use egui::{
+    Align2, Painter, Pos2, Rect, Response, Sense, Shape, Stroke, TextStyle, Ui, Vec2,
+    epaint::TextShape, pos2, vec2,
+};
+use std::f32::consts::FRAC_PI_2;
+
+/// Cell size
+const CELL: Vec2 = vec2(24.0, 16.0);
+
+/// Dendrogram height
+const DENDROGRAM: f32 = 80.0;
+
+/// Label width
+const MARGIN: f32 = 120.0;
+
+/// Clustering heatmap widget
+///
+/// Rows are fatty acids, columns are samples, both in the order of their
+/// dendrograms. Each row is colored from its minimum to its maximum.
+pub(crate) struct Heatmap<'a> {
+    value: &'a Value,
+    settings: &'a Settings,
+}
+
+impl<'a> Heatmap<'a> {
+    pub(crate) fn new(value: &'a Value, settings: &'a Settings) -> Self {
+        Self { value, settings }
+    }
+
+    pub(crate) fn show(self, ui: &mut Ui) -> Response {
+        let rows = &self.value.label_dendrogram.order;
+        let columns = &self.value.sample_dendrogram.order;
+        let size = vec2(
+            DENDROGRAM + columns.len() as f32 * CELL.x + MARGIN,
+            DENDROGRAM + rows.len() as f32 * CELL.y + MARGIN,
+        );
+        let (mut response, painter) = ui.allocate_painter(size, Sense::hover());
+        let origin = response.rect.min + Vec2::splat(DENDROGRAM);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let color = ui.visuals().text_color();
+        let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+        let [low, high] = [
+            ui.visuals().extreme_bg_color,
+            ui.visuals().selection.bg_fill,
+        ];
+        // Cells and fatty acids
+        for (y, &row) in rows.iter().enumerate() {
+            let values = &self.value.values[row];
+            let (min, max) = values
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                    (min.min(value), max.max(value))
+                });
+            for (x, &column) in columns.iter().enumerate() {
+                let t = if max > min {
+                    (values[column] - min) / (max - min)
+                } else {
+                    0.0
+                };
+                painter.rect_filled(
+                    Rect::from_min_size(origin + vec2(x as f32 * CELL.x, y as f32 * CELL.y), CELL),
+                    0.0,
+                    low.lerp_to_gamma(high, t as _),
+                );
+            }
+            painter.text(
+                origin
+                    + vec2(
+                        columns.len() as f32 * CELL.x + 4.0,
+                        (y as f32 + 0.5) * CELL.y,
+                    ),
+                Align2::LEFT_CENTER,
+                &self.value.labels[row],
+                font_id.clone(),
+                color,
+            );
+        }
+        // Samples (rotated)
+        for (x, &column) in columns.iter().enumerate() {
+            let galley =
+                painter.layout_no_wrap(self.value.samples[column].clone(), font_id.clone(), color);
+            let position = origin
+                + vec2(
+                    (x as f32 + 0.5) * CELL.x + galley.size().y / 2.0,
+                    rows.len() as f32 * CELL.y + 4.0,
+                );
+            painter.add(TextShape::new(position, galley, color).with_angle(FRAC_PI_2));
+        }
+        // Dendrograms
+        dendrogram(
+            &painter,
+            &self.value.sample_dendrogram,
+            |position, height| {
+                pos2(
+                    origin.x + (position + 0.5) * CELL.x,
+                    origin.y - height * DENDROGRAM,
+                )
+            },
+            stroke,
+        );
+        dendrogram(
+            &painter,
+            &self.value.label_dendrogram,
+            |position, height| {
+                pos2(
+                    origin.x - height * DENDROGRAM,
+                    origin.y + (position + 0.5) * CELL.y,
+                )
+            },
+            stroke,
+        );
+        // Hover
+        if let Some(pointer) = response.hover_pos() {
+            let offset = pointer - origin;
+            if offset.x >= 0.0 && offset.y >= 0.0 {
+                let x = (offset.x / CELL.x) as usize;
+                let y = (offset.y / CELL.y) as usize;
+                if let (Some(&column), Some(&row)) = (columns.get(x), rows.get(y)) {
+                    let value = self.format(self.value.values[row][column]);
+                    response = response.on_hover_ui_at_pointer(|ui| {
+                        ui.label(&self.value.samples[column]);
+                        ui.label(&self.value.labels[row]);
+                        ui.label(value);
+                    });
+                }
+            }
+        }
+        response
+    }
+
+    fn format(&self, mut value: f64) -> String {
+        let precision = self.settings.precision;
+        if self.settings.percent {
+            value *= 100.0;
+            format!("{value:.precision$}%")
+        } else {
+            format!("{value:.precision$}")
+        }
+    }
+}
+
+/// Draws the dendrogram, `point` maps the leaf position and the relative
+/// height to the screen
+fn dendrogram(
+    painter: &Painter,
+    dendrogram: &Dendrogram,
+    point: impl Fn(f32, f32) -> Pos2,
+    stroke: Stroke,
+) {
+    let leaves = dendrogram.order.len();
+    let mut positions = vec![0.0; leaves + dendrogram.merges.len()];
+    let mut heights = vec![0.0; leaves + dendrogram.merges.len()];
+    for (position, &leaf) in dendrogram.order.iter().enumerate() {
+        positions[leaf] = position as f32;
+    }
+    // Undefined distances are drawn at the top
+    let max = dendrogram
+        .merges
+        .iter()
+        .map(|merge| merge.distance)
+        .filter(|distance| distance.is_finite())
+        .fold(0.0, f64::max);
+    for (index, merge) in dendrogram.merges.iter().enumerate() {
+        let height = if max > 0.0 {
+            (merge.distance / max).min(1.0) as f32
+        } else {
+            0.0
+        };
+        let [left, right] = [merge.left, merge.right];
+        painter.add(Shape::line(
+            vec![
+                point(positions[left], heights[left]),
+                point(positions[left], height),
+                point(positions[right], height),
+                point(positions[right], heights[right]),
+            ],
+            stroke,
+        ));
+        positions[leaves + index] = (positions[left] + positions[right]) / 2.0;
+        heights[leaves + index] = height;
+    }
+}
@@ -1,8 +1,13 @@
-use crate::{app::states::calculation::settings::Settings, utils::chaddock::Sign};
-use egui::{Label, Response, RichText, TextStyle, TextWrapMode, Ui, Widget};
+use crate::{
+    app::states::calculation::settings::Settings,
+    r#const::{CORRELATION, COUNT, EM_DASH, P_VALUE, Q_VALUE},
+    utils::chaddock::Sign,
+};
+use egui::{Grid, Label, Response, RichText, TextStyle, TextWrapMode, Ui, Widget};
 #[cfg(feature = "markdown")]
 use egui_ext::Markdown as _;
 use egui_extras::{Column, TableBuilder, TableRow};
+use egui_l20n::prelude::*;
 use polars::prelude::*;
 use std::mem::take;
 use tracing::instrument;
@@ -63,22 +68,66 @@ impl<'a> Correlations<'a> {
             });
         }
         for series in iter {
+            let fields = series.struct_()?;
+            let value = fields.field_by_name(CORRELATION)?.f64()?.get(index);
+            let count = fields.field_by_name(COUNT)?.u32()?.get(index);
+            let p_value = fields.field_by_name(P_VALUE)?.f64()?.get(index);
+            let q_value = fields.field_by_name(Q_VALUE)?.f64()?.get(index);
             row.col(|ui| {
-                let value = series.f64().unwrap().get(index).unwrap();
-                let sign = Sign::from(value);
+                let Some(value) = value else {
+                    ui.label(EM_DASH);
+                    return;
+                };
                 let mut color = ui.style().visuals.text_color();
-                if self.settings.chaddock {
-                    color = sign.chaddock().color(color);
-                } else {
-                    color = sign.color(color);
+                let significant = q_value.is_some_and(|q_value| q_value <= self.settings.alpha);
+                if significant || !self.settings.significance {
+                    let sign = Sign::from(value);
+                    if self.settings.chaddock {
+                        color = sign.chaddock().color(color);
+                    } else {
+                        color = sign.color(color);
+                    }
                 }
-                Label::new(RichText::new(value.to_string()).color(color)).ui(ui);
+                Label::new(RichText::new(value.to_string()).color(color))
+                    .ui(ui)
+                    .on_hover_ui(|ui| {
+                        Grid::new(ui.next_auto_id()).show(ui, |ui| {
+                            ui.label(ui.localize("Count"));
+                            match count {
+                                Some(count) => ui.label(count.to_string()),
+                                None => ui.label(EM_DASH),
+                            };
+                            ui.end_row();
+                            for (name, value) in [(P_VALUE, p_value), (Q_VALUE, q_value)] {
+                                ui.label(ui.localize(name))
+                                    .on_hover_localized(&format!("{name}.hover"));
+                                match value {
+                                    Some(value) => ui.label(format!("{value:.4}")),
+                                    None => ui.label(EM_DASH),
+                                };
+                                ui.end_row();
+                            }
+                        });
+                    });
             });
         }
         Ok(())
     }
 }
 
+/// Matrix of the correlation field (`Correlation`, `PValue`, `QValue`)
+pub(crate) fn matrix(data_frame: &DataFrame, field: &str) -> PolarsResult<DataFrame> {
+    let mut matrix = data_frame.clone();
+    for name in data_frame.get_column_names_str().into_iter().skip(1) {
+        let series = data_frame[name]
+            .struct_()?
+            .field_by_name(field)?
+            .with_name(name.into());
+        matrix.with_column(series)?;
+    }
+    Ok(matrix)
+}
+
 impl Widget for Correlations<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         self.show(ui)
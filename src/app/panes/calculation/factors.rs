@@ -0,0 +1,241 @@
+use crate::{
+    app::states::calculation::{
+        ID_SOURCE,
+        settings::{Axis, Preference, Settings},
+    },
+    assets::{REFERENCE_FACTORS, Reference},
+    r#const::{CARBON, ENRICHMENT, MEAN, SELECTIVITY, STANDARD_DEVIATION, UNSATURATION},
+    text::Text as _,
+};
+use egui::{Align2, Grid, Id, RichText, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Legend, Plot, PlotPoint, Points, Text};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Plot size
+const SIZE: f32 = 320.0;
+
+/// Factors view
+///
+/// Positional preference of each fatty acid by its enrichment factor, and its
+/// selectivity factor against the chain length or the unsaturation, both
+/// compared with the reference ranges of the plant oils.
+pub(crate) struct FactorsView<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> FactorsView<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+}
+
+impl FactorsView<'_> {
+    pub(crate) fn show(&self, ui: &mut Ui) {
+        _ = self.try_show(ui);
+    }
+
+    // | Label | FattyAcid | Carbon | Unsaturation | Enrichment | Selectivity |
+    // | ---   | ---       | ---    | ---          | ---        | ---         |
+    // | str   | struct    | i64    | i64          | struct[3]  | struct[3]   |
+    #[instrument(skip_all, err)]
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let rows = self.rows()?;
+        self.grid(ui, &rows);
+        ui.separator();
+        self.plot(ui, &rows);
+        Ok(())
+    }
+
+    fn rows(&self) -> PolarsResult<Vec<Row>> {
+        let labels = self.data_frame[LABEL].str()?;
+        let fatty_acids = self.data_frame.try_fatty_acid()?;
+        let carbons = self.data_frame[CARBON].i64()?;
+        let unsaturations = self.data_frame[UNSATURATION].i64()?;
+        let enrichment = self.data_frame[ENRICHMENT].struct_()?;
+        let enrichment_means = enrichment.field_by_name(MEAN)?;
+        let enrichment_standard_deviations = enrichment.field_by_name(STANDARD_DEVIATION)?;
+        let selectivity = self.data_frame[SELECTIVITY].struct_()?;
+        let selectivity_means = selectivity.field_by_name(MEAN)?;
+        let selectivity_standard_deviations = selectivity.field_by_name(STANDARD_DEVIATION)?;
+        let finite = |value: Option<f64>| value.filter(|value| value.is_finite());
+        let mut rows = Vec::with_capacity(self.data_frame.height());
+        for row in 0..self.data_frame.height() {
+            let reference = fatty_acids.get(row)?.and_then(|fatty_acid| {
+                REFERENCE_FACTORS
+                    .iter()
+                    .find(|reference| reference.matches(&fatty_acid))
+            });
+            rows.push(Row {
+                label: labels.get(row).unwrap_or_default().to_owned(),
+                carbon: carbons.get(row),
+                unsaturation: unsaturations.get(row),
+                enrichment: finite(enrichment_means.f64()?.get(row)),
+                enrichment_standard_deviation: finite(
+                    enrichment_standard_deviations.f64()?.get(row),
+                ),
+                selectivity: finite(selectivity_means.f64()?.get(row)),
+                selectivity_standard_deviation: finite(
+                    selectivity_standard_deviations.f64()?.get(row),
+                ),
+                reference,
+            });
+        }
+        Ok(rows)
+    }
+
+    fn grid(&self, ui: &mut Ui, rows: &[Row]) {
+        let neutral = if self.settings.normalize_factors {
+            1.0 / 3.0
+        } else {
+            1.0
+        };
+        Grid::new(Id::new(ID_SOURCE).with("Factors"))
+            .striped(true)
+            .show(ui, |ui| {
+                ui.heading(ui.localize("FattyAcid"));
+                ui.heading(ui.localize("EnrichmentFactor.abbreviation"))
+                    .on_hover_localized("EnrichmentFactor.hover");
+                ui.heading(ui.localize("Preference"))
+                    .on_hover_localized("Preference.hover");
+                ui.heading(ui.localize("ReferenceRange"))
+                    .on_hover_localized("ReferenceRange.hover");
+                ui.heading(ui.localize("SelectivityFactor.abbreviation"));
+                ui.heading(ui.localize("ReferenceRange"))
+                    .on_hover_localized("ReferenceRange.hover");
+                ui.end_row();
+                for row in rows {
+                    ui.label(&row.label);
+                    // Enrichment factor
+                    ui.label(self.format(row.enrichment, row.enrichment_standard_deviation));
+                    // Preference
+                    match row.enrichment {
+                        Some(enrichment) => {
+                            let preference =
+                                Preference::new(enrichment, neutral, self.settings.factors.band);
+                            let color = match preference {
+                                Preference::Preferred => ui.visuals().selection.bg_fill,
+                                Preference::Random => ui.visuals().text_color(),
+                                Preference::Excluded => ui.visuals().warn_fg_color,
+                            };
+                            ui.label(RichText::new(ui.localize(preference.text())).color(color))
+                                .on_hover_localized(preference.hover_text());
+                        }
+                        None => {
+                            ui.label("");
+                        }
+                    }
+                    // Reference range
+                    self.range(
+                        ui,
+                        row.reference
+                            .map(|reference| (reference, reference.enrichment)),
+                        row.enrichment,
+                    );
+                    // Selectivity factor
+                    ui.label(self.format(row.selectivity, row.selectivity_standard_deviation));
+                    self.range(
+                        ui,
+                        row.reference
+                            .map(|reference| (reference, reference.selectivity)),
+                        row.selectivity,
+                    );
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn plot(&self, ui: &mut Ui, rows: &[Row]) {
+        let axis = self.settings.factors.axis;
+        let name = ui.localize("SelectivityFactor");
+        ui.label(RichText::new(&name).strong());
+        let mut points = Vec::new();
+        let mut texts = Vec::new();
+        for row in rows {
+            let x = match axis {
+                Axis::Carbon => row.carbon,
+                Axis::Unsaturation => row.unsaturation,
+            };
+            if let (Some(x), Some(y)) = (x, row.selectivity) {
+                points.push([x as f64, y]);
+                texts.push((PlotPoint::new(x as f64, y), row.label.clone()));
+            }
+        }
+        let color = ui.visuals().selection.bg_fill;
+        let text_color = ui.visuals().text_color();
+        Plot::new(Id::new(ID_SOURCE).with("SelectivityFactor"))
+            .width(SIZE * 2.0)
+            .height(SIZE)
+            .x_axis_label(ui.localize(axis.text()))
+            .y_axis_label(ui.localize("SelectivityFactor.abbreviation"))
+            .legend(Legend::default())
+            .show(ui, |ui| {
+                ui.points(Points::new(&name, points).color(color).radius(4.0));
+                for (point, label) in texts {
+                    ui.text(
+                        Text::new("", point, RichText::new(label).small())
+                            .color(text_color)
+                            .anchor(Align2::LEFT_BOTTOM),
+                    );
+                }
+            });
+    }
+
+    /// Reference range, highlighted if the value is out of it
+    fn range(&self, ui: &mut Ui, reference: Option<(&Reference, (f64, f64))>, value: Option<f64>) {
+        let Some((reference, (min, max))) = reference else {
+            ui.label("");
+            return;
+        };
+        // Reference ranges are not normalized
+        let (min, max) = if self.settings.normalize_factors {
+            (min / 3.0, max / 3.0)
+        } else {
+            (min, max)
+        };
+        let precision = self.settings.precision;
+        let mut text = RichText::new(format!("{min:.precision$}–{max:.precision$}"));
+        if value.is_some_and(|value| value < min || value > max) {
+            text = text.color(ui.visuals().error_fg_color);
+        }
+        ui.label(text).on_hover_ui(|ui| {
+            ui.label(&reference.name);
+            ui.horizontal(|ui| {
+                ui.label(&reference.source);
+                ui.hyperlink_to(
+                    format!("doi:{}", reference.doi),
+                    format!("https://doi.org/{}", reference.doi),
+                );
+            });
+        });
+    }
+
+    fn format(&self, mean: Option<f64>, standard_deviation: Option<f64>) -> String {
+        let precision = self.settings.precision;
+        match (mean, standard_deviation) {
+            (Some(mean), Some(standard_deviation)) if self.settings.standard_deviation => {
+                format!("{mean:.precision$} ± {standard_deviation:.precision$}")
+            }
+            (Some(mean), _) => format!("{mean:.precision$}"),
+            (None, _) => String::new(),
+        }
+    }
+}
+
+/// Factors of the fatty acid
+struct Row {
+    label: String,
+    carbon: Option<i64>,
+    unsaturation: Option<i64>,
+    enrichment: Option<f64>,
+    enrichment_standard_deviation: Option<f64>,
+    selectivity: Option<f64>,
+    selectivity_standard_deviation: Option<f64>,
+    reference: Option<&'static Reference>,
+}
@@ -0,0 +1,187 @@
+use crate::{
+    app::states::calculation::{
+        ID_SOURCE,
+        settings::{Chart, Settings},
+    },
+    r#const::{NAME, SAMPLE},
+    utils::ui::color,
+};
+use egui::{Color32, Id, RichText, Stroke, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoint, PlotPoints, Polygon, Text};
+use polars::prelude::*;
+use std::f64::consts::{FRAC_PI_2, TAU};
+use tracing::instrument;
+
+/// Plot size
+const SIZE: f32 = 480.0;
+
+/// Indices plot
+///
+/// Visible indices (in the order of the indices list) of each sample, every
+/// index is normalized by its maximum absolute value across the samples.
+pub(crate) struct IndicesPlot<'a> {
+    data_frame: &'a DataFrame,
+    samples: &'a [String],
+    settings: &'a Settings,
+}
+
+impl<'a> IndicesPlot<'a> {
+    pub(crate) fn new(
+        data_frame: &'a DataFrame,
+        samples: &'a [String],
+        settings: &'a Settings,
+    ) -> Self {
+        Self {
+            data_frame,
+            samples,
+            settings,
+        }
+    }
+}
+
+impl IndicesPlot<'_> {
+    pub(crate) fn show(&self, ui: &mut Ui) {
+        _ = self.try_show(ui);
+    }
+
+    // | Name | StereospecificNumbers123 | ... |
+    // | ---  | ---                      | ... |
+    // | str  | struct[3]                | ... |
+    #[instrument(skip_all, err)]
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let names: Vec<_> = self.data_frame[NAME]
+            .str()?
+            .iter()
+            .map(|name| ui.localize(name.unwrap_or_default()))
+            .collect();
+        let values = self.values()?;
+        match self.settings.indices_plot.chart {
+            Chart::Radar => radar(ui, &names, self.samples, &values),
+            Chart::Bars => bars(ui, &names, self.samples, &values),
+        }
+        Ok(())
+    }
+
+    /// Normalized values, rows are indices, columns are samples
+    fn values(&self) -> PolarsResult<Vec<Vec<f64>>> {
+        let name = self
+            .settings
+            .indices_plot
+            .stereospecific_numbers
+            .to_string();
+        let samples = self.data_frame[name.as_str()]
+            .struct_()?
+            .field_by_name(SAMPLE)?;
+        let mut values = Vec::with_capacity(self.data_frame.height());
+        for row in 0..self.data_frame.height() {
+            let mut row_values = vec![0.0; self.samples.len()];
+            if let Some(sample) = samples.array()?.get_as_series(row) {
+                for (index, value) in sample.f64()?.iter().enumerate().take(row_values.len()) {
+                    row_values[index] = value.filter(|value| value.is_finite()).unwrap_or_default();
+                }
+            }
+            let max = row_values
+                .iter()
+                .fold(0.0, |max: f64, value| max.max(value.abs()));
+            if max > 0.0 {
+                for value in &mut row_values {
+                    *value /= max;
+                }
+            }
+            values.push(row_values);
+        }
+        Ok(values)
+    }
+}
+
+/// Radar chart, one polygon per sample
+fn radar(ui: &mut Ui, names: &[String], samples: &[String], values: &[Vec<f64>]) {
+    let count = names.len();
+    let point = |axis: usize, radius: f64| {
+        let angle = FRAC_PI_2 - TAU * axis as f64 / count.max(1) as f64;
+        [radius * angle.cos(), radius * angle.sin()]
+    };
+    let label = RichText::new(ui.localize("Chart-Radar")).strong();
+    ui.label(label).on_hover_localized("Chart-Radar.hover");
+    let weak = ui.visuals().weak_text_color();
+    Plot::new(Id::new(ID_SOURCE).with("IndicesRadar"))
+        .width(SIZE)
+        .height(SIZE)
+        .data_aspect(1.0)
+        .show_axes(false)
+        .show_grid(false)
+        .legend(Legend::default())
+        .show(ui, |ui| {
+            // Grid
+            for level in [0.25, 0.5, 0.75, 1.0] {
+                let points = (0..count).map(|axis| point(axis, level)).collect();
+                ui.polygon(
+                    Polygon::new("", PlotPoints::new(points))
+                        .stroke(Stroke::new(0.5, weak))
+                        .fill_color(Color32::TRANSPARENT),
+                );
+            }
+            for (axis, name) in names.iter().enumerate() {
+                ui.line(
+                    Line::new("", PlotPoints::new(vec![[0.0, 0.0], point(axis, 1.0)]))
+                        .color(weak)
+                        .width(0.5),
+                );
+                let [x, y] = point(axis, 1.15);
+                ui.text(Text::new(
+                    "",
+                    PlotPoint::new(x, y),
+                    RichText::new(name).small(),
+                ));
+            }
+            // Samples
+            for (index, sample) in samples.iter().enumerate() {
+                let points = (0..count)
+                    .map(|axis| point(axis, values[axis][index].max(0.0)))
+                    .collect();
+                let color = color(index);
+                ui.polygon(
+                    Polygon::new(sample, PlotPoints::new(points))
+                        .stroke(Stroke::new(1.5, color))
+                        .fill_color(color.gamma_multiply(0.1)),
+                );
+            }
+        });
+}
+
+/// Grouped bars, one group per index, one bar per sample
+fn bars(ui: &mut Ui, names: &[String], samples: &[String], values: &[Vec<f64>]) {
+    let width = 0.8 / samples.len().max(1) as f64;
+    let label = RichText::new(ui.localize("Chart-Bars")).strong();
+    ui.label(label).on_hover_localized("Chart-Bars.hover");
+    let labels = names.to_vec();
+    Plot::new(Id::new(ID_SOURCE).with("IndicesBars"))
+        .width(SIZE * 2.0)
+        .height(SIZE)
+        .x_axis_formatter(move |mark, _| {
+            let index = mark.value.round();
+            if index == mark.value && index >= 0.0 {
+                labels.get(index as usize).cloned().unwrap_or_default()
+            } else {
+                String::new()
+            }
+        })
+        .legend(Legend::default())
+        .show(ui, |ui| {
+            for (index, sample) in samples.iter().enumerate() {
+                let offset = (index as f64 - (samples.len() as f64 - 1.0) / 2.0) * width;
+                let bars = values
+                    .iter()
+                    .zip(names)
+                    .enumerate()
+                    .map(|(row, (values, name))| {
+                        Bar::new(row as f64 + offset, values[index])
+                            .name(name)
+                            .width(width)
+                    })
+                    .collect();
+                ui.bar_chart(BarChart::new(sample, bars).color(color(index)));
+            }
+        });
+}
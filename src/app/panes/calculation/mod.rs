@@ -1,4 +1,14 @@
-use self::{correlations::Correlations, properties::Properties, table::TableView};
+use self::{
+    clustering::Heatmap,
+    correlations::{Correlations, matrix},
+    factors::FactorsView,
+    indices::IndicesPlot,
+    pca::Pca,
+    plot::PlotView,
+    properties::Properties,
+    series::SeriesView,
+    table::TableView,
+};
 use super::{Behavior, MARGIN};
 #[cfg(feature = "markdown")]
 use crate::r#const::markdown::CORRELATIONS;
@@ -6,6 +16,11 @@ use crate::{
     app::{
         computers::calculation::{
             Computed as CalculationComputed, Key as CalculationKey,
+            clustering::{Computed as ClusteringComputed, Key as ClusteringKey},
+            factors::{Computed as FactorsComputed, Key as FactorsKey},
+            pca::{Computed as PcaComputed, Key as PcaKey},
+            plot::{Computed as PlotComputed, Key as PlotKey},
+            series::{Computed as SeriesComputed, Key as SeriesKey},
             sum::{
                 correlations::{Computed as CorrelationsComputed, Key as CorrelationsKey},
                 properties::{
@@ -17,11 +32,24 @@ use crate::{
             },
         },
         identifiers::COMPOSE,
-        states::calculation::{ID_SOURCE, State, settings::Settings},
-        widgets::butons::{ResetButton, ResizeButton, SettingsButton},
+        states::{
+            calculation::{
+                ID_SOURCE, State,
+                settings::{
+                    Axis, Chart, Distance, Linkage, STEREOSPECIFIC_NUMBERS, Settings,
+                    StereospecificNumbers,
+                },
+            },
+            composition::settings::View,
+        },
+        widgets::{
+            butons::{ResetButton, ResizeButton, SettingsButton},
+            reference::References,
+        },
     },
-    r#const::THRESHOLD,
-    export::ron,
+    r#const::{COMPONENT, CORRELATION, EM_DASH, P_VALUE, Q_VALUE, THRESHOLD},
+    export::{ron, xlsx},
+    text::Text,
     utils::{
         HashedDataFrame, HashedMetaDataFrame,
         metadata::{authors, date, description, name},
@@ -29,14 +57,15 @@ use crate::{
 };
 use anyhow::Result;
 use egui::{
-    CentralPanel, CursorIcon, Frame, Id, MenuBar, Response, RichText, ScrollArea, TextStyle,
-    TopBottomPanel, Ui, Widget as _, Window, util::hash,
+    CentralPanel, ComboBox, CursorIcon, Frame, Id, MenuBar, Response, RichText, ScrollArea, Slider,
+    TextStyle, TopBottomPanel, Ui, Widget as _, Window, util::hash,
 };
 #[cfg(feature = "markdown")]
 use egui_ext::Markdown as _;
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::{
-    CALCULATOR, FLOPPY_DISK, INTERSECT_THREE, LIST, SIGMA, SLIDERS_HORIZONTAL, X,
+    BOOKS, CALCULATOR, CHART_LINE, CHART_SCATTER, FLOPPY_DISK, INTERSECT_THREE, LIST, SCALES,
+    SIGMA, SLIDERS_HORIZONTAL, TREE_STRUCTURE, X,
 };
 use egui_tiles::{TileId, UiResponse};
 use lipid::prelude::*;
@@ -164,9 +193,24 @@ impl Pane {
         // Composition
         self.composition_button(ui, state);
         ui.separator();
+        // View
+        self.view_button(ui, state);
+        ui.separator();
         response
     }
 
+    /// View button
+    fn view_button(&self, ui: &mut Ui, state: &mut State) {
+        ui.menu_button(RichText::new(state.settings.view.icon()).heading(), |ui| {
+            ui.selectable_value(&mut state.settings.view, View::Plot, View::Plot.text())
+                .on_hover_text(View::Plot.hover_text());
+            ui.selectable_value(&mut state.settings.view, View::Table, View::Table.text())
+                .on_hover_text(View::Table.hover_text());
+        })
+        .response
+        .on_hover_text(state.settings.view.hover_text());
+    }
+
     fn list_button(&self, ui: &mut Ui, state: &mut State) {
         ui.menu_button(RichText::new(LIST).heading(), |ui| {
             let mut clicked = false;
@@ -202,6 +246,38 @@ impl Pane {
                 ),
             )
             .on_hover_localized("Correlation.hover");
+            ui.toggle_value(
+                &mut state.windows.open_pca,
+                (
+                    RichText::new(CHART_SCATTER).heading(),
+                    RichText::new(ui.localize("PrincipalComponentAnalysis")).heading(),
+                ),
+            )
+            .on_hover_localized("PrincipalComponentAnalysis.hover");
+            ui.toggle_value(
+                &mut state.windows.open_clustering,
+                (
+                    RichText::new(TREE_STRUCTURE).heading(),
+                    RichText::new(ui.localize("HierarchicalClustering")).heading(),
+                ),
+            )
+            .on_hover_localized("HierarchicalClustering.hover");
+            ui.toggle_value(
+                &mut state.windows.open_factors,
+                (
+                    RichText::new(SCALES).heading(),
+                    RichText::new(ui.localize("FactorAnalysis")).heading(),
+                ),
+            )
+            .on_hover_localized("FactorAnalysis.hover");
+            ui.toggle_value(
+                &mut state.windows.open_series,
+                (
+                    RichText::new(CHART_LINE).heading(),
+                    RichText::new(ui.localize("Series")).heading(),
+                ),
+            )
+            .on_hover_localized("Series.hover");
             ui.toggle_value(
                 &mut state.windows.open_sum,
                 (
@@ -218,6 +294,15 @@ impl Pane {
                 ),
             )
             .on_hover_localized("BiodieselProperties.hover");
+            ui.separator();
+            ui.toggle_value(
+                &mut state.windows.open_references,
+                (
+                    RichText::new(BOOKS).heading(),
+                    RichText::new(ui.localize("Reference?PluralCategory=other")).heading(),
+                ),
+            )
+            .on_hover_localized("Reference.hover");
         });
     }
 
@@ -253,6 +338,8 @@ impl Pane {
                     col(STEREOSPECIFIC_NUMBERS123),
                     col(STEREOSPECIFIC_NUMBERS13),
                     col(STEREOSPECIFIC_NUMBERS2),
+                    col(STEREOSPECIFIC_NUMBERS1),
+                    col(STEREOSPECIFIC_NUMBERS3),
                 ])
                 .collect()?;
             frames.push(MetaDataFrame::new(
@@ -304,6 +391,8 @@ impl Pane {
                 col(STEREOSPECIFIC_NUMBERS123),
                 col(STEREOSPECIFIC_NUMBERS13),
                 col(STEREOSPECIFIC_NUMBERS2),
+                col(STEREOSPECIFIC_NUMBERS1),
+                col(STEREOSPECIFIC_NUMBERS3),
             ])
             .collect()?;
         let frame = MetaDataFrame::new(meta, HashedDataFrame::new(data)?);
@@ -385,7 +474,43 @@ impl Pane {
             .collect();
         state.settings.threshold.manual =
             self.target[THRESHOLD].bool()?.into_no_null_iter().collect();
-        TableView::new(&self.target, state).show(ui);
+        state.settings.order = if state.settings.clustering.sort {
+            ui.memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<ClusteringComputed>()
+                    .get(ClusteringKey::new(
+                        &self.target,
+                        &self.frames,
+                        &state.settings,
+                    ))
+            })
+            .order
+        } else {
+            Vec::new()
+        };
+        match state.settings.view {
+            View::Plot => {
+                let data_frame = ui.memory_mut(|memory| {
+                    memory
+                        .caches
+                        .cache::<PlotComputed>()
+                        .get(PlotKey::new(&self.target, &state.settings))
+                });
+                let names = match state.settings.index {
+                    Some(index) => vec![self.frames[index].meta.format(" ").to_string()],
+                    None => self
+                        .frames
+                        .iter()
+                        .map(|frame| frame.meta.format(" ").to_string())
+                        .collect(),
+                };
+                PlotView::new(&data_frame, &state.settings)
+                    .with_names(&names)
+                    .show(ui);
+            }
+            View::Table => TableView::new(&self.target, state).show(ui),
+        }
         Ok(())
     }
 }
@@ -393,8 +518,13 @@ impl Pane {
 impl Pane {
     fn windows(&mut self, ui: &mut Ui, state: &mut State) {
         self.correlations_window(ui, state);
+        self.pca_window(ui, state);
+        self.clustering_window(ui, state);
+        self.factors_window(ui, state);
+        self.series_window(ui, state);
         self.properties_window(ui, state);
         self.biodiesel_properties_window(ui, state);
+        self.references_window(ui, state);
         self.settings_window(ui, state);
     }
 
@@ -427,29 +557,386 @@ impl Pane {
             memory
                 .caches
                 .cache::<CorrelationsComputed>()
-                .get(CorrelationsKey::new(&self.target, settings))
+                .get(CorrelationsKey::new(&self.target, &self.frames, settings))
         });
+        let name = format!(
+            "{}.correlations.utca.xlsx",
+            self.title_with_separator(settings.index, "."),
+        );
+        if ui
+            .button((FLOPPY_DISK, "XLSX"))
+            .on_hover_localized("Save")
+            .on_hover_text(&name)
+            .clicked()
+        {
+            let correlations = matrix(&data_frame, CORRELATION)?;
+            let p_values = matrix(&data_frame, P_VALUE)?;
+            let q_values = matrix(&data_frame, Q_VALUE)?;
+            _ = xlsx::save_sheets(
+                &[
+                    (CORRELATION, &correlations),
+                    (P_VALUE, &p_values),
+                    (Q_VALUE, &q_values),
+                ],
+                &name,
+            );
+        }
+        ui.separator();
         Correlations::new(&data_frame, settings).show(ui);
         Ok(())
     }
 
+    fn pca_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{CHART_SCATTER} Calculation PCA"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Pca"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_pca)
+            .show(ui.ctx(), |ui| self.pca_content(ui, &mut state.settings))
+        {
+            inner_response
+                .response
+                .on_hover_text(self.title(state.settings.index));
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn pca_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let value = ui.memory_mut(|memory| {
+            memory.caches.cache::<PcaComputed>().get(PcaKey::new(
+                &self.target,
+                &self.frames,
+                settings,
+            ))
+        });
+        ui.horizontal(|ui| -> PolarsResult<()> {
+            variables(ui, &mut settings.pca.stereospecific_numbers);
+            ui.checkbox(&mut settings.pca.center, ui.localize("Center"))
+                .on_hover_localized("Center.hover");
+            ui.checkbox(&mut settings.pca.scale, ui.localize("Scale"))
+                .on_hover_localized("Scale.hover");
+            ui.separator();
+            // Group
+            let mut keys: Vec<_> = self
+                .frames
+                .iter()
+                .flat_map(|frame| frame.meta.keys().cloned())
+                .collect();
+            keys.sort();
+            keys.dedup();
+            let stem = ui.localize("Stem");
+            ui.label(ui.localize("Group"));
+            ComboBox::from_id_salt("Group")
+                .selected_text(settings.pca.group.as_deref().unwrap_or(&stem))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut settings.pca.group, None, stem.as_str())
+                        .on_hover_localized("Stem.hover");
+                    for key in keys {
+                        ui.selectable_value(&mut settings.pca.group, Some(key.clone()), key);
+                    }
+                })
+                .response
+                .on_hover_localized("Group.hover");
+            ui.separator();
+            // Axes
+            let components = value.scree[COMPONENT].str()?;
+            for (id_salt, component) in [("X", &mut settings.pca.x), ("Y", &mut settings.pca.y)] {
+                ui.label(id_salt);
+                ComboBox::from_id_salt(id_salt)
+                    .selected_text(components.get(*component).unwrap_or(EM_DASH))
+                    .show_ui(ui, |ui| {
+                        for (index, name) in components.iter().enumerate() {
+                            ui.selectable_value(component, index, name.unwrap_or(EM_DASH));
+                        }
+                    });
+            }
+            ui.separator();
+            // Save
+            let name = format!(
+                "{}.pca.utca.xlsx",
+                self.title_with_separator(settings.index, "."),
+            );
+            if ui
+                .button((FLOPPY_DISK, "XLSX"))
+                .on_hover_localized("Save")
+                .on_hover_text(&name)
+                .clicked()
+            {
+                _ = xlsx::save_sheets(
+                    &[
+                        ("Scree", &value.scree),
+                        ("Scores", &value.scores),
+                        ("Loadings", &value.loadings),
+                    ],
+                    &name,
+                );
+            }
+            Ok(())
+        })
+        .inner?;
+        ui.separator();
+        Pca::new(&value, settings).show(ui).inner
+    }
+
+    fn clustering_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) =
+            Window::new(format!("{TREE_STRUCTURE} Calculation clustering"))
+                .id(ui.auto_id_with(ID_SOURCE).with("Clustering"))
+                .default_pos(ui.next_widget_position())
+                .open(&mut state.windows.open_clustering)
+                .scroll([true, true])
+                .show(ui.ctx(), |ui| {
+                    self.clustering_content(ui, &mut state.settings)
+                })
+        {
+            inner_response
+                .response
+                .on_hover_text(self.title(state.settings.index));
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn clustering_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let value = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ClusteringComputed>()
+                .get(ClusteringKey::new(&self.target, &self.frames, settings))
+        });
+        ui.horizontal(|ui| {
+            variables(ui, &mut settings.clustering.stereospecific_numbers);
+            ui.separator();
+            // Distance
+            ui.label(ui.localize("Distance"))
+                .on_hover_localized("Distance.hover");
+            let distances = Distance::ALL.map(|distance| (distance, ui.localize(distance.text())));
+            ComboBox::from_id_salt("Distance")
+                .selected_text(ui.localize(settings.clustering.distance.text()))
+                .show_ui(ui, |ui| {
+                    for (distance, text) in distances {
+                        ui.selectable_value(&mut settings.clustering.distance, distance, text)
+                            .on_hover_localized(distance.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(settings.clustering.distance.hover_text());
+            // Linkage
+            ui.label(ui.localize("Linkage"))
+                .on_hover_localized("Linkage.hover");
+            let linkages = Linkage::ALL.map(|linkage| (linkage, ui.localize(linkage.text())));
+            ComboBox::from_id_salt("Linkage")
+                .selected_text(ui.localize(settings.clustering.linkage.text()))
+                .show_ui(ui, |ui| {
+                    for (linkage, text) in linkages {
+                        ui.selectable_value(&mut settings.clustering.linkage, linkage, text)
+                            .on_hover_localized(linkage.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(settings.clustering.linkage.hover_text());
+            ui.separator();
+            ui.checkbox(
+                &mut settings.clustering.sort,
+                ui.localize("SortByClustering"),
+            )
+            .on_hover_localized("SortByClustering.hover");
+        });
+        ui.separator();
+        Heatmap::new(&value, settings).show(ui);
+        Ok(())
+    }
+
+    fn factors_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{SCALES} Calculation factors"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Factors"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_factors)
+            .scroll([true, true])
+            .show(ui.ctx(), |ui| self.factors_content(ui, &mut state.settings))
+        {
+            inner_response
+                .response
+                .on_hover_text(self.title(state.settings.index));
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn factors_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<FactorsComputed>()
+                .get(FactorsKey::new(&self.target, settings))
+        });
+        ui.horizontal(|ui| {
+            // Band
+            ui.label(ui.localize("Band"))
+                .on_hover_localized("Band.hover");
+            Slider::new(&mut settings.factors.band, 0.0..=1.0)
+                .custom_formatter(|value, _| format!("±{:.0}%", value * 100.0))
+                .ui(ui);
+            ui.separator();
+            // Axis
+            let axes = Axis::ALL.map(|axis| (axis, ui.localize(axis.text())));
+            ComboBox::from_id_salt("Axis")
+                .selected_text(ui.localize(settings.factors.axis.text()))
+                .show_ui(ui, |ui| {
+                    for (axis, text) in axes {
+                        ui.selectable_value(&mut settings.factors.axis, axis, text)
+                            .on_hover_localized(axis.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(settings.factors.axis.hover_text());
+        });
+        ui.separator();
+        FactorsView::new(&data_frame, settings).show(ui);
+        Ok(())
+    }
+
+    fn series_window(&mut self, ui: &mut Ui, state: &mut State) {
+        if let Some(inner_response) = Window::new(format!("{CHART_LINE} Calculation series"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Series"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_series)
+            .scroll([true, true])
+            .show(ui.ctx(), |ui| self.series_content(ui, &mut state.settings))
+        {
+            inner_response
+                .response
+                .on_hover_text(self.title(state.settings.index));
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    fn series_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let value = ui.memory_mut(|memory| {
+            memory.caches.cache::<SeriesComputed>().get(SeriesKey::new(
+                &self.target,
+                &self.frames,
+                settings,
+            ))
+        });
+        ui.horizontal(|ui| {
+            // Variable
+            let mut keys: Vec<_> = self
+                .frames
+                .iter()
+                .flat_map(|frame| frame.meta.keys().cloned())
+                .collect();
+            keys.sort();
+            keys.dedup();
+            let treatment = ui.localize("Treatment");
+            ui.label(ui.localize("Variable"))
+                .on_hover_localized("Variable.hover");
+            ComboBox::from_id_salt("Variable")
+                .selected_text(settings.series.variable.as_deref().unwrap_or(&treatment))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut settings.series.variable, None, treatment.as_str())
+                        .on_hover_localized("Treatment.hover");
+                    for key in keys {
+                        ui.selectable_value(&mut settings.series.variable, Some(key.clone()), key);
+                    }
+                });
+            ui.separator();
+            // Stereospecific numbers
+            let variables = STEREOSPECIFIC_NUMBERS.map(|stereospecific_numbers| {
+                (
+                    stereospecific_numbers,
+                    ui.localize(stereospecific_numbers.text()),
+                )
+            });
+            ComboBox::from_id_salt("StereospecificNumbers")
+                .selected_text(ui.localize(settings.series.stereospecific_numbers.text()))
+                .show_ui(ui, |ui| {
+                    for (stereospecific_numbers, text) in variables {
+                        ui.selectable_value(
+                            &mut settings.series.stereospecific_numbers,
+                            stereospecific_numbers,
+                            text,
+                        );
+                    }
+                });
+            ui.checkbox(
+                &mut settings.series.indices,
+                ui.localize("Index?PluralCategory=other"),
+            );
+        });
+        ui.separator();
+        SeriesView::new(&value, settings).show(ui);
+        Ok(())
+    }
+
     fn properties_window(&mut self, ui: &mut Ui, state: &mut State) {
         Window::new(format!("{SIGMA} Calculation properties"))
             .id(ui.auto_id_with(ID_SOURCE).with("Properties"))
             .default_pos(ui.next_widget_position())
             .open(&mut state.windows.open_sum)
-            .show(ui.ctx(), |ui| self.properties_content(ui, &state.settings));
+            .show(ui.ctx(), |ui| {
+                self.properties_content(ui, &mut state.settings)
+            });
     }
 
     #[instrument(skip_all, err)]
-    fn properties_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+    fn properties_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
         let data_frame = ui.memory_mut(|memory| {
             memory
                 .caches
                 .cache::<PropertiesComputed>()
                 .get(PropertiesKey::new(&self.target, settings))
         });
-        Properties::new(&data_frame, settings).show(ui).inner
+        ui.horizontal(|ui| {
+            let indices_plot = &mut settings.indices_plot;
+            for view in [View::Table, View::Plot] {
+                ui.selectable_value(&mut indices_plot.view, view, view.icon())
+                    .on_hover_text(view.hover_text());
+            }
+            if indices_plot.view == View::Plot {
+                ui.separator();
+                let charts =
+                    [Chart::Radar, Chart::Bars].map(|chart| (chart, ui.localize(chart.text())));
+                ComboBox::from_id_salt("Chart")
+                    .selected_text(ui.localize(indices_plot.chart.text()))
+                    .show_ui(ui, |ui| {
+                        for (chart, text) in charts {
+                            ui.selectable_value(&mut indices_plot.chart, chart, text)
+                                .on_hover_localized(chart.hover_text());
+                        }
+                    });
+                let variables = STEREOSPECIFIC_NUMBERS.map(|stereospecific_numbers| {
+                    (
+                        stereospecific_numbers,
+                        ui.localize(stereospecific_numbers.text()),
+                    )
+                });
+                ComboBox::from_id_salt("StereospecificNumbers")
+                    .selected_text(ui.localize(indices_plot.stereospecific_numbers.text()))
+                    .show_ui(ui, |ui| {
+                        for (stereospecific_numbers, text) in variables {
+                            ui.selectable_value(
+                                &mut indices_plot.stereospecific_numbers,
+                                stereospecific_numbers,
+                                text,
+                            );
+                        }
+                    });
+            }
+        });
+        ui.separator();
+        match settings.indices_plot.view {
+            View::Plot => {
+                let frames = match settings.index {
+                    Some(index) => &self.frames[index..=index],
+                    None => &self.frames[..],
+                };
+                let samples: Vec<_> = frames
+                    .iter()
+                    .map(|frame| frame.meta.format(" ").to_string())
+                    .collect();
+                IndicesPlot::new(&data_frame, &samples, settings).show(ui);
+                Ok(())
+            }
+            View::Table => Properties::new(&data_frame, settings).show(ui).inner,
+        }
     }
 
     fn biodiesel_properties_window(&mut self, ui: &mut Ui, state: &mut State) {
@@ -474,7 +961,20 @@ impl Pane {
                 .cache::<BiodieselPropertiesComputed>()
                 .get(BiodieselPropertiesKey::new(&self.target, settings))
         });
-        Properties::new(&data_frame, settings).show(ui).inner
+        Properties::new(&data_frame, settings)
+            .with_standards(true)
+            .show(ui)
+            .inner
+    }
+
+    fn references_window(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{BOOKS} Calculation references"))
+            .id(ui.auto_id_with(ID_SOURCE).with("References"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_references)
+            .show(ui.ctx(), |ui| {
+                ui.add(References::new(&state.settings.indices));
+            });
     }
 
     fn settings_window(&mut self, ui: &mut Ui, state: &mut State) {
@@ -488,6 +988,41 @@ impl Pane {
     }
 }
 
+/// Variables: one of the stereospecific numbers or all of them concatenated
+fn variables(ui: &mut Ui, stereospecific_numbers: &mut Option<StereospecificNumbers>) {
+    let variables: Vec<_> = STEREOSPECIFIC_NUMBERS
+        .map(Some)
+        .into_iter()
+        .chain([None])
+        .map(|stereospecific_numbers| {
+            let text = match stereospecific_numbers {
+                Some(stereospecific_numbers) => ui.localize(stereospecific_numbers.text()),
+                None => ui.localize("All"),
+            };
+            (stereospecific_numbers, text)
+        })
+        .collect();
+    let selected_text = variables
+        .iter()
+        .find(|(variable, _)| variable == stereospecific_numbers)
+        .map_or_default(|(_, text)| text.clone());
+    ComboBox::from_id_salt("Variables")
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            for (variable, text) in variables {
+                ui.selectable_value(stereospecific_numbers, variable, text);
+            }
+        })
+        .response
+        .on_hover_localized("Variables.hover");
+}
+
+mod clustering;
 mod correlations;
+mod factors;
+mod indices;
+mod pca;
+mod plot;
 mod properties;
+mod series;
 mod table;
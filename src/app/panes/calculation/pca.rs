@@ -0,0 +1,204 @@
+use crate::{
+    app::{
+        computers::calculation::pca::Value,
+        states::calculation::{ID_SOURCE, settings::Settings},
+    },
+    r#const::{COMPONENT, CUMULATIVE, EM_DASH, EXPLAINED, GROUP, NAME},
+};
+use egui::{Id, InnerResponse, RichText, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoint, PlotPoints, Points, Text};
+use indexmap::IndexMap;
+use lipid::prelude::*;
+use polars::prelude::*;
+
+/// Plot size
+const SIZE: f64 = 320.0;
+
+/// Bar width
+const WIDTH: f64 = 0.8;
+
+/// Principal component analysis widget
+pub(crate) struct Pca<'a> {
+    value: &'a Value,
+    settings: &'a Settings,
+}
+
+impl<'a> Pca<'a> {
+    pub(crate) fn new(value: &'a Value, settings: &'a Settings) -> Self {
+        Self { value, settings }
+    }
+
+    pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
+        ui.horizontal_top(|ui| -> PolarsResult<()> {
+            self.scree(ui)?;
+            ui.separator();
+            self.scores(ui)?;
+            ui.separator();
+            self.loadings(ui)?;
+            Ok(())
+        })
+    }
+
+    /// Component name and explained variance
+    fn axis(&self, index: usize) -> PolarsResult<String> {
+        let components = self.value.scree[COMPONENT].str()?;
+        let explained = self.value.scree[EXPLAINED].f64()?;
+        Ok(match (components.get(index), explained.get(index)) {
+            (Some(component), Some(explained)) => format!("{component} ({explained:.1}%)"),
+            _ => EM_DASH.to_owned(),
+        })
+    }
+
+    /// Component columns of the plot axes
+    fn components(&self, data_frame: &DataFrame) -> Option<[&Float64Chunked; 2]> {
+        let components = self.value.scree[COMPONENT].str().ok()?;
+        let x = components.get(self.settings.pca.x)?;
+        let y = components.get(self.settings.pca.y)?;
+        Some([data_frame[x].f64().ok()?, data_frame[y].f64().ok()?])
+    }
+
+    // | Component | Eigenvalue | Explained | Cumulative |
+    // | ---       | ---        | ---       | ---        |
+    // | str       | f64        | f64       | f64        |
+    fn scree(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let components = self.value.scree[COMPONENT].str()?;
+        let explained = self.value.scree[EXPLAINED].f64()?;
+        let cumulative = self.value.scree[CUMULATIVE].f64()?;
+        let bars = components
+            .iter()
+            .zip(explained)
+            .enumerate()
+            .filter_map(|(index, (component, explained))| {
+                Some(
+                    Bar::new(index as f64 + 1.0, explained?)
+                        .name(component.unwrap_or(EM_DASH))
+                        .width(WIDTH),
+                )
+            })
+            .collect();
+        let cumulative = PlotPoints::new(
+            cumulative
+                .iter()
+                .enumerate()
+                .filter_map(|(index, value)| Some([index as f64 + 1.0, value?]))
+                .collect(),
+        );
+        let names = [ui.localize("Explained"), ui.localize("Cumulative")];
+        ui.vertical(|ui| {
+            ui.label(RichText::new(ui.localize("Scree")).strong())
+                .on_hover_localized("Scree.hover");
+            Plot::new(Id::new(ID_SOURCE).with("Scree"))
+                .width(SIZE)
+                .height(SIZE)
+                .x_axis_label(ui.localize("Component"))
+                .y_axis_label(ui.localize("Explained"))
+                .y_axis_formatter(|mark, _| format!("{}%", mark.value))
+                .legend(Legend::default())
+                .show(ui, |ui| {
+                    let [explained_name, cumulative_name] = names;
+                    ui.bar_chart(BarChart::new(explained_name, bars));
+                    ui.line(Line::new(cumulative_name, cumulative));
+                });
+        });
+        Ok(())
+    }
+
+    // | Name | Group | PC1 | PC2 | ... |
+    // | ---  | ---   | --- | --- | ... |
+    // | str  | str   | f64 | f64 | ... |
+    fn scores(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let names = self.value.scores[NAME].str()?;
+        let groups = self.value.scores[GROUP].str()?;
+        let mut series = IndexMap::<_, Vec<_>>::new();
+        let mut points = Vec::new();
+        if let Some([x, y]) = self.components(&self.value.scores) {
+            for row in 0..self.value.scores.height() {
+                let (Some(x), Some(y)) = (x.get(row), y.get(row)) else {
+                    continue;
+                };
+                let group = groups.get(row).unwrap_or(EM_DASH).to_owned();
+                series.entry(group).or_default().push([x, y]);
+                points.push((names.get(row).unwrap_or(EM_DASH).to_owned(), [x, y]));
+            }
+        }
+        let [x_axis, y_axis] = [
+            self.axis(self.settings.pca.x)?,
+            self.axis(self.settings.pca.y)?,
+        ];
+        ui.vertical(|ui| {
+            ui.label(RichText::new(ui.localize("Scores")).strong())
+                .on_hover_localized("Scores.hover");
+            Plot::new(Id::new(ID_SOURCE).with("Scores"))
+                .width(SIZE)
+                .height(SIZE)
+                .x_axis_label(x_axis)
+                .y_axis_label(y_axis)
+                .label_formatter(move |_, value| nearest(&points, value))
+                .legend(Legend::default())
+                .show(ui, |ui| {
+                    for (group, series) in series {
+                        ui.points(Points::new(group, series).radius(4.0));
+                    }
+                });
+        });
+        Ok(())
+    }
+
+    // | Label | PC1 | PC2 | ... |
+    // | ---   | --- | --- | ... |
+    // | str   | f64 | f64 | ... |
+    fn loadings(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let labels = self.value.loadings[LABEL].str()?;
+        let mut points = Vec::new();
+        if let Some([x, y]) = self.components(&self.value.loadings) {
+            for row in 0..self.value.loadings.height() {
+                if let (Some(x), Some(y)) = (x.get(row), y.get(row)) {
+                    points.push((labels.get(row).unwrap_or(EM_DASH).to_owned(), [x, y]));
+                }
+            }
+        }
+        let [x_axis, y_axis] = [
+            self.axis(self.settings.pca.x)?,
+            self.axis(self.settings.pca.y)?,
+        ];
+        let name = ui.localize("Loadings");
+        ui.vertical(|ui| {
+            ui.label(RichText::new(&name).strong())
+                .on_hover_localized("Loadings.hover");
+            let series = PlotPoints::new(points.iter().map(|(_, point)| *point).collect());
+            let texts = points.clone();
+            Plot::new(Id::new(ID_SOURCE).with("Loadings"))
+                .width(SIZE)
+                .height(SIZE)
+                .data_aspect(1.0)
+                .x_axis_label(x_axis)
+                .y_axis_label(y_axis)
+                .label_formatter(move |_, value| nearest(&points, value))
+                .show(ui, |ui| {
+                    for (label, [x, y]) in texts {
+                        ui.text(Text::new(
+                            label.clone(),
+                            PlotPoint::new(x, y),
+                            RichText::new(label).small(),
+                        ));
+                    }
+                    ui.points(Points::new(name, series).radius(2.0));
+                });
+        });
+        Ok(())
+    }
+}
+
+/// Nearest point name
+fn nearest(points: &[(String, [f64; 2])], value: &PlotPoint) -> String {
+    let name = points
+        .iter()
+        .min_by(|(_, a), (_, b)| {
+            let a = (a[0] - value.x).hypot(a[1] - value.y);
+            let b = (b[0] - value.x).hypot(b[1] - value.y);
+            a.total_cmp(&b)
+        })
+        .map_or(EM_DASH, |(name, _)| name.as_str());
+    format!("{name}\n{:.3}; {:.3}", value.x, value.y)
+}
@@ -0,0 +1,236 @@
+use crate::{
+    app::states::calculation::{
+        ID_SOURCE,
+        settings::{STEREOSPECIFIC_NUMBERS, Settings},
+    },
+    r#const::{ENRICHMENT, MEAN, SAMPLE, STANDARD_DEVIATION},
+    utils::ui::color,
+};
+use egui::{Id, RichText, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Bar, BarChart, HLine, Legend, Line, Plot, PlotPoints, PlotUi, Points};
+use lipid::prelude::*;
+use polars::prelude::*;
+use tracing::instrument;
+
+/// Bar width
+const WIDTH: f64 = 0.25;
+
+/// Calculation plot
+///
+/// Positional distribution (grouped bars of the stereospecific numbers) and
+/// enrichment factor of each fatty acid.
+pub(crate) struct PlotView<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+    names: &'a [String],
+}
+
+impl<'a> PlotView<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+            names: &[],
+        }
+    }
+
+    /// Sample names, one for each element of the sample arrays
+    pub(crate) fn with_names(self, names: &'a [String]) -> Self {
+        Self { names, ..self }
+    }
+}
+
+impl PlotView<'_> {
+    pub(crate) fn show(&self, ui: &mut Ui) {
+        _ = self.try_show(ui);
+    }
+
+    // | Label | StereospecificNumbers123 | StereospecificNumbers13 | StereospecificNumbers2 | Enrichment |
+    // | ---   | ---                      | ---                     | ---                    | ---        |
+    // | str   | struct[3]                | struct[3]               | struct[3]              | struct[3]  |
+    #[instrument(skip_all, err)]
+    fn try_show(&self, ui: &mut Ui) -> PolarsResult<()> {
+        let labels: Vec<_> = self.data_frame[LABEL]
+            .str()?
+            .iter()
+            .map(|label| label.unwrap_or_default().to_owned())
+            .collect();
+        let height = ui.available_height() / 2.0;
+        // Positional distribution
+        let mut columns = Vec::new();
+        for (index, stereospecific_numbers) in STEREOSPECIFIC_NUMBERS.into_iter().enumerate() {
+            columns.push((
+                stereospecific_numbers.to_string(),
+                ui.localize(stereospecific_numbers.text()),
+                (index as f64 - 1.0) * WIDTH,
+            ));
+        }
+        let mut charts = Vec::new();
+        let mut overlay = Vec::new();
+        if self.settings.overlay {
+            overlay = self.overlay(&columns)?;
+        } else {
+            for (column, name, offset) in columns {
+                charts.push((name, self.chart(&column, offset)?));
+            }
+        }
+        let percent = self.settings.percent;
+        let name = ui.localize("PositionalDistribution");
+        ui.label(RichText::new(&name).strong())
+            .on_hover_localized("PositionalDistribution.hover");
+        self.plot("PositionalDistribution", &labels)
+            .height(height)
+            .y_axis_formatter(move |mark, _| {
+                if percent {
+                    format!("{}%", mark.value)
+                } else {
+                    mark.value.to_string()
+                }
+            })
+            .show(ui, |ui| {
+                for (index, (name, chart)) in charts.into_iter().enumerate() {
+                    chart.show(ui, name, index);
+                }
+                self.show_overlay(ui, overlay);
+            });
+        // Enrichment factor
+        let name = ui.localize("EnrichmentFactor");
+        let mut chart = None;
+        let mut overlay = Vec::new();
+        if self.settings.overlay {
+            overlay = self.overlay(&[(ENRICHMENT.to_owned(), name.clone(), 0.0)])?;
+        } else {
+            chart = Some(self.chart(ENRICHMENT, 0.0)?);
+        }
+        ui.label(RichText::new(&name).strong())
+            .on_hover_localized("EnrichmentFactor.hover");
+        // Random distribution
+        let (neutral, text) = if self.settings.normalize_factors {
+            (1.0 / 3.0, "1/3")
+        } else {
+            (1.0, "1")
+        };
+        self.plot("EnrichmentFactor", &labels)
+            .height(height)
+            .show(ui, |ui| {
+                ui.hline(HLine::new(text, neutral));
+                if let Some(chart) = chart {
+                    chart.show(ui, name, 0);
+                }
+                self.show_overlay(ui, overlay);
+            });
+        Ok(())
+    }
+
+    fn plot(&self, id_salt: &str, labels: &[String]) -> Plot<'static> {
+        let labels = labels.to_vec();
+        Plot::new(Id::new(ID_SOURCE).with(id_salt))
+            .x_axis_formatter(move |mark, _| {
+                let index = mark.value.round();
+                if index == mark.value && index >= 0.0 {
+                    labels.get(index as usize).cloned().unwrap_or_default()
+                } else {
+                    String::new()
+                }
+            })
+            .legend(Legend::default())
+    }
+
+    /// Bars (mean), error bars (standard deviation) and samples of the column
+    fn chart(&self, name: &str, offset: f64) -> PolarsResult<Chart> {
+        let column = self.data_frame[name].struct_()?;
+        let means = column.field_by_name(MEAN)?;
+        let standard_deviations = column.field_by_name(STANDARD_DEVIATION)?;
+        let samples = column.field_by_name(SAMPLE)?;
+        let mut chart = Chart::default();
+        for (row, (mean, standard_deviation)) in means
+            .f64()?
+            .iter()
+            .zip(standard_deviations.f64()?)
+            .enumerate()
+        {
+            let Some(mean) = mean.filter(|mean| mean.is_finite()) else {
+                continue;
+            };
+            let x = row as f64 + offset;
+            chart.bars.push(Bar::new(x, mean).width(WIDTH));
+            if self.settings.standard_deviation
+                && let Some(standard_deviation) = standard_deviation
+            {
+                chart.errors.push([
+                    [x, mean - standard_deviation],
+                    [x, mean + standard_deviation],
+                ]);
+            }
+            if self.settings.samples
+                && let Some(sample) = samples.array()?.get_as_series(row)
+            {
+                for value in sample.f64()?.iter().flatten() {
+                    if value.is_finite() {
+                        chart.samples.push([x, value]);
+                    }
+                }
+            }
+        }
+        Ok(chart)
+    }
+
+    /// Bars of each sample of the columns (column name, bar name, offset)
+    fn overlay(&self, columns: &[(String, String, f64)]) -> PolarsResult<Vec<Vec<Bar>>> {
+        let count = self.names.len().max(1);
+        let width = WIDTH / count as f64;
+        let mut overlay = vec![Vec::new(); count];
+        for (column, name, offset) in columns {
+            let samples = self.data_frame[column.as_str()]
+                .struct_()?
+                .field_by_name(SAMPLE)?;
+            for row in 0..self.data_frame.height() {
+                let Some(sample) = samples.array()?.get_as_series(row) else {
+                    continue;
+                };
+                for (index, value) in sample.f64()?.iter().enumerate().take(count) {
+                    let Some(value) = value.filter(|value| value.is_finite()) else {
+                        continue;
+                    };
+                    let x = row as f64 + offset + (index as f64 - (count - 1) as f64 / 2.0) * width;
+                    overlay[index].push(Bar::new(x, value).width(width).name(name));
+                }
+            }
+        }
+        Ok(overlay)
+    }
+
+    /// Each sample with its own color and legend entry
+    fn show_overlay(&self, ui: &mut PlotUi, overlay: Vec<Vec<Bar>>) {
+        for (index, bars) in overlay.into_iter().enumerate() {
+            let name = self
+                .names
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| index.to_string());
+            ui.bar_chart(BarChart::new(name, bars).color(color(index)));
+        }
+    }
+}
+
+/// Bars, error bars and samples
+#[derive(Default)]
+struct Chart {
+    bars: Vec<Bar>,
+    errors: Vec<[[f64; 2]; 2]>,
+    samples: Vec<[f64; 2]>,
+}
+
+impl Chart {
+    fn show(self, ui: &mut PlotUi, name: String, index: usize) {
+        let color = color(index);
+        ui.bar_chart(BarChart::new(&name, self.bars).color(color));
+        for error in self.errors {
+            ui.line(Line::new(&name, PlotPoints::new(error.to_vec())).color(color));
+        }
+        if !self.samples.is_empty() {
+            ui.points(Points::new(&name, self.samples).color(color).radius(2.0));
+        }
+    }
+}
@@ -1,32 +1,33 @@
 #[cfg(feature = "markdown")]
 use crate::r#const::markdown::{
-    CETANE_NUMBER, COLD_FILTER_PLUGGING_POINT, DEGREE_OF_UNSATURATION,
+    CETANE_NUMBER, CLOUD_POINT, COLD_FILTER_PLUGGING_POINT, DEGREE_OF_UNSATURATION, DENSITY,
     EICOSAPENTAENOIC_AND_DOCOSAHEXAENOIC, FISH_LIPID_QUALITY, HEALTH_PROMOTING_INDEX,
-    HYPOCHOLESTEROLEMIC_TO_HYPERCHOLESTEROLEMIC, INDEX_OF_ATHEROGENICITY, INDEX_OF_THROMBOGENICITY,
-    IODINE_VALUE, LINOLEIC_TO_ALPHA_LINOLENIC, LONG_CHAIN_SATURATED_FACTOR, OXIDATION_STABILITY,
-    POLYUNSATURATED_6_TO_POLYUNSATURATED_3, POLYUNSATURATED_TO_SATURATED, TRANS,
-    UNSATURATION_INDEX,
+    HIGHER_HEATING_VALUE, HYPOCHOLESTEROLEMIC_TO_HYPERCHOLESTEROLEMIC, INDEX_OF_ATHEROGENICITY,
+    INDEX_OF_THROMBOGENICITY, IODINE_VALUE, KINEMATIC_VISCOSITY, LINOLEIC_TO_ALPHA_LINOLENIC,
+    LONG_CHAIN_SATURATED_FACTOR, OXIDATION_STABILITY, POLYUNSATURATED_6_TO_POLYUNSATURATED_3,
+    POLYUNSATURATED_TO_SATURATED, POUR_POINT, TRANS, UNSATURATION_INDEX,
 };
 use crate::{
     app::{
+        computers::calculation::sum::properties::biodiesel::{STANDARDS, limits},
         states::calculation::settings::Settings,
-        widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
+        widgets::{mean_and_standard_deviation::MeanAndStandardDeviation, reference::Reference},
     },
-    r#const::{EM_DASH, NAME},
+    r#const::{EM_DASH, MEAN, NAME},
 };
-#[cfg(feature = "markdown")]
-use egui::Popup;
-use egui::{Grid, InnerResponse, Response, Ui, Widget};
+use egui::{Grid, InnerResponse, Popup, Response, Ui, Widget};
 #[cfg(feature = "markdown")]
 use egui_ext::Markdown as _;
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::INFO;
+use lipid::prelude::STEREOSPECIFIC_NUMBERS1;
 use polars::prelude::*;
 
 /// Properties widget
 pub(crate) struct Properties<'a> {
     data_frame: &'a DataFrame,
     settings: &'a Settings,
+    standards: bool,
 }
 
 impl<'a> Properties<'a> {
@@ -34,9 +35,15 @@ impl<'a> Properties<'a> {
         Self {
             data_frame,
             settings,
+            standards: false,
         }
     }
 
+    /// Show biodiesel standard limits and highlight out of spec values
+    pub(crate) fn with_standards(self, standards: bool) -> Self {
+        Self { standards, ..self }
+    }
+
     pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
         Grid::new(ui.auto_id_with("Properties")).show(ui, |ui| -> PolarsResult<()> {
             ui.heading(ui.localize("Property?PluralCategory=one"));
@@ -46,34 +53,69 @@ impl<'a> Properties<'a> {
                 .on_hover_localized("StereospecificNumber?number=13");
             ui.heading(ui.localize("StereospecificNumber.abbreviation?number=2"))
                 .on_hover_localized("StereospecificNumber?number=2");
+            if self.data_frame.schema().contains(STEREOSPECIFIC_NUMBERS1) {
+                ui.heading(ui.localize("StereospecificNumber.abbreviation?number=1"))
+                    .on_hover_localized("StereospecificNumber?number=1");
+                ui.heading(ui.localize("StereospecificNumber.abbreviation?number=3"))
+                    .on_hover_localized("StereospecificNumber?number=3");
+            }
+            if self.standards {
+                for standard in STANDARDS {
+                    ui.heading(ui.localize(standard))
+                        .on_hover_localized(&format!("{standard}.hover"));
+                }
+            }
             ui.end_row();
             let columns = self.data_frame.get_columns();
             for row in 0..self.data_frame.height() {
+                let name = self.data_frame[NAME].str()?.get(row).unwrap_or(EM_DASH);
+                let limits = if self.standards {
+                    limits(name)
+                } else {
+                    [None, None]
+                };
                 for column in columns {
                     match column.name().as_str() {
                         NAME => {
                             ui.horizontal(|ui| -> PolarsResult<()> {
                                 ui.visuals_mut().button_frame = false;
-                                #[allow(unused_variables)]
                                 let response = ui.button(INFO);
-                                let name = self.data_frame[NAME].str()?.get(row).unwrap_or(EM_DASH);
                                 ui.label(ui.localize(name));
                                 #[cfg(feature = "markdown")]
                                 Popup::menu(&response).show(|ui| {
                                     ui.markdown(asset(name));
                                 });
+                                #[cfg(not(feature = "markdown"))]
+                                Popup::menu(&response).show(|ui| {
+                                    ui.add(Reference::new(name));
+                                });
                                 Ok(())
                             })
                             .inner?;
                         }
-                        name => {
-                            MeanAndStandardDeviation::new(&self.data_frame, [name], row)
+                        column_name => {
+                            let mean = column.struct_()?.field_by_name(MEAN)?.f64()?.get(row);
+                            let color = mean
+                                .is_some_and(|mean| {
+                                    limits.iter().flatten().any(|limit| !limit.contains(mean))
+                                })
+                                .then_some(ui.visuals().error_fg_color);
+                            MeanAndStandardDeviation::new(&self.data_frame, [column_name], row)
                                 .with_standard_deviation(self.settings.standard_deviation)
                                 .with_sample(true)
+                                .with_color(color)
                                 .show(ui)?;
                         }
                     }
                 }
+                if self.standards {
+                    for limit in limits {
+                        match limit {
+                            Some(limit) => ui.label(limit.to_string()),
+                            None => ui.label(EM_DASH),
+                        };
+                    }
+                }
                 ui.end_row();
             }
             Ok(())
@@ -168,6 +210,11 @@ fn asset(name: &str) -> &str {
         "IodineValue" => IODINE_VALUE,
         "LongChainSaturatedFactor" => LONG_CHAIN_SATURATED_FACTOR,
         "OxidationStability" => OXIDATION_STABILITY,
+        "CloudPoint" => CLOUD_POINT,
+        "Density" => DENSITY,
+        "HigherHeatingValue" => HIGHER_HEATING_VALUE,
+        "KinematicViscosity" => KINEMATIC_VISCOSITY,
+        "PourPoint" => POUR_POINT,
         _ => "",
     }
 }
@@ -0,0 +1,165 @@
+use crate::{
+    app::{
+        computers::calculation::series::Value,
+        states::calculation::{ID_SOURCE, settings::Settings},
+    },
+    r#const::EM_DASH,
+    utils::ui::color,
+};
+use egui::{Grid, Id, RichText, Stroke, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Legend, Line, Plot, PlotPoints, Points, Polygon};
+
+/// Plot size
+const SIZE: f32 = 320.0;
+
+/// Series widget
+///
+/// Mean (with the standard deviation band) of each fatty acid or index over
+/// the levels of the ordering variable, and its trend statistics.
+pub(crate) struct SeriesView<'a> {
+    value: &'a Value,
+    settings: &'a Settings,
+}
+
+impl<'a> SeriesView<'a> {
+    pub(crate) fn new(value: &'a Value, settings: &'a Settings) -> Self {
+        Self { value, settings }
+    }
+
+    pub(crate) fn show(self, ui: &mut Ui) {
+        if self.value.unordered > 0 {
+            ui.label(
+                RichText::new(format!(
+                    "{}: {}",
+                    ui.localize("Unordered"),
+                    self.value.unordered
+                ))
+                .color(ui.visuals().warn_fg_color),
+            )
+            .on_hover_localized("Unordered.hover");
+        }
+        if self.value.levels.len() < 2 {
+            ui.label(ui.localize("NoSeries"))
+                .on_hover_localized("NoSeries.hover");
+            return;
+        }
+        let names: Vec<_> = self
+            .value
+            .trends
+            .iter()
+            .map(|trend| {
+                if self.settings.series.indices {
+                    ui.localize(&trend.name)
+                } else {
+                    trend.name.clone()
+                }
+            })
+            .collect();
+        ui.horizontal_top(|ui| {
+            self.plot(ui, &names);
+            ui.separator();
+            self.statistics(ui, &names);
+        });
+    }
+
+    fn plot(&self, ui: &mut Ui, names: &[String]) {
+        let scale = self.scale();
+        let levels = &self.value.levels;
+        Plot::new(Id::new(ID_SOURCE).with("Series"))
+            .width(SIZE * 2.0)
+            .height(SIZE)
+            .x_axis_label(
+                self.settings
+                    .series
+                    .variable
+                    .clone()
+                    .unwrap_or_else(|| ui.localize("Treatment")),
+            )
+            .legend(Legend::default())
+            .show(ui, |ui| {
+                for (index, (trend, name)) in self.value.trends.iter().zip(names).enumerate() {
+                    let color = color(index);
+                    let mut means = Vec::new();
+                    let mut upper = Vec::new();
+                    let mut lower = Vec::new();
+                    for ((level, mean), standard_deviation) in levels
+                        .iter()
+                        .zip(&trend.means)
+                        .zip(&trend.standard_deviations)
+                    {
+                        let Some(mean) = mean else {
+                            continue;
+                        };
+                        means.push([*level, mean * scale]);
+                        if let Some(standard_deviation) = standard_deviation {
+                            upper.push([*level, (mean + standard_deviation) * scale]);
+                            lower.push([*level, (mean - standard_deviation) * scale]);
+                        }
+                    }
+                    // Standard deviation band
+                    if self.settings.standard_deviation && upper.len() > 1 {
+                        upper.extend(lower.into_iter().rev());
+                        ui.polygon(
+                            Polygon::new(name, PlotPoints::new(upper))
+                                .stroke(Stroke::NONE)
+                                .fill_color(color.gamma_multiply(0.15)),
+                        );
+                    }
+                    ui.points(Points::new(name, means.clone()).color(color).radius(3.0));
+                    ui.line(Line::new(name, PlotPoints::new(means)).color(color));
+                }
+            });
+    }
+
+    fn statistics(&self, ui: &mut Ui, names: &[String]) {
+        let precision = self.settings.precision;
+        let scale = self.scale();
+        let alpha = self.settings.alpha;
+        Grid::new(Id::new(ID_SOURCE).with("SeriesStatistics"))
+            .striped(true)
+            .show(ui, |ui| {
+                if self.settings.series.indices {
+                    ui.heading(ui.localize("Index"));
+                } else {
+                    ui.heading(ui.localize("FattyAcid"));
+                }
+                ui.heading(ui.localize("Slope"))
+                    .on_hover_localized("Slope.hover");
+                ui.heading("τ").on_hover_localized("MannKendall.hover");
+                ui.heading("p").on_hover_localized("MannKendall.hover");
+                ui.end_row();
+                for (trend, name) in self.value.trends.iter().zip(names) {
+                    ui.label(name);
+                    ui.label(trend.slope.map_or_else(
+                        || EM_DASH.to_owned(),
+                        |slope| format!("{:.precision$}", slope * scale),
+                    ));
+                    match trend.mann_kendall {
+                        Some((tau, p_value)) => {
+                            ui.label(format!("{tau:.2}"));
+                            let mut text = RichText::new(format!("{p_value:.3}"));
+                            if p_value < alpha {
+                                text = text.strong();
+                            }
+                            ui.label(text);
+                        }
+                        None => {
+                            ui.label(EM_DASH);
+                            ui.label(EM_DASH);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Fatty acids are fractions, indices are not
+    fn scale(&self) -> f64 {
+        if self.settings.percent && !self.settings.series.indices {
+            100.0
+        } else {
+            1.0
+        }
+    }
+}
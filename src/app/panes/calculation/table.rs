@@ -116,6 +116,14 @@ impl TableView<'_> {
                 ui.heading(ui.localize("StereospecificNumber.abbreviation?number=13"))
                     .on_hover_localized("StereospecificNumber?number=13");
             }
+            (1, bottom::STEREOSPECIFIC_NUMBERS1) => {
+                ui.heading(ui.localize("StereospecificNumber.abbreviation?number=1"))
+                    .on_hover_localized("StereospecificNumber?number=1");
+            }
+            (1, bottom::STEREOSPECIFIC_NUMBERS3) => {
+                ui.heading(ui.localize("StereospecificNumber.abbreviation?number=3"))
+                    .on_hover_localized("StereospecificNumber?number=3");
+            }
             (1, bottom::ENRICHMENT_FACTOR) => {
                 #[allow(unused_variables)]
                 let response = ui.heading(ui.localize("EnrichmentFactor.abbreviation"));
@@ -263,6 +271,18 @@ impl TableView<'_> {
                     .with_calculation(true)
                     .show(ui)?;
             }
+            (row, bottom::STEREOSPECIFIC_NUMBERS1) => {
+                MeanAndStandardDeviation::new(&data_frame, [STEREOSPECIFIC_NUMBERS1], row)
+                    .with_standard_deviation(self.state.settings.standard_deviation)
+                    .with_sample(true)
+                    .show(ui)?;
+            }
+            (row, bottom::STEREOSPECIFIC_NUMBERS3) => {
+                MeanAndStandardDeviation::new(&data_frame, [STEREOSPECIFIC_NUMBERS3], row)
+                    .with_standard_deviation(self.state.settings.standard_deviation)
+                    .with_sample(true)
+                    .show(ui)?;
+            }
             (row, bottom::ENRICHMENT_FACTOR) => {
                 MeanAndStandardDeviation::new(&data_frame, [FACTORS, ENRICHMENT], row)
                     .with_standard_deviation(self.state.settings.standard_deviation)
@@ -309,6 +329,18 @@ impl TableView<'_> {
                     .with_calculation(true)
                     .show(ui)?;
             }
+            (row, bottom::STEREOSPECIFIC_NUMBERS1) => {
+                MeanAndStandardDeviation::new(&data_frame, [STEREOSPECIFIC_NUMBERS1], row)
+                    .with_standard_deviation(self.state.settings.standard_deviation)
+                    .with_sample(true)
+                    .show(ui)?;
+            }
+            (row, bottom::STEREOSPECIFIC_NUMBERS3) => {
+                MeanAndStandardDeviation::new(&data_frame, [STEREOSPECIFIC_NUMBERS3], row)
+                    .with_standard_deviation(self.state.settings.standard_deviation)
+                    .with_sample(true)
+                    .show(ui)?;
+            }
             _ => {}
         }
         Ok(())
@@ -418,7 +450,7 @@ mod top {
     use super::*;
 
     pub(super) const IDENTIFIER: Range<usize> = 0..3;
-    pub(super) const STEREOSPECIFIC_NUMBERS: Range<usize> = IDENTIFIER.end..IDENTIFIER.end + 3;
+    pub(super) const STEREOSPECIFIC_NUMBERS: Range<usize> = IDENTIFIER.end..IDENTIFIER.end + 5;
     pub(super) const FACTORS: Range<usize> =
         STEREOSPECIFIC_NUMBERS.end..STEREOSPECIFIC_NUMBERS.end + 2;
 }
@@ -435,6 +467,10 @@ mod bottom {
         STEREOSPECIFIC_NUMBERS123.end..STEREOSPECIFIC_NUMBERS123.end + 1;
     pub(super) const STEREOSPECIFIC_NUMBERS13: Range<usize> =
         STEREOSPECIFIC_NUMBERS2.end..STEREOSPECIFIC_NUMBERS2.end + 1;
+    pub(super) const STEREOSPECIFIC_NUMBERS1: Range<usize> =
+        STEREOSPECIFIC_NUMBERS13.end..STEREOSPECIFIC_NUMBERS13.end + 1;
+    pub(super) const STEREOSPECIFIC_NUMBERS3: Range<usize> =
+        STEREOSPECIFIC_NUMBERS1.end..STEREOSPECIFIC_NUMBERS1.end + 1;
     pub(super) const ENRICHMENT_FACTOR: Range<usize> = top::FACTORS.start..top::FACTORS.start + 1;
     pub(super) const SELECTIVITY_FACTOR: Range<usize> =
         ENRICHMENT_FACTOR.end..ENRICHMENT_FACTOR.end + 1;
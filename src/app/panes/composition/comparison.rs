@@ -0,0 +1,145 @@
+use crate::{
+    app::states::composition::{ID_SOURCE, settings::Settings},
+    r#const::{EM_DASH, KEY, MEASURED, PREDICTED, RESIDUAL},
+};
+use egui::{Grid, Id, InnerResponse, RichText, ScrollArea, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Line, Plot, PlotPoints, Points};
+use polars::prelude::*;
+
+/// Comparison widget
+pub(crate) struct Comparison<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> Comparison<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    // | Key | Predicted | Measured | Residual |
+    // | --- | ---       | ---      | ---      |
+    // | str | f64       | f64      | f64      |
+    pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
+        ui.vertical(|ui| -> PolarsResult<()> {
+            let keys = self.data_frame[KEY].str()?;
+            let predicted = self.data_frame[PREDICTED].f64()?;
+            let measured = self.data_frame[MEASURED].f64()?;
+            let residuals = self.data_frame[RESIDUAL].f64()?;
+            // Root mean square error
+            ui.horizontal(|ui| {
+                ui.label(ui.localize("RootMeanSquareError"))
+                    .on_hover_localized("RootMeanSquareError.hover");
+                match root_mean_square_error(residuals) {
+                    Some(value) => ui.label(self.format(value)),
+                    None => ui.label(EM_DASH),
+                }
+            });
+            ui.separator();
+            ui.horizontal_top(|ui| {
+                ScrollArea::vertical()
+                    .id_salt("Table")
+                    .auto_shrink([true, false])
+                    .show(ui, |ui| {
+                        Grid::new(ui.auto_id_with("Comparison"))
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.heading(ui.localize("Key"));
+                                ui.heading(ui.localize("Predicted"))
+                                    .on_hover_localized("Predicted.hover");
+                                ui.heading(ui.localize("Measured"))
+                                    .on_hover_localized("Measured.hover");
+                                ui.heading(ui.localize("Residual"))
+                                    .on_hover_localized("Residual.hover");
+                                ui.end_row();
+                                for row in 0..self.data_frame.height() {
+                                    ui.label(keys.get(row).unwrap_or(EM_DASH));
+                                    for values in [predicted, measured, residuals] {
+                                        match values.get(row) {
+                                            Some(value) => ui.label(self.format(value)),
+                                            None => ui.label(EM_DASH),
+                                        };
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                ui.separator();
+                self.parity(ui, keys, predicted, measured);
+            });
+            Ok(())
+        })
+    }
+
+    fn format(&self, value: f64) -> String {
+        let precision = self.settings.precision;
+        if self.settings.percent {
+            format!("{value:.precision$}%")
+        } else {
+            format!("{value:.precision$}")
+        }
+    }
+
+    /// Parity plot
+    fn parity(
+        &self,
+        ui: &mut Ui,
+        keys: &StringChunked,
+        predicted: &Float64Chunked,
+        measured: &Float64Chunked,
+    ) {
+        let points: Vec<_> = keys
+            .iter()
+            .zip(predicted)
+            .zip(measured)
+            .filter_map(|((key, x), y)| Some((key.unwrap_or(EM_DASH).to_owned(), [x?, y?])))
+            .collect();
+        let max = points
+            .iter()
+            .fold(0f64, |max, (_, [x, y])| max.max(*x).max(*y));
+        let name = ui.localize("Parity");
+        ui.vertical(|ui| {
+            ui.label(RichText::new(&name).strong())
+                .on_hover_localized("Parity.hover");
+            let series = PlotPoints::new(points.iter().map(|(_, point)| *point).collect());
+            Plot::new(Id::new(ID_SOURCE).with("Parity"))
+                .data_aspect(1.0)
+                .x_axis_label(ui.localize("Predicted"))
+                .y_axis_label(ui.localize("Measured"))
+                .label_formatter(move |_, value| {
+                    // Nearest point key
+                    let key = points
+                        .iter()
+                        .min_by(|(_, a), (_, b)| {
+                            let a = (a[0] - value.x).hypot(a[1] - value.y);
+                            let b = (b[0] - value.x).hypot(b[1] - value.y);
+                            a.total_cmp(&b)
+                        })
+                        .map_or(EM_DASH, |(key, _)| key.as_str());
+                    format!("{key}\n{:.2}; {:.2}", value.x, value.y)
+                })
+                .show(ui, |ui| {
+                    ui.line(Line::new(
+                        "y = x",
+                        PlotPoints::new(vec![[0.0, 0.0], [max, max]]),
+                    ));
+                    ui.points(Points::new(name, series).radius(3.0));
+                });
+        });
+    }
+}
+
+/// Root mean square error
+fn root_mean_square_error(residuals: &Float64Chunked) -> Option<f64> {
+    let (sum, count) = residuals
+        .iter()
+        .flatten()
+        .fold((0.0, 0), |(sum, count), residual| {
+            (sum + residual * residual, count + 1)
+        });
+    (count > 0).then(|| (sum / count as f64).sqrt())
+}
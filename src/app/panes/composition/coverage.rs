@@ -0,0 +1,177 @@
+use crate::{
+    app::{
+        computers::composition::coverage::count,
+        states::composition::{
+            ID_SOURCE,
+            settings::{Coverage as CoverageSettings, Settings},
+        },
+    },
+    r#const::{CUMULATIVE, EM_DASH, KEY, VALUE},
+};
+use egui::{Grid, Id, InnerResponse, RichText, ScrollArea, Ui};
+use egui_l20n::prelude::*;
+use egui_plot::{Bar, BarChart, HLine, Line, Plot, PlotPoints};
+use polars::prelude::*;
+
+/// Bar width
+const WIDTH: f64 = 0.8;
+
+/// Coverage widget
+pub(crate) struct Coverage<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> Coverage<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    // | Key | Value | Cumulative |
+    // | --- | ---   | ---        |
+    // | str | f64   | f64        |
+    pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
+        ui.vertical(|ui| -> PolarsResult<()> {
+            let keys = self.data_frame[KEY].str()?;
+            let values = self.data_frame[VALUE].f64()?;
+            let cumulative = self.data_frame[CUMULATIVE].f64()?;
+            // Counts
+            Grid::new(ui.auto_id_with("Levels"))
+                .show(ui, |ui| -> PolarsResult<()> {
+                    ui.heading(ui.localize("Level"))
+                        .on_hover_localized("Level.hover");
+                    for level in CoverageSettings::LEVELS {
+                        let text = RichText::new(format!("{level}%"));
+                        if level == self.settings.coverage.level {
+                            ui.label(text.heading().strong());
+                        } else {
+                            ui.label(text.heading());
+                        }
+                    }
+                    ui.label(RichText::new("100%").heading());
+                    ui.end_row();
+                    ui.label(ui.localize("Count"))
+                        .on_hover_localized("Count.hover");
+                    for level in CoverageSettings::LEVELS {
+                        let count = count(self.data_frame, level as f64 / 100.0)?;
+                        ui.label(count.to_string());
+                    }
+                    ui.label(self.data_frame.height().to_string());
+                    ui.end_row();
+                    Ok(())
+                })
+                .inner?;
+            ui.separator();
+            let level = self.settings.coverage.level as f64 / 100.0;
+            let count = count(self.data_frame, level)?;
+            ui.horizontal_top(|ui| {
+                ScrollArea::vertical()
+                    .id_salt("Table")
+                    .auto_shrink([true, false])
+                    .show(ui, |ui| {
+                        Grid::new(ui.auto_id_with("Coverage"))
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.heading("#");
+                                ui.heading(ui.localize("Key"));
+                                ui.heading(ui.localize("Value"))
+                                    .on_hover_localized("Value.hover");
+                                ui.heading(ui.localize("Cumulative"))
+                                    .on_hover_localized("Cumulative.hover");
+                                ui.end_row();
+                                for row in 0..count {
+                                    ui.label((row + 1).to_string());
+                                    ui.label(keys.get(row).unwrap_or(EM_DASH));
+                                    for values in [values, cumulative] {
+                                        match values.get(row) {
+                                            Some(value) => ui.label(self.format(value)),
+                                            None => ui.label(EM_DASH),
+                                        };
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+                ui.separator();
+                self.pareto(ui, keys, values, cumulative, level, count);
+            });
+            Ok(())
+        })
+    }
+
+    fn format(&self, value: f64) -> String {
+        let precision = self.settings.precision;
+        if self.settings.percent {
+            format!("{value:.precision$}%")
+        } else {
+            format!("{value:.precision$}")
+        }
+    }
+
+    /// Pareto plot
+    fn pareto(
+        &self,
+        ui: &mut Ui,
+        keys: &StringChunked,
+        values: &Float64Chunked,
+        cumulative: &Float64Chunked,
+        level: f64,
+        count: usize,
+    ) {
+        let total = if self.settings.percent { 100.0 } else { 1.0 };
+        let mut covered = Vec::new();
+        let mut rest = Vec::new();
+        for (index, (key, value)) in keys.iter().zip(values).enumerate() {
+            let Some(value) = value else {
+                continue;
+            };
+            let bar = Bar::new(index as f64 + 1.0, value)
+                .name(key.unwrap_or(EM_DASH))
+                .width(WIDTH);
+            if index < count {
+                covered.push(bar);
+            } else {
+                rest.push(bar);
+            }
+        }
+        let cumulative = PlotPoints::new(
+            cumulative
+                .iter()
+                .enumerate()
+                .filter_map(|(index, value)| Some([index as f64 + 1.0, value?]))
+                .collect(),
+        );
+        let name = ui.localize("Pareto");
+        let names = [
+            ui.localize("Covered"),
+            ui.localize("Rest"),
+            ui.localize("Cumulative"),
+        ];
+        ui.vertical(|ui| {
+            ui.label(RichText::new(&name).strong())
+                .on_hover_localized("Pareto.hover");
+            let percent = self.settings.percent;
+            Plot::new(Id::new(ID_SOURCE).with("Pareto"))
+                .x_axis_label(ui.localize("Rank"))
+                .y_axis_label(ui.localize("Value"))
+                .y_axis_formatter(move |mark, _| {
+                    if percent {
+                        format!("{}%", mark.value)
+                    } else {
+                        mark.value.to_string()
+                    }
+                })
+                .legend(Default::default())
+                .show(ui, |ui| {
+                    let [covered_name, rest_name, cumulative_name] = names;
+                    ui.bar_chart(BarChart::new(covered_name, covered));
+                    ui.bar_chart(BarChart::new(rest_name, rest));
+                    ui.line(Line::new(cumulative_name, cumulative));
+                    ui.hline(HLine::new(format!("{}%", level * 100.0), level * total));
+                });
+        });
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    app::states::composition::settings::Settings,
+    r#const::{DIFFERENCE, EM_DASH, KEY, LEFT, LOG_RATIO, ONLY, RELATIVE_DIFFERENCE, RIGHT},
+};
+use egui::{Grid, ScrollArea, TextStyle, Ui};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+
+/// Diff widget
+pub(crate) struct Diff<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> Diff<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    // | Key | Left | Right | Difference | RelativeDifference | LogRatio | Only |
+    // | --- | ---  | ---   | ---        | ---                | ---      | ---  |
+    // | str | f64  | f64   | f64        | f64                | f64      | str  |
+    pub(crate) fn show(self, ui: &mut Ui) -> PolarsResult<()> {
+        let keys = self.data_frame[KEY].str()?;
+        let lefts = self.data_frame[LEFT].f64()?;
+        let rights = self.data_frame[RIGHT].f64()?;
+        let differences = self.data_frame[DIFFERENCE].f64()?;
+        let relative_differences = self.data_frame[RELATIVE_DIFFERENCE].f64()?;
+        let log_ratios = self.data_frame[LOG_RATIO].f64()?;
+        let onlys = self.data_frame[ONLY].str()?;
+        let row_height = ui.text_style_height(&TextStyle::Body) + ui.spacing().item_spacing.y;
+        ScrollArea::both().show_rows(ui, row_height, self.data_frame.height() + 1, |ui, rows| {
+            Grid::new(ui.auto_id_with("Diff"))
+                .striped(true)
+                .show(ui, |ui| {
+                    for row in rows {
+                        // Header
+                        let Some(row) = row.checked_sub(1) else {
+                            ui.strong(ui.localize("Key"));
+                            for name in [LEFT, RIGHT, DIFFERENCE, RELATIVE_DIFFERENCE, LOG_RATIO] {
+                                ui.strong(ui.localize(name))
+                                    .on_hover_localized(&format!("{name}.hover"));
+                            }
+                            ui.strong(ui.localize("Only"))
+                                .on_hover_localized("Only.hover");
+                            ui.end_row();
+                            continue;
+                        };
+                        ui.label(keys.get(row).unwrap_or(EM_DASH));
+                        for values in [lefts, rights, differences] {
+                            match values.get(row) {
+                                Some(value) => ui.label(self.format(value)),
+                                None => ui.label(EM_DASH),
+                            };
+                        }
+                        match relative_differences.get(row) {
+                            Some(value) => ui.label(format!("{value:+.1}%")),
+                            None => ui.label(EM_DASH),
+                        };
+                        match log_ratios.get(row) {
+                            Some(value) => ui.label(format!("{value:+.2}")),
+                            None => ui.label(EM_DASH),
+                        };
+                        match onlys.get(row) {
+                            Some(only) => ui.label(ui.localize(only)),
+                            None => ui.label(""),
+                        };
+                        ui.end_row();
+                    }
+                });
+        });
+        Ok(())
+    }
+
+    fn format(&self, value: f64) -> String {
+        let precision = self.settings.precision;
+        if self.settings.percent {
+            format!("{value:.precision$}%")
+        } else {
+            format!("{value:.precision$}")
+        }
+    }
+}
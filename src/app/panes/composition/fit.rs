@@ -0,0 +1,71 @@
+use crate::{
+    app::states::composition::settings::Settings,
+    r#const::{
+        COEFFICIENT_OF_DETERMINATION, EM_DASH, NAME, ROOT_MEAN_SQUARE_ERROR, SELECTIVITY,
+        STANDARD_ERROR,
+    },
+};
+use egui::{Grid, InnerResponse, Ui};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+
+/// Fit widget
+pub(crate) struct Fit<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> Fit<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    // | Name | Selectivity | StandardError | RootMeanSquareError | CoefficientOfDetermination |
+    // | ---  | ---         | ---           | ---                 | ---                        |
+    // | str  | f64         | f64           | f64                 | f64                        |
+    pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
+        Grid::new(ui.auto_id_with("Fit")).show(ui, |ui| -> PolarsResult<()> {
+            let names = self.data_frame[NAME].str()?;
+            let selectivities = self.data_frame[SELECTIVITY].f64()?;
+            let standard_errors = self.data_frame[STANDARD_ERROR].f64()?;
+            let root_mean_square_errors = self.data_frame[ROOT_MEAN_SQUARE_ERROR].f64()?;
+            let coefficients_of_determination =
+                self.data_frame[COEFFICIENT_OF_DETERMINATION].f64()?;
+            let precision = self.settings.precision;
+            ui.heading(ui.localize("Sample"));
+            ui.heading(ui.localize("Selectivity"))
+                .on_hover_localized("Selectivity.hover");
+            ui.heading(ui.localize("RootMeanSquareError"))
+                .on_hover_localized("RootMeanSquareError.hover");
+            ui.heading(ui.localize("CoefficientOfDetermination"))
+                .on_hover_localized("CoefficientOfDetermination.hover");
+            ui.end_row();
+            for row in 0..self.data_frame.height() {
+                ui.label(names.get(row).unwrap_or(EM_DASH));
+                match (selectivities.get(row), standard_errors.get(row)) {
+                    (Some(selectivity), Some(standard_error)) => {
+                        ui.label(format!("{selectivity:.3} ± {standard_error:.3}"))
+                    }
+                    (Some(selectivity), None) => ui.label(format!("{selectivity:.3}")),
+                    _ => ui.label(EM_DASH),
+                };
+                match root_mean_square_errors.get(row) {
+                    Some(value) if self.settings.percent => {
+                        ui.label(format!("{value:.precision$}%"))
+                    }
+                    Some(value) => ui.label(format!("{value:.precision$}")),
+                    None => ui.label(EM_DASH),
+                };
+                match coefficients_of_determination.get(row) {
+                    Some(value) if value.is_finite() => ui.label(format!("{value:.3}")),
+                    _ => ui.label(EM_DASH),
+                };
+                ui.end_row();
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    app::{
+        computers::composition::mass_spectrometry::{
+            AMMONIATED, FORMULA, FRAGMENTS, ISOTOPES, PROTONATED, SODIATED,
+        },
+        states::composition::settings::Settings,
+    },
+    r#const::{EM_DASH, SPECIES, VALUE},
+};
+use egui::{Grid, ScrollArea, TextStyle, Ui};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+
+/// Mass to charge ratio precision
+const PRECISION: usize = 4;
+
+/// Mass spectrometry widget
+pub(crate) struct MassSpectrometry<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> MassSpectrometry<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    // | Species | Formula | Value | [M+H]+ | [M+NH4]+ | [M+Na]+ | [M+NH4-R1COOH-NH3]+ | ... | M+1 | ... |
+    // | ---     | ---     | ---   | ---    | ---      | ---     | ---                 | ... | --- | ... |
+    // | str     | str     | f64   | f64    | f64      | f64     | f64                 | ... | f64 | ... |
+    pub(crate) fn show(self, ui: &mut Ui) -> PolarsResult<()> {
+        let species = self.data_frame[SPECIES].str()?;
+        let formulas = self.data_frame[FORMULA].str()?;
+        let values = self.data_frame[VALUE].f64()?;
+        let mut ions = Vec::new();
+        for name in [PROTONATED, AMMONIATED, SODIATED]
+            .into_iter()
+            .chain(FRAGMENTS)
+        {
+            ions.push(self.data_frame[name].f64()?);
+        }
+        let mut isotopes = Vec::new();
+        for name in ISOTOPES {
+            isotopes.push(self.data_frame[name].f64()?);
+        }
+        let precision = self.settings.precision;
+        let row_height = ui.text_style_height(&TextStyle::Body) + ui.spacing().item_spacing.y;
+        ScrollArea::both().show_rows(ui, row_height, self.data_frame.height() + 1, |ui, rows| {
+            Grid::new(ui.auto_id_with("MassSpectrometry"))
+                .striped(true)
+                .show(ui, |ui| {
+                    for row in rows {
+                        // Header
+                        let Some(row) = row.checked_sub(1) else {
+                            ui.strong(ui.localize("Species"));
+                            ui.strong(ui.localize("Formula"));
+                            ui.strong(ui.localize("Value"))
+                                .on_hover_localized("Value.hover");
+                            for name in [PROTONATED, AMMONIATED, SODIATED] {
+                                ui.strong(name);
+                            }
+                            for name in FRAGMENTS {
+                                ui.strong(name).on_hover_localized("Fragment.hover");
+                            }
+                            for name in ISOTOPES {
+                                ui.strong(name).on_hover_localized("Isotope.hover");
+                            }
+                            ui.end_row();
+                            continue;
+                        };
+                        ui.label(species.get(row).unwrap_or(EM_DASH));
+                        ui.label(formulas.get(row).unwrap_or(EM_DASH));
+                        match values.get(row) {
+                            Some(value) if self.settings.percent => {
+                                ui.label(format!("{value:.precision$}%"))
+                            }
+                            Some(value) => ui.label(format!("{value:.precision$}")),
+                            None => ui.label(EM_DASH),
+                        };
+                        for ion in &ions {
+                            match ion.get(row) {
+                                Some(value) => ui.label(format!("{value:.PRECISION$}")),
+                                None => ui.label(EM_DASH),
+                            };
+                        }
+                        for isotope in &isotopes {
+                            match isotope.get(row) {
+                                Some(value) => ui.label(format!("{value:.1}%")),
+                                None => ui.label(EM_DASH),
+                            };
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+        Ok(())
+    }
+}
@@ -1,35 +1,59 @@
-use self::{plot::PlotView, sum::symmetry::Symmetry, table::TableView};
+use self::{
+    comparison::Comparison,
+    coverage::Coverage,
+    diff::Diff,
+    fit::Fit,
+    mass_spectrometry::MassSpectrometry,
+    plot::PlotView,
+    sum::{quality::Quality, symmetry::Symmetry},
+    table::TableView,
+};
 use super::{Behavior, MARGIN};
 use crate::{
     app::{
         computers::composition::{
             Computed as CompositionComputed, Key as CompositionKey,
+            comparison::{Computed as ComparisonComputed, Key as ComparisonKey},
+            coverage::{Computed as CoverageComputed, Key as CoverageKey, count},
+            diff::{Computed as DiffComputed, Key as DiffKey},
+            fit::{Computed as FitComputed, Key as FitKey},
+            mass_spectrometry::{Computed as MassSpectrometryComputed, Key as MassSpectrometryKey},
+            plot::{Computed as PlotComputed, Key as PlotKey},
             species::{Computed as SpeciesComputed, Key as SpeciesKey},
-            sum::symmetry::{Computed as SymmetryComputed, Key as SymmetryKey},
+            sum::{
+                quality::{Computed as QualityComputed, Key as QualityKey},
+                symmetry::{Computed as SymmetryComputed, Key as SymmetryKey},
+            },
             table::{Computed as TableComputed, Key as TableKey},
             unique::{Computed as UniqueComputed, Key as UniqueKey},
         },
         states::composition::{
             ID_SOURCE, State,
-            settings::{Settings, View},
+            settings::{
+                COMPOSITIONS, Class, Composition, Coverage as CoverageSettings, Filter, Ion,
+                Method, Operand, Settings, View,
+            },
         },
         widgets::butons::{ResetButton, ResizeButton, SettingsButton},
     },
-    r#const::VALUE,
+    r#const::{FILTERS, KEYS, VALUE},
     export::{ron, xlsx},
     text::Text,
     utils::{
         HashedDataFrame, HashedMetaDataFrame,
-        metadata::{authors, date, description, name},
+        metadata::{authors, date, description, field, name},
     },
 };
 use anyhow::Result;
 use egui::{
-    CentralPanel, CursorIcon, Frame, Id, MenuBar, Response, RichText, ScrollArea, TextStyle,
-    TopBottomPanel, Ui, Widget as _, Window, util::hash,
+    CentralPanel, ComboBox, CursorIcon, Frame, Grid, Id, MenuBar, Response, RichText, ScrollArea,
+    TextStyle, TopBottomPanel, Ui, Widget as _, Window, util::hash,
 };
 use egui_l20n::prelude::*;
-use egui_phosphor::regular::{FLOPPY_DISK, INTERSECT_THREE, LIST, SIGMA, SLIDERS_HORIZONTAL, X};
+use egui_phosphor::regular::{
+    ATOM, FLOPPY_DISK, FUNCTION, GIT_DIFF, INTERSECT_THREE, LIST, PERCENT, SCALES, SIGMA,
+    SLIDERS_HORIZONTAL, X,
+};
 use egui_tiles::{TileId, UiResponse};
 use lipid::prelude::*;
 use metadata::{
@@ -38,20 +62,28 @@ use metadata::{
 use polars::prelude::*;
 use polars_utils::format_list_truncated;
 use serde::{Deserialize, Serialize};
-use std::fmt::{Debug, Display, from_fn};
-use tracing::instrument;
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display, from_fn},
+};
+use tracing::{instrument, warn};
 
 /// Composition pane
 #[derive(Default, Deserialize, Serialize)]
 pub(crate) struct Pane {
     id: Option<Id>,
     frames: Vec<HashedMetaDataFrame>,
+    #[serde(default)]
+    measured: Option<HashedMetaDataFrame>,
     // Слишком большой размер вызывает задержку при десериализации, при открытие
     // программы.
     #[serde(skip)]
     species: HashedDataFrame,
     #[serde(skip)]
     composition: HashedDataFrame,
+    /// Filters of the measured composition, restored on the next frame
+    #[serde(skip)]
+    filters: Option<BTreeMap<Composition, Filter>>,
 }
 
 impl Pane {
@@ -59,11 +91,24 @@ impl Pane {
         Self {
             id: None,
             frames,
+            measured: None,
             species: HashedDataFrame::EMPTY,
             composition: HashedDataFrame::EMPTY,
+            filters: None,
         }
     }
 
+    /// Attaches measured triacylglycerol composition, with its saved filters
+    pub(crate) fn attach(&mut self, frame: HashedMetaDataFrame) {
+        if let Some(filters) = field(&frame.meta, FILTERS) {
+            match ::ron::de::from_str(filters) {
+                Ok(filters) => self.filters = Some(filters),
+                Err(error) => warn!(%error, "invalid filters metadata"),
+            }
+        }
+        self.measured = Some(frame);
+    }
+
     pub(crate) fn title(&self, index: Option<usize>) -> String {
         self.title_with_separator(index, " ")
     }
@@ -140,6 +185,10 @@ impl Pane {
     }
 
     fn init(&mut self, ui: &mut Ui, state: &mut State) {
+        // Filters
+        if let Some(filters) = self.filters.take() {
+            state.settings.filters.extend(filters);
+        }
         // Species
         self.species = ui.memory_mut(|memory| {
             memory
@@ -169,6 +218,23 @@ impl Pane {
         // Sum
         self.sum_button(ui, state);
         ui.separator();
+        // Measured
+        self.measured_button(ui, state);
+        ui.separator();
+        // Diff
+        ui.toggle_value(
+            &mut state.windows.open_diff,
+            RichText::new(GIT_DIFF).heading(),
+        )
+        .on_hover_localized("Diff.hover");
+        ui.separator();
+        // Mass spectrometry
+        ui.toggle_value(
+            &mut state.windows.open_mass_spectrometry,
+            RichText::new(ATOM).heading(),
+        )
+        .on_hover_localized("MassSpectrometry.hover");
+        ui.separator();
         // Save
         self.save_button(ui, state);
         ui.separator();
@@ -229,6 +295,48 @@ impl Pane {
                 ),
             )
             .on_hover_localized("Symmetry.hover");
+            ui.toggle_value(
+                &mut state.windows.open_quality,
+                (
+                    RichText::new(SIGMA).heading(),
+                    RichText::new(ui.localize("Quality")).heading(),
+                ),
+            )
+            .on_hover_localized("Quality.hover");
+            ui.toggle_value(
+                &mut state.windows.open_coverage,
+                (
+                    RichText::new(PERCENT).heading(),
+                    RichText::new(ui.localize("Coverage")).heading(),
+                ),
+            )
+            .on_hover_localized("Coverage.hover");
+        });
+    }
+
+    /// Measured button
+    fn measured_button(&self, ui: &mut Ui, state: &mut State) {
+        ui.add_enabled_ui(self.measured.is_some(), |ui| {
+            ui.menu_button(RichText::new(SCALES).heading(), |ui| {
+                ui.toggle_value(
+                    &mut state.windows.open_comparison,
+                    (
+                        RichText::new(SCALES).heading(),
+                        RichText::new(ui.localize("Comparison")).heading(),
+                    ),
+                )
+                .on_hover_localized("Comparison.hover");
+                ui.toggle_value(
+                    &mut state.windows.open_fit,
+                    (
+                        RichText::new(FUNCTION).heading(),
+                        RichText::new(ui.localize("Fit")).heading(),
+                    ),
+                )
+                .on_hover_localized("Fit.hover");
+            })
+            .response
+            .on_disabled_hover_localized("Comparison.empty");
         });
     }
 
@@ -288,13 +396,24 @@ impl Pane {
         //     })
         // });
         let data_frame = &self.composition;
-        let data_frame = data_frame.data_frame.unnest(["Keys"], None)?;
-        _ = xlsx::save(&data_frame, &format!("{name}.utca.xlsx"));
+        let data_frame = data_frame.data_frame.unnest([KEYS], None)?;
+        let quality = ui
+            .memory_mut(|memory| {
+                memory
+                    .caches
+                    .cache::<QualityComputed>()
+                    .get(QualityKey::new(&self.species, &state.settings))
+            })
+            .unnest([VALUE], None)?;
+        _ = xlsx::save_sheets(
+            &[("Composition", &data_frame), ("Quality", &quality)],
+            &format!("{name}.utca.xlsx"),
+        );
         Ok(())
     }
 
     fn meta(&self, state: &State) -> Metadata {
-        match state.settings.index {
+        let mut meta = match state.settings.index {
             Some(index) => self.frames[index].meta.clone(),
             None => {
                 let mut meta = Metadata::default();
@@ -305,7 +424,22 @@ impl Pane {
                 meta.insert(VERSION.to_owned(), DEFAULT_VERSION.to_owned());
                 meta
             }
+        };
+        let filters = state
+            .settings
+            .compositions
+            .iter()
+            .filter_map(|composition| Some((composition, state.settings.filters.get(composition)?)))
+            .collect::<BTreeMap<_, _>>();
+        if !filters.is_empty() {
+            match ::ron::ser::to_string(&filters) {
+                Ok(filters) => {
+                    meta.insert(FILTERS.to_owned(), filters);
+                }
+                Err(error) => warn!(%error, "filters metadata is not written"),
+            }
         }
+        meta
     }
 
     fn central(&mut self, ui: &mut Ui, state: &mut State) {
@@ -317,16 +451,25 @@ impl Pane {
                 hash: hash(key),
             }
         });
-        // Table
-        let data_frame = ui.memory_mut(|memory| {
-            memory
-                .caches
-                .cache::<TableComputed>()
-                .get(TableKey::new(&self.composition, &state.settings))
-        });
         match state.settings.view {
-            View::Plot => PlotView::new(&data_frame, state).show(ui),
-            View::Table => TableView::new(&data_frame, state).show(ui),
+            View::Plot => {
+                let data_frame = ui.memory_mut(|memory| {
+                    memory
+                        .caches
+                        .cache::<PlotComputed>()
+                        .get(PlotKey::new(&self.composition, &state.settings))
+                });
+                PlotView::new(&data_frame, state).show(ui);
+            }
+            View::Table => {
+                let data_frame = ui.memory_mut(|memory| {
+                    memory
+                        .caches
+                        .cache::<TableComputed>()
+                        .get(TableKey::new(&self.composition, &state.settings))
+                });
+                TableView::new(&data_frame, &self.composition, state).show(ui);
+            }
         }
     }
 }
@@ -335,6 +478,12 @@ impl Pane {
     fn windows(&mut self, ui: &mut Ui, state: &mut State) {
         self.settings_window(ui, state);
         self.sum_window(ui, state);
+        self.quality_window(ui, state);
+        self.coverage_window(ui, state);
+        self.diff_window(ui, state);
+        self.comparison_window(ui, state);
+        self.fit_window(ui, state);
+        self.mass_spectrometry_window(ui, state);
     }
 
     fn settings_window(&mut self, ui: &mut Ui, state: &mut State) {
@@ -360,11 +509,24 @@ impl Pane {
             .id(ui.auto_id_with(ID_SOURCE).with("Symmetry"))
             .default_pos(ui.next_widget_position())
             .open(&mut state.windows.open_sum)
-            .show(ui.ctx(), |ui| self.sum_content(ui, &state.settings));
+            .show(ui.ctx(), |ui| self.sum_content(ui, &mut state.settings));
     }
 
     #[instrument(skip_all, err)]
-    fn sum_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+    fn sum_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Class"))
+                .on_hover_localized("Class.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Class"))
+                .selected_text(ui.localize(settings.class.text()))
+                .show_ui(ui, |ui| {
+                    for class in [Class::Symmetry, Class::Saturation, Class::Omega] {
+                        ui.selectable_value(&mut settings.class, class, ui.localize(class.text()))
+                            .on_hover_localized(class.hover_text());
+                    }
+                });
+        });
+        ui.separator();
         let data_frame = ui.memory_mut(|memory| {
             memory
                 .caches
@@ -373,8 +535,349 @@ impl Pane {
         });
         Symmetry::new(&data_frame, settings).show(ui).inner
     }
+
+    fn quality_window(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{SIGMA} Composition quality"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Quality"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_quality)
+            .show(ui.ctx(), |ui| self.quality_content(ui, &state.settings));
+    }
+
+    #[instrument(skip_all, err)]
+    fn quality_content(&mut self, ui: &mut Ui, settings: &Settings) -> PolarsResult<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<QualityComputed>()
+                .get(QualityKey::new(&self.species, settings))
+        });
+        Quality::new(&data_frame, settings).show(ui).inner
+    }
+
+    fn coverage_window(&mut self, ui: &mut Ui, state: &mut State) {
+        let meta = self.meta(state);
+        Window::new(format!("{PERCENT} Composition coverage"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Coverage"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_coverage)
+            .show(ui.ctx(), |ui| {
+                self.coverage_content(ui, &meta, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn coverage_content(
+        &mut self,
+        ui: &mut Ui,
+        meta: &Metadata,
+        settings: &mut Settings,
+    ) -> Result<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<CoverageComputed>()
+                .get(CoverageKey::new(&self.species, settings))
+        });
+        ui.horizontal(|ui| -> Result<()> {
+            ComboBox::from_id_salt(ui.auto_id_with("Composition"))
+                .selected_text(ui.localize(settings.coverage.composition.text()))
+                .show_ui(ui, |ui| {
+                    for composition in COMPOSITIONS {
+                        ui.selectable_value(
+                            &mut settings.coverage.composition,
+                            composition,
+                            ui.localize(composition.text()),
+                        )
+                        .on_hover_localized(composition.hover_text());
+                    }
+                });
+            ui.label(ui.localize("Level"))
+                .on_hover_localized("Level.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Level"))
+                .selected_text(format!("{}%", settings.coverage.level))
+                .show_ui(ui, |ui| {
+                    for level in CoverageSettings::LEVELS {
+                        ui.selectable_value(
+                            &mut settings.coverage.level,
+                            level,
+                            format!("{level}%"),
+                        );
+                    }
+                });
+            let name = format!(
+                "{}.coverage{}.utca.xlsx",
+                meta.format("."),
+                settings.coverage.level,
+            );
+            if ui
+                .button((FLOPPY_DISK, "XLSX"))
+                .on_hover_localized("Save")
+                .on_hover_text(&name)
+                .clicked()
+            {
+                let count = count(&data_frame, settings.coverage.level as f64 / 100.0)?;
+                xlsx::save(&data_frame.head(Some(count)), &name)?;
+            }
+            Ok(())
+        })
+        .inner?;
+        ui.separator();
+        Coverage::new(&data_frame, settings).show(ui).inner?;
+        Ok(())
+    }
+
+    fn diff_window(&mut self, ui: &mut Ui, state: &mut State) {
+        let meta = self.meta(state);
+        Window::new(format!("{GIT_DIFF} Composition diff"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Diff"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_diff)
+            .show(ui.ctx(), |ui| {
+                self.diff_content(ui, &meta, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn diff_content(
+        &mut self,
+        ui: &mut Ui,
+        meta: &Metadata,
+        settings: &mut Settings,
+    ) -> Result<()> {
+        Grid::new(ui.auto_id_with("Operands")).show(ui, |ui| {
+            ui.label(ui.localize("Left"))
+                .on_hover_localized("Left.hover");
+            operand(ui, &self.frames, &mut settings.diff.left, "Left");
+            ui.end_row();
+            ui.label(ui.localize("Right"))
+                .on_hover_localized("Right.hover");
+            operand(ui, &self.frames, &mut settings.diff.right, "Right");
+            ui.end_row();
+        });
+        let [left, right] = [settings.diff.left, settings.diff.right].map(|operand| {
+            ui.memory_mut(|memory| {
+                memory.caches.cache::<SpeciesComputed>().get(SpeciesKey {
+                    frames: &self.frames,
+                    index: operand.index,
+                    ddof: settings.ddof,
+                    discriminants: &settings.discriminants,
+                    method: operand.method,
+                    threshold: &settings.threshold,
+                })
+            })
+        });
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<DiffComputed>()
+                .get(DiffKey::new(&left, &right, settings))
+        });
+        ui.horizontal(|ui| -> Result<()> {
+            ComboBox::from_id_salt(ui.auto_id_with("Composition"))
+                .selected_text(ui.localize(settings.diff.composition.text()))
+                .show_ui(ui, |ui| {
+                    for composition in COMPOSITIONS {
+                        ui.selectable_value(
+                            &mut settings.diff.composition,
+                            composition,
+                            ui.localize(composition.text()),
+                        )
+                        .on_hover_localized(composition.hover_text());
+                    }
+                });
+            let name = format!("{}.diff.utca.xlsx", meta.format("."));
+            if ui
+                .button((FLOPPY_DISK, "XLSX"))
+                .on_hover_localized("Save")
+                .on_hover_text(&name)
+                .clicked()
+            {
+                xlsx::save(&data_frame, &name)?;
+            }
+            Ok(())
+        })
+        .inner?;
+        ui.separator();
+        Diff::new(&data_frame, settings).show(ui)?;
+        Ok(())
+    }
+
+    fn comparison_window(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{SCALES} Composition comparison"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Comparison"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_comparison)
+            .show(ui.ctx(), |ui| {
+                self.comparison_content(ui, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn comparison_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let Some(measured) = &self.measured else {
+            ui.label(ui.localize("Comparison.empty"));
+            return Ok(());
+        };
+        let detach = measured_header(ui, measured, settings);
+        ui.separator();
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<ComparisonComputed>()
+                .get(ComparisonKey::new(&self.species, &measured.data, settings))
+        });
+        Comparison::new(&data_frame, settings).show(ui).inner?;
+        if detach {
+            self.measured = None;
+        }
+        Ok(())
+    }
+
+    fn fit_window(&mut self, ui: &mut Ui, state: &mut State) {
+        Window::new(format!("{FUNCTION} Composition fit"))
+            .id(ui.auto_id_with(ID_SOURCE).with("Fit"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_fit)
+            .show(ui.ctx(), |ui| self.fit_content(ui, &mut state.settings));
+    }
+
+    #[instrument(skip_all, err)]
+    fn fit_content(&mut self, ui: &mut Ui, settings: &mut Settings) -> PolarsResult<()> {
+        let Some(measured) = &self.measured else {
+            ui.label(ui.localize("Comparison.empty"));
+            return Ok(());
+        };
+        let detach = measured_header(ui, measured, settings);
+        ui.separator();
+        let data_frame = ui.memory_mut(|memory| {
+            memory.caches.cache::<FitComputed>().get(FitKey::new(
+                &self.frames,
+                &measured.data,
+                settings,
+            ))
+        });
+        Fit::new(&data_frame, settings).show(ui).inner?;
+        if detach {
+            self.measured = None;
+        }
+        Ok(())
+    }
+}
+
+impl Pane {
+    fn mass_spectrometry_window(&mut self, ui: &mut Ui, state: &mut State) {
+        let meta = self.meta(state);
+        Window::new(format!("{ATOM} Composition mass spectrometry"))
+            .id(ui.auto_id_with(ID_SOURCE).with("MassSpectrometry"))
+            .default_pos(ui.next_widget_position())
+            .open(&mut state.windows.open_mass_spectrometry)
+            .show(ui.ctx(), |ui| {
+                self.mass_spectrometry_content(ui, &meta, &mut state.settings)
+            });
+    }
+
+    #[instrument(skip_all, err)]
+    fn mass_spectrometry_content(
+        &mut self,
+        ui: &mut Ui,
+        meta: &Metadata,
+        settings: &mut Settings,
+    ) -> Result<()> {
+        let data_frame = ui.memory_mut(|memory| {
+            memory
+                .caches
+                .cache::<MassSpectrometryComputed>()
+                .get(MassSpectrometryKey::new(&self.species, settings))
+        });
+        ui.horizontal(|ui| -> Result<()> {
+            ui.label(ui.localize("Ion")).on_hover_localized("Ion.hover");
+            ComboBox::from_id_salt(ui.auto_id_with("Ion"))
+                .selected_text(ui.localize(settings.ion.text()))
+                .show_ui(ui, |ui| {
+                    for ion in [Ion::Protonated, Ion::Ammoniated, Ion::Sodiated] {
+                        ui.selectable_value(&mut settings.ion, ion, ui.localize(ion.text()))
+                            .on_hover_localized(ion.hover_text());
+                    }
+                });
+            let name = format!("{}.ms.utca.xlsx", meta.format("."));
+            if ui
+                .button((FLOPPY_DISK, "XLSX"))
+                .on_hover_localized("Save")
+                .on_hover_text(&name)
+                .clicked()
+            {
+                xlsx::save(&data_frame, &name)?;
+            }
+            Ok(())
+        })
+        .inner?;
+        ui.separator();
+        MassSpectrometry::new(&data_frame, settings).show(ui)?;
+        Ok(())
+    }
+}
+
+/// Diff operand sample and method selectors
+fn operand(ui: &mut Ui, frames: &[HashedMetaDataFrame], operand: &mut Operand, id_salt: &str) {
+    if operand.index.is_some_and(|index| index >= frames.len()) {
+        operand.index = None;
+    }
+    let selected_text = match operand.index {
+        Some(index) => frames[index].meta.format(".").to_string(),
+        None => "Mean ± standard deviations".to_owned(),
+    };
+    ComboBox::from_id_salt(ui.auto_id_with(id_salt).with("Sample"))
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            for index in 0..frames.len() {
+                ui.selectable_value(
+                    &mut operand.index,
+                    Some(index),
+                    frames[index].meta.format(".").to_string(),
+                );
+            }
+            ui.selectable_value(&mut operand.index, None, "Mean ± standard deviations");
+        });
+    ComboBox::from_id_salt(ui.auto_id_with(id_salt).with("Method"))
+        .selected_text(ui.localize(operand.method.text()))
+        .show_ui(ui, |ui| {
+            for method in [Method::Gunstone, Method::MartinezForce, Method::VanderWal] {
+                ui.selectable_value(&mut operand.method, method, ui.localize(method.text()))
+                    .on_hover_localized(method.hover_text());
+            }
+        });
+}
+
+/// Measured composition header with the composition level selector, returns
+/// whether the measured composition should be detached
+fn measured_header(ui: &mut Ui, measured: &HashedMetaDataFrame, settings: &mut Settings) -> bool {
+    ui.horizontal(|ui| {
+        ComboBox::from_id_salt(ui.auto_id_with("Composition"))
+            .selected_text(ui.localize(settings.comparison.text()))
+            .show_ui(ui, |ui| {
+                for composition in COMPOSITIONS {
+                    ui.selectable_value(
+                        &mut settings.comparison,
+                        composition,
+                        ui.localize(composition.text()),
+                    )
+                    .on_hover_localized(composition.hover_text());
+                }
+            })
+            .response
+            .on_hover_localized(settings.comparison.hover_text());
+        ui.label(measured.meta.format(" ").to_string());
+        ui.button(X).on_hover_localized("Detach.hover").clicked()
+    })
+    .inner
 }
 
+mod comparison;
+mod coverage;
+mod diff;
+mod fit;
+mod mass_spectrometry;
 mod plot;
 mod sum;
 mod table;
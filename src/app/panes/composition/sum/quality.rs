@@ -0,0 +1,51 @@
+use crate::{
+    app::{
+        states::composition::settings::Settings,
+        widgets::mean_and_standard_deviation::MeanAndStandardDeviation,
+    },
+    r#const::{EM_DASH, NAME, VALUE},
+};
+use egui::{Grid, InnerResponse, Response, Ui, Widget};
+use egui_l20n::prelude::*;
+use polars::prelude::*;
+
+/// Quality widget
+pub(crate) struct Quality<'a> {
+    data_frame: &'a DataFrame,
+    settings: &'a Settings,
+}
+
+impl<'a> Quality<'a> {
+    pub(crate) fn new(data_frame: &'a DataFrame, settings: &'a Settings) -> Self {
+        Self {
+            data_frame,
+            settings,
+        }
+    }
+
+    pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
+        Grid::new(ui.auto_id_with("Quality")).show(ui, |ui| -> PolarsResult<()> {
+            ui.heading(ui.localize("Property?PluralCategory=one"));
+            ui.heading(ui.localize("Value"))
+                .on_hover_localized("Value.hover");
+            ui.end_row();
+            for row in 0..self.data_frame.height() {
+                let name = self.data_frame[NAME].str()?.get(row).unwrap_or(EM_DASH);
+                ui.label(ui.localize(name))
+                    .on_hover_localized(&format!("{name}.hover"));
+                MeanAndStandardDeviation::new(&self.data_frame, [VALUE], row)
+                    .with_standard_deviation(self.settings.standard_deviation)
+                    .with_sample(true)
+                    .show(ui)?;
+                ui.end_row();
+            }
+            Ok(())
+        })
+    }
+}
+
+impl Widget for Quality<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
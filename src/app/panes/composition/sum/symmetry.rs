@@ -6,6 +6,7 @@ use crate::{
         },
     },
     r#const::{EM_DASH, GROUP, TRIACYLGLYCEROLS, VALUE},
+    text::Text,
 };
 use egui::{
     Grid, InnerResponse, PopupCloseBehavior, Response, ScrollArea, Ui, Widget,
@@ -33,7 +34,8 @@ impl<'a> Symmetry<'a> {
 
     pub(crate) fn show(self, ui: &mut Ui) -> InnerResponse<PolarsResult<()>> {
         Grid::new(ui.auto_id_with("Symmetry")).show(ui, |ui| -> PolarsResult<()> {
-            ui.heading(ui.localize("Symmetry"));
+            ui.heading(ui.localize(self.settings.class.text()))
+                .on_hover_localized(self.settings.class.hover_text());
             ui.heading(ui.localize("Value"))
                 .on_hover_localized("Value.hover");
             ui.heading(ui.localize("Species"));
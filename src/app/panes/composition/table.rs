@@ -1,10 +1,11 @@
 use super::ID_SOURCE;
 use crate::{
     app::{
-        panes::MARGIN, states::composition::State,
+        panes::MARGIN,
+        states::composition::{State, settings::FilterWidget},
         widgets::mean_and_standard_deviation::NewMeanAndStandardDeviation,
     },
-    r#const::{KEY, SPECIES, THRESHOLD, VALUE},
+    r#const::{CALCULATED_OXIDIZABILITY, KEY, KEYS, SPECIES, THRESHOLD, VALUE},
     text::Text,
     utils::HashedDataFrame,
 };
@@ -20,20 +21,29 @@ use egui_table::{CellInfo, Column, HeaderCellInfo, HeaderRow, Table, TableDelega
 use lipid::prelude::*;
 use polars::prelude::*;
 use std::ops::Range;
-use tracing::instrument;
+use tracing::{error, instrument};
 
 /// Composition table
 #[derive(Debug)]
 pub(super) struct TableView<'a> {
     data_frame: &'a HashedDataFrame,
+    composition: &'a DataFrame,
     state: &'a mut State,
     // is_row_expanded: BTreeMap<u64, bool>,
     // prefetched: Vec<PrefetchInfo>,
 }
 
 impl<'a> TableView<'a> {
-    pub(crate) fn new(data_frame: &'a HashedDataFrame, state: &'a mut State) -> Self {
-        Self { data_frame, state }
+    pub(crate) fn new(
+        data_frame: &'a HashedDataFrame,
+        composition: &'a DataFrame,
+        state: &'a mut State,
+    ) -> Self {
+        Self {
+            data_frame,
+            composition,
+            state,
+        }
     }
 }
 
@@ -91,8 +101,13 @@ impl TableView<'_> {
             (1, column) if column.start != 0 => {
                 let index = column.start / 2;
                 let composition = self.state.settings.compositions[index];
-                ui.heading(ui.localize(composition.text()))
-                    .on_hover_text(ui.localize(composition.hover_text()));
+                ui.horizontal(|ui| {
+                    if let Err(error) = self.filter(ui, index) {
+                        error!(%error);
+                    }
+                    ui.heading(ui.localize(composition.text()))
+                        .on_hover_text(ui.localize(composition.hover_text()));
+                });
             }
             (2, column) if column.start + 1 < self.data_frame.width() => {
                 if !column.start.is_multiple_of(2) {
@@ -105,6 +120,21 @@ impl TableView<'_> {
         }
     }
 
+    /// Composition filter
+    fn filter(&mut self, ui: &mut Ui, index: usize) -> PolarsResult<()> {
+        let composition = self.state.settings.compositions[index];
+        let series = &self.composition[KEYS].struct_()?.fields_as_series()[index];
+        let filters = &mut self.state.settings.filters;
+        let filter = filters.entry(composition).or_default();
+        FilterWidget::new(composition, filter, series)
+            .percent(self.state.settings.percent)
+            .ui(ui);
+        if filter.is_empty() {
+            filters.remove(&composition);
+        }
+        Ok(())
+    }
+
     #[instrument(skip(self, ui), err)]
     fn cell_content_ui(
         &mut self,
@@ -198,6 +228,13 @@ impl TableView<'_> {
                     .with_standard_deviation(self.state.settings.standard_deviation)
                     .with_sample(true)
                     .show(ui)?;
+                    ui.label(
+                        species
+                            .struct_()?
+                            .field_by_name(CALCULATED_OXIDIZABILITY)?
+                            .str_value(index)?,
+                    )
+                    .on_hover_localized(CALCULATED_OXIDIZABILITY);
                     ui.end_row();
                 }
                 Ok(())
@@ -0,0 +1,234 @@
+use crate::{text::Text, utils::HashedMetaDataFrame};
+use egui::util::hash;
+use std::collections::VecDeque;
+
+/// Maximum number of the recorded changes
+const DEPTH: usize = 100;
+
+/// Edit history (undo and redo) of the configuration pane
+///
+/// The frames are recorded after each change, the clones of the data frames
+/// are cheap (the columns are shared). A drag or a text edit changes the frames
+/// on every frame of the interaction, so it is recorded once, as a single
+/// change, when the interaction ends.
+#[derive(Clone, Debug, Default)]
+pub(super) struct History {
+    current: Option<Snapshot>,
+    undo: VecDeque<(Change, Snapshot)>,
+    redo: Vec<(Change, Snapshot)>,
+}
+
+impl History {
+    /// Records the frames if they are changed since the last record and the
+    /// interaction (drag or text edit) is not in progress
+    pub(super) fn record(&mut self, frames: &[HashedMetaDataFrame], index: usize, editing: bool) {
+        let hash = hash(frames);
+        let Some(current) = &mut self.current else {
+            self.current = Some(Snapshot::new(frames, index, hash));
+            return;
+        };
+        if current.hash == hash {
+            current.index = index;
+            return;
+        }
+        // Keep the snapshot before the interaction until it ends
+        if editing {
+            return;
+        }
+        let change = Change::new(current, frames, index);
+        let previous = std::mem::replace(current, Snapshot::new(frames, index, hash));
+        self.undo.push_back((change, previous));
+        if self.undo.len() > DEPTH {
+            self.undo.pop_front();
+        }
+        self.redo.clear();
+    }
+
+    /// Restores the frames before the last change
+    pub(super) fn undo(&mut self, frames: &mut Vec<HashedMetaDataFrame>, index: &mut usize) {
+        let Some((change, previous)) = self.undo.pop_back() else {
+            return;
+        };
+        if let Some(current) = self.current.replace(previous.clone()) {
+            self.redo.push((change, current));
+        }
+        previous.restore(frames, index);
+    }
+
+    /// Restores the frames after the last undone change
+    pub(super) fn redo(&mut self, frames: &mut Vec<HashedMetaDataFrame>, index: &mut usize) {
+        let Some((change, next)) = self.redo.pop() else {
+            return;
+        };
+        if let Some(current) = self.current.replace(next.clone()) {
+            self.undo.push_back((change, current));
+        }
+        next.restore(frames, index);
+    }
+
+    /// Changes that can be undone, the last one is the most recent
+    pub(super) fn undo_changes(&self) -> impl DoubleEndedIterator<Item = Change> {
+        self.undo.iter().map(|(change, _)| *change)
+    }
+
+    /// Changes that can be redone, the last one is the next one
+    pub(super) fn redo_changes(&self) -> impl DoubleEndedIterator<Item = Change> {
+        self.redo.iter().map(|(change, _)| *change)
+    }
+
+    pub(super) fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub(super) fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+/// Recorded frames
+#[derive(Clone, Debug)]
+struct Snapshot {
+    frames: Vec<HashedMetaDataFrame>,
+    index: usize,
+    hash: u64,
+}
+
+impl Snapshot {
+    fn new(frames: &[HashedMetaDataFrame], index: usize, hash: u64) -> Self {
+        Self {
+            frames: frames.to_vec(),
+            index,
+            hash,
+        }
+    }
+
+    fn restore(self, frames: &mut Vec<HashedMetaDataFrame>, index: &mut usize) {
+        *frames = self.frames;
+        *index = self.index.min(frames.len().saturating_sub(1));
+    }
+}
+
+/// Change of the frames
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Change {
+    AddRow,
+    ClearTable,
+    DeleteRow,
+    DeleteTable,
+    Edit,
+}
+
+impl Change {
+    fn new(previous: &Snapshot, frames: &[HashedMetaDataFrame], index: usize) -> Self {
+        if frames.len() < previous.frames.len() {
+            return Self::DeleteTable;
+        }
+        let height = |frames: &[HashedMetaDataFrame], index: usize| {
+            frames.get(index).map_or(0, |frame| frame.data.height())
+        };
+        let (before, after) = (height(&previous.frames, index), height(frames, index));
+        if after == 0 && before > 0 {
+            Self::ClearTable
+        } else if after > before {
+            Self::AddRow
+        } else if after < before {
+            Self::DeleteRow
+        } else {
+            Self::Edit
+        }
+    }
+}
+
+impl Text for Change {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::AddRow => "Change-AddRow",
+            Self::ClearTable => "Change-ClearTable",
+            Self::DeleteRow => "Change-DeleteRow",
+            Self::DeleteTable => "Change-DeleteTable",
+            Self::Edit => "Change-Edit",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::AddRow => "Change-AddRow.hover",
+            Self::ClearTable => "Change-ClearTable.hover",
+            Self::DeleteRow => "Change-DeleteRow.hover",
+            Self::DeleteTable => "Change-DeleteTable.hover",
+            Self::Edit => "Change-Edit.hover",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::HashedDataFrame;
+    use metadata::{Metadata, polars::MetaDataFrame};
+    use polars::prelude::*;
+
+    fn frame(values: &[f64]) -> HashedMetaDataFrame {
+        let data_frame = df! { "Value" => values }.unwrap();
+        MetaDataFrame::new(Metadata::default(), HashedDataFrame::new(data_frame).unwrap())
+    }
+
+    #[test]
+    fn editing() {
+        let mut history = History::default();
+        history.record(&[frame(&[1.0])], 0, false);
+        // Every frame of the interaction
+        history.record(&[frame(&[2.0])], 0, true);
+        history.record(&[frame(&[3.0])], 0, true);
+        assert!(!history.can_undo());
+        // The interaction ends
+        history.record(&[frame(&[3.0])], 0, false);
+        assert_eq!(history.undo_changes().collect::<Vec<_>>(), [Change::Edit]);
+        let (mut frames, mut index) = (vec![frame(&[3.0])], 0);
+        history.undo(&mut frames, &mut index);
+        assert_eq!(frames[0].data.hash, frame(&[1.0]).data.hash);
+    }
+
+    #[test]
+    fn depth() {
+        let mut history = History::default();
+        for value in 0..DEPTH + 10 {
+            history.record(&[frame(&[value as f64])], 0, false);
+        }
+        assert_eq!(history.undo_changes().count(), DEPTH);
+    }
+
+    #[test]
+    fn redo() {
+        let mut history = History::default();
+        history.record(&[frame(&[1.0])], 0, false);
+        history.record(&[frame(&[1.0, 2.0])], 0, false);
+        let (mut frames, mut index) = (vec![frame(&[1.0, 2.0])], 0);
+        history.undo(&mut frames, &mut index);
+        assert_eq!(history.redo_changes().collect::<Vec<_>>(), [Change::AddRow]);
+        // A new change clears the redo stack
+        history.record(&[frame(&[3.0])], 0, false);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn delete_table() {
+        let mut history = History::default();
+        history.record(&[frame(&[1.0]), frame(&[2.0])], 1, false);
+        // The index is not updated yet
+        history.record(&[frame(&[1.0])], 1, false);
+        assert_eq!(
+            history.undo_changes().collect::<Vec<_>>(),
+            [Change::DeleteTable],
+        );
+        let (mut frames, mut index) = (vec![frame(&[1.0])], 1);
+        history.undo(&mut frames, &mut index);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].data.hash, frame(&[2.0]).data.hash);
+        assert_eq!(index, 1);
+        // The index is clamped to the frames
+        history.redo(&mut frames, &mut index);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(index, 0);
+    }
+}
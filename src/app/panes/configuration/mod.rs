@@ -1,4 +1,4 @@
-use self::table::TableView;
+use self::{history::History, table::TableView};
 use super::{Behavior, MARGIN};
 use crate::{
     app::{
@@ -7,16 +7,19 @@ use crate::{
         widgets::butons::{EditButton, ResetButton, ResizeButton, SettingsButton},
     },
     export,
+    text::Text as _,
     utils::{HashedDataFrame, HashedMetaDataFrame},
 };
 use anyhow::Result;
 use egui::{
-    CentralPanel, CursorIcon, Frame, Id, MenuBar, Response, RichText, ScrollArea, TextStyle,
-    TextWrapMode, TopBottomPanel, Ui, Widget as _, Window, util::hash,
+    CentralPanel, CursorIcon, Frame, Id, Key, KeyboardShortcut, MenuBar, Modifiers, Response,
+    RichText, ScrollArea, TextStyle, TextWrapMode, TopBottomPanel, Ui, Widget as _, Window,
+    util::hash,
 };
 use egui_l20n::prelude::*;
 use egui_phosphor::regular::{
-    CALCULATOR, ERASER, FLOPPY_DISK, LIST, NOTE_PENCIL, SLIDERS_HORIZONTAL, TAG, TEXT_AA, TRASH, X,
+    ARROW_U_UP_LEFT, ARROW_U_UP_RIGHT, CALCULATOR, CLOCK_COUNTER_CLOCKWISE, COLUMNS, ERASER,
+    FLOPPY_DISK, LIST, NOTE_PENCIL, SLIDERS_HORIZONTAL, TAG, TEXT_AA, TRASH, X,
 };
 use egui_tiles::{TileId, UiResponse};
 use lipid::prelude::*;
@@ -46,11 +49,17 @@ pub(crate) static SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
 pub(crate) struct Pane {
     id: Option<Id>,
     frames: Vec<HashedMetaDataFrame>,
+    #[serde(skip)]
+    history: History,
 }
 
 impl Pane {
     pub(crate) fn new(frames: Vec<HashedMetaDataFrame>) -> Self {
-        Self { id: None, frames }
+        Self {
+            id: None,
+            frames,
+            history: History::default(),
+        }
     }
 
     pub(crate) fn title(&self, index: Option<usize>) -> String {
@@ -112,9 +121,12 @@ impl Pane {
         CentralPanel::default()
             .frame(Frame::central_panel(&ui.style()))
             .show_inside(ui, |ui| {
+                self.shortcuts(ui, &mut state);
                 self.central(ui, &mut state);
                 self.windows(ui, &mut state);
             });
+        let editing = ui.ctx().dragged_id().is_some() || ui.ctx().wants_keyboard_input();
+        self.history.record(&self.frames, state.settings.index, editing);
         if behavior.close == Some(tile_id) {
             state.remove(ui.ctx(), id);
         } else {
@@ -159,6 +171,7 @@ impl Pane {
         ResizeButton::new(&mut state.settings.resizable).ui(ui);
         EditButton::new(&mut state.settings.edit).ui(ui);
         self.rename_button(ui, state);
+        self.stereospecific_button(ui, state);
         // Clear
         ui.add_enabled_ui(
             state.settings.edit && self.frames[state.settings.index].data.height() > 0,
@@ -185,6 +198,8 @@ impl Pane {
             }
         });
         ui.separator();
+        self.history_button(ui, state);
+        ui.separator();
         SettingsButton::new(&mut state.windows.open_settings).ui(ui);
         ui.separator();
         self.save_button(ui, state);
@@ -194,6 +209,81 @@ impl Pane {
         response
     }
 
+    /// History buttons: undo, redo and the list of the changes
+    fn history_button(&mut self, ui: &mut Ui, state: &mut State) {
+        let index = &mut state.settings.index;
+        ui.add_enabled_ui(self.history.can_undo(), |ui| {
+            if ui
+                .button(RichText::new(ARROW_U_UP_LEFT).heading())
+                .on_hover_localized("Undo")
+                .clicked()
+            {
+                self.history.undo(&mut self.frames, index);
+            }
+        });
+        ui.add_enabled_ui(self.history.can_redo(), |ui| {
+            if ui
+                .button(RichText::new(ARROW_U_UP_RIGHT).heading())
+                .on_hover_localized("Redo")
+                .clicked()
+            {
+                self.history.redo(&mut self.frames, index);
+            }
+        });
+        ui.menu_button(RichText::new(CLOCK_COUNTER_CLOCKWISE).heading(), |ui| {
+            // Redo changes, the farthest one on top
+            let redo: Vec<_> = self.history.redo_changes().collect();
+            for (steps, change) in redo.into_iter().enumerate() {
+                let text = RichText::new(ui.localize(change.text())).weak();
+                if ui
+                    .button(text)
+                    .on_hover_localized(change.hover_text())
+                    .clicked()
+                {
+                    for _ in 0..=steps {
+                        self.history.redo(&mut self.frames, index);
+                    }
+                }
+            }
+            ui.separator();
+            // Undo changes, the most recent one on top
+            let undo: Vec<_> = self.history.undo_changes().rev().collect();
+            for (steps, change) in undo.into_iter().enumerate() {
+                if ui
+                    .button(ui.localize(change.text()))
+                    .on_hover_localized(change.hover_text())
+                    .clicked()
+                {
+                    for _ in 0..=steps {
+                        self.history.undo(&mut self.frames, index);
+                    }
+                }
+            }
+        })
+        .response
+        .on_hover_localized("History");
+    }
+
+    /// Ctrl+Z undo and Ctrl+Shift+Z redo, unless the text is edited
+    fn shortcuts(&mut self, ui: &mut Ui, state: &mut State) {
+        if !ui.ui_contains_pointer() || ui.memory(|memory| memory.focused().is_some()) {
+            return;
+        }
+        let index = &mut state.settings.index;
+        if ui.input_mut(|input| {
+            input.consume_shortcut(&KeyboardShortcut::new(
+                Modifiers::COMMAND | Modifiers::SHIFT,
+                Key::Z,
+            ))
+        }) {
+            self.history.redo(&mut self.frames, index);
+        } else if ui.input_mut(|input| {
+            input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Z))
+        }) {
+            self.history.undo(&mut self.frames, index);
+        }
+    }
+
     /// Rename
     fn rename_button(&mut self, ui: &mut Ui, state: &State) {
         ui.add_enabled_ui(
@@ -232,6 +322,39 @@ impl Pane {
         Ok(())
     }
 
+    /// Stereospecific button, adds or removes SN-1 column from stereospecific
+    /// analysis
+    fn stereospecific_button(&mut self, ui: &mut Ui, state: &State) {
+        let data_frame = &self.frames[state.settings.index].data;
+        let selected = data_frame.schema().contains(STEREOSPECIFIC_NUMBERS1);
+        ui.add_enabled_ui(state.settings.edit, |ui| {
+            if ui
+                .selectable_label(selected, RichText::new(COLUMNS).heading())
+                .on_hover_localized("Stereospecific")
+                .clicked()
+            {
+                _ = self.stereospecific(state, !selected);
+            }
+        });
+    }
+
+    #[instrument(skip(self, state), err)]
+    fn stereospecific(&mut self, state: &State, stereospecific: bool) -> PolarsResult<()> {
+        let frame = &mut self.frames[state.settings.index];
+        let mut lazy_frame = frame.data.data_frame.clone().lazy();
+        lazy_frame = if stereospecific {
+            lazy_frame.with_column(
+                lit(NULL)
+                    .cast(DataType::Float64)
+                    .alias(STEREOSPECIFIC_NUMBERS1),
+            )
+        } else {
+            lazy_frame.drop(cols([STEREOSPECIFIC_NUMBERS1]))
+        };
+        frame.data = HashedDataFrame::new(lazy_frame.collect()?)?;
+        Ok(())
+    }
+
     // Save button
     fn save_button(&self, ui: &mut Ui, state: &State) {
         ui.menu_button(RichText::new(FLOPPY_DISK).heading(), |ui| {
@@ -353,4 +476,5 @@ impl Pane {
     }
 }
 
+mod history;
 mod table;
@@ -25,6 +25,8 @@ const FA: Range<usize> = LABEL.end..LABEL.end + 1;
 const SN123: Range<usize> = FA.end..FA.end + 1;
 const SN2_OR_SN1223: Range<usize> = SN123.end..SN123.end + 1;
 const LEN: usize = SN2_OR_SN1223.end;
+/// Optional SN-1 from stereospecific analysis
+const SN1: Range<usize> = LEN..LEN + 1;
 
 /// Table view
 pub(super) struct TableView<'a> {
@@ -51,7 +53,11 @@ impl TableView<'_> {
         }
         let height = ui.text_style_height(&TextStyle::Heading) + 2.0 * MARGIN.y;
         let num_rows = self.data.height() as u64 + 1;
-        let num_columns = LEN;
+        let num_columns = if self.data.schema().contains(STEREOSPECIFIC_NUMBERS1) {
+            SN1.end
+        } else {
+            LEN
+        };
         Table::new()
             .id_salt(id_salt)
             .num_rows(num_rows)
@@ -167,6 +173,20 @@ impl TableView<'_> {
                     });
                 };
             }
+            (0, SN1) => {
+                let response = ui
+                    .heading(ui.localize("StereospecificNumber.abbreviation?number=1"))
+                    .on_hover_localized("StereospecificNumber?number=1");
+                if self.state.settings.edit && response.hovered() {
+                    ui.ctx().input(|input| {
+                        for event in &input.raw.events {
+                            if let Event::Paste(text) = event {
+                                _ = self.paste_floats(STEREOSPECIFIC_NUMBERS1, text);
+                            }
+                        }
+                    });
+                };
+            }
             _ => {}
         };
     }
@@ -301,6 +321,19 @@ impl TableView<'_> {
                     self.data.rehash()?;
                 }
             }
+            (row, SN1) => {
+                let value = self.data[STEREOSPECIFIC_NUMBERS1].f64()?.get(row);
+                let inner_response = FloatWidget::new(value)
+                    .editable(self.state.settings.edit)
+                    .precision(Some(self.state.settings.precision))
+                    .hover(true)
+                    .show(ui);
+                if let Some(value) = inner_response.inner {
+                    self.data
+                        .try_apply(STEREOSPECIFIC_NUMBERS1, change_f64(row, value))?;
+                    self.data.rehash()?;
+                }
+            }
             _ => {}
         }
         Ok(())
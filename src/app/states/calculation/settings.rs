@@ -1,5 +1,8 @@
 use crate::{
-    app::{MAX_PRECISION, states::calculation::ID_SOURCE},
+    app::{
+        MAX_PRECISION,
+        states::{calculation::ID_SOURCE, composition::settings::View},
+    },
     assets::CHRISTIE,
     text::Text,
 };
@@ -43,6 +46,9 @@ pub(crate) struct Settings {
     pub(crate) precision: usize,
     pub(crate) significant: bool,
     pub(crate) table: Table,
+    pub(crate) view: View,
+    pub(crate) samples: bool,
+    pub(crate) overlay: bool,
     // General parameters
     pub(crate) ddof: u8,
     // Special parameters
@@ -54,14 +60,29 @@ pub(crate) struct Settings {
     pub(crate) weighted: bool,
     // Mutable
     pub(crate) fatty_acids: Vec<String>,
+    /// Label order of the clustering, empty is the original order
+    pub(crate) order: Vec<String>,
 
     // Correlations
     pub(crate) auto_size_correlations_table: bool,
+    pub(crate) collection: bool,
     pub(crate) chaddock: bool, // Chaddock, R.E. (1925). Principles and methods of statistics. Boston, New York, 1925.
     pub(crate) correlation: Correlation,
+    pub(crate) correction: Correction,
+    pub(crate) alpha: f64,
+    pub(crate) significance: bool,
     pub(crate) stereospecific_numbers: StereospecificNumbers,
     // Indices
     pub(crate) indices: Indices,
+    pub(crate) indices_plot: IndicesPlot,
+    // Principal component analysis
+    pub(crate) pca: Pca,
+    // Hierarchical clustering
+    pub(crate) clustering: Clustering,
+    // Enrichment and selectivity factors
+    pub(crate) factors: Factors,
+    // Series
+    pub(crate) series: Series,
 }
 
 impl Settings {
@@ -75,6 +96,9 @@ impl Settings {
             precision: 1,
             significant: false,
             table: Table::new(),
+            view: View::Table,
+            samples: false,
+            overlay: false,
             // General parameters
             ddof: 1,
             // Special parameters
@@ -86,13 +110,27 @@ impl Settings {
             weighted: false,
             // Mutable
             fatty_acids: Vec::new(),
+            order: Vec::new(),
             // Correlations
             auto_size_correlations_table: false,
             correlation: Correlation::Pearson,
+            collection: false,
             chaddock: false,
+            correction: Correction::BenjaminiHochberg,
+            alpha: 0.05,
+            significance: false,
             stereospecific_numbers: StereospecificNumbers::OneAndTwoAndTree,
             // Indices
             indices: Indices::new(),
+            indices_plot: IndicesPlot::new(),
+            // Principal component analysis
+            pca: Pca::new(),
+            // Hierarchical clustering
+            clustering: Clustering::new(),
+            // Enrichment and selectivity factors
+            factors: Factors::new(),
+            // Series
+            series: Series::new(),
         }
     }
 }
@@ -109,6 +147,13 @@ impl Settings {
         self.sticky(ui);
         self.truncate(ui);
 
+        // Plot
+        if self.view == View::Plot {
+            ui.labeled_separator(ui.localize("Plot"));
+            self.samples(ui);
+            self.overlay(ui);
+        }
+
         ui.labeled_separator("Parameters");
         self.standard(ui);
 
@@ -137,7 +182,11 @@ impl Settings {
             self.auto_size_correlations_table(ui);
             self.stereospecific_numbers(ui);
             self.correlation(ui);
+            self.collection(ui);
             self.chaddock(ui);
+            self.correction(ui);
+            self.alpha(ui);
+            self.significance(ui);
         });
 
         // Indices
@@ -212,6 +261,24 @@ impl Settings {
         });
     }
 
+    /// Show samples
+    fn samples(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("ShowSamples"))
+                .on_hover_localized("ShowSamples.hover");
+            ui.checkbox(&mut self.samples, ());
+        });
+    }
+
+    /// Overlay samples
+    fn overlay(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("OverlaySamples"))
+                .on_hover_localized("OverlaySamples.hover");
+            ui.checkbox(&mut self.overlay, ());
+        });
+    }
+
     /// Standard
     fn standard(&mut self, ui: &mut Ui) {
         // Label
@@ -520,6 +587,15 @@ impl Settings {
         });
     }
 
+    /// Collection
+    fn collection(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Collection"))
+                .on_hover_localized("Collection.hover");
+            ui.checkbox(&mut self.collection, ());
+        });
+    }
+
     /// Chaddock
     fn chaddock(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
@@ -529,6 +605,56 @@ impl Settings {
         });
     }
 
+    /// Multiple testing correction
+    fn correction(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Correction"))
+                .on_hover_localized("Correction.hover");
+            ComboBox::from_id_salt("Correction")
+                .selected_text(ui.localize(self.correction.text()))
+                .show_ui(ui, |ui| {
+                    for correction in [
+                        Correction::None,
+                        Correction::Bonferroni,
+                        Correction::BenjaminiHochberg,
+                    ] {
+                        ui.selectable_value(
+                            &mut self.correction,
+                            correction,
+                            ui.localize(correction.text()),
+                        )
+                        .on_hover_localized(correction.hover_text());
+                    }
+                })
+                .response
+                .on_hover_localized(self.correction.hover_text());
+        });
+    }
+
+    /// Significance level
+    fn alpha(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("SignificanceLevel"))
+                .on_hover_localized("SignificanceLevel.hover");
+            DragValue::new(&mut self.alpha)
+                .range(0.001..=0.2)
+                .speed(0.001)
+                .ui(ui);
+            if ui.button((BOOKMARK, "0.05")).clicked() {
+                self.alpha = 0.05;
+            };
+        });
+    }
+
+    /// Suppress coloring of insignificant correlations
+    fn significance(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("Significance"))
+                .on_hover_localized("Significance.hover");
+            ui.checkbox(&mut self.significance, ());
+        });
+    }
+
     /// Indices
     fn indices(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
@@ -579,6 +705,255 @@ impl Text for Correlation {
     }
 }
 
+/// Multiple testing correction
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Correction {
+    None,
+    Bonferroni,
+    BenjaminiHochberg,
+}
+
+impl Text for Correction {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::None => "Correction-None",
+            Self::Bonferroni => "Correction-Bonferroni",
+            Self::BenjaminiHochberg => "Correction-BenjaminiHochberg",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::None => "Correction-None.hover",
+            Self::Bonferroni => "Correction-Bonferroni.hover",
+            Self::BenjaminiHochberg => "Correction-BenjaminiHochberg.hover",
+        }
+    }
+}
+
+/// Principal component analysis settings
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Pca {
+    /// Variables, `None` is all stereospecific numbers concatenated
+    pub(crate) stereospecific_numbers: Option<StereospecificNumbers>,
+    pub(crate) center: bool,
+    pub(crate) scale: bool,
+    /// Metadata key of the sample group, `None` is the name stem
+    pub(crate) group: Option<String>,
+    /// Components of the score and loading plots axes
+    pub(crate) x: usize,
+    pub(crate) y: usize,
+}
+
+impl Pca {
+    pub(crate) fn new() -> Self {
+        Self {
+            stereospecific_numbers: Some(StereospecificNumbers::OneAndTwoAndTree),
+            center: true,
+            scale: false,
+            group: None,
+            x: 0,
+            y: 1,
+        }
+    }
+}
+
+/// Hierarchical clustering settings
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Clustering {
+    /// Variables, `None` is all stereospecific numbers concatenated
+    pub(crate) stereospecific_numbers: Option<StereospecificNumbers>,
+    pub(crate) distance: Distance,
+    pub(crate) linkage: Linkage,
+    /// Reorder the calculation and correlations tables by the clustering
+    pub(crate) sort: bool,
+}
+
+impl Clustering {
+    pub(crate) fn new() -> Self {
+        Self {
+            stereospecific_numbers: Some(StereospecificNumbers::OneAndTwoAndTree),
+            distance: Distance::Euclidean,
+            linkage: Linkage::Average,
+            sort: false,
+        }
+    }
+}
+
+/// Distance
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Distance {
+    Euclidean,
+    BrayCurtis,
+    /// 1 - Pearson correlation coefficient
+    Correlation,
+}
+
+impl Distance {
+    pub(crate) const ALL: [Self; 3] = [Self::Euclidean, Self::BrayCurtis, Self::Correlation];
+}
+
+impl Text for Distance {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Euclidean => "Distance-Euclidean",
+            Self::BrayCurtis => "Distance-BrayCurtis",
+            Self::Correlation => "Distance-Correlation",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Euclidean => "Distance-Euclidean.hover",
+            Self::BrayCurtis => "Distance-BrayCurtis.hover",
+            Self::Correlation => "Distance-Correlation.hover",
+        }
+    }
+}
+
+/// Linkage
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Linkage {
+    Single,
+    Complete,
+    Average,
+    Ward,
+}
+
+impl Linkage {
+    pub(crate) const ALL: [Self; 4] = [Self::Single, Self::Complete, Self::Average, Self::Ward];
+}
+
+impl Text for Linkage {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Single => "Linkage-Single",
+            Self::Complete => "Linkage-Complete",
+            Self::Average => "Linkage-Average",
+            Self::Ward => "Linkage-Ward",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Single => "Linkage-Single.hover",
+            Self::Complete => "Linkage-Complete.hover",
+            Self::Average => "Linkage-Average.hover",
+            Self::Ward => "Linkage-Ward.hover",
+        }
+    }
+}
+
+/// Enrichment and selectivity factors settings
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct Factors {
+    /// Relative band around the neutral enrichment factor (1 or 1/3 if the
+    /// factors are normalized), inside of which the position is random
+    pub(crate) band: f64,
+    /// Selectivity factor plot axis
+    pub(crate) axis: Axis,
+}
+
+impl Factors {
+    pub(crate) fn new() -> Self {
+        Self {
+            band: 0.2,
+            axis: Axis::Carbon,
+        }
+    }
+}
+
+/// Selectivity factor plot axis
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Axis {
+    Carbon,
+    Unsaturation,
+}
+
+impl Axis {
+    pub(crate) const ALL: [Self; 2] = [Self::Carbon, Self::Unsaturation];
+}
+
+impl Text for Axis {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Carbon => "Axis-Carbon",
+            Self::Unsaturation => "Axis-Unsaturation",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Carbon => "Axis-Carbon.hover",
+            Self::Unsaturation => "Axis-Unsaturation.hover",
+        }
+    }
+}
+
+/// Positional preference of the fatty acid
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Preference {
+    /// Enriched in the SN-2 position
+    Preferred,
+    /// Neither enriched nor depleted
+    Random,
+    /// Depleted in the SN-2 position
+    Excluded,
+}
+
+impl Preference {
+    /// Preference of the enrichment factor within the relative band around
+    /// the neutral value
+    pub(crate) fn new(enrichment: f64, neutral: f64, band: f64) -> Self {
+        if enrichment > neutral * (1.0 + band) {
+            Self::Preferred
+        } else if enrichment < neutral * (1.0 - band) {
+            Self::Excluded
+        } else {
+            Self::Random
+        }
+    }
+}
+
+impl Text for Preference {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Preferred => "Preference-Preferred",
+            Self::Random => "Preference-Random",
+            Self::Excluded => "Preference-Excluded",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Preferred => "Preference-Preferred.hover",
+            Self::Random => "Preference-Random.hover",
+            Self::Excluded => "Preference-Excluded.hover",
+        }
+    }
+}
+
+/// Series settings
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) struct Series {
+    /// Metadata key of the ordering variable, `None` is the first numeric
+    /// treatment of the name (`{Control;3day}`)
+    pub(crate) variable: Option<String>,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+    /// Indices instead of the fatty acids
+    pub(crate) indices: bool,
+}
+
+impl Series {
+    pub(crate) fn new() -> Self {
+        Self {
+            variable: None,
+            stereospecific_numbers: StereospecificNumbers::OneAndTwoAndTree,
+            indices: false,
+        }
+    }
+}
+
 /// Calculation table settings
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub(crate) struct Table {
@@ -602,7 +977,7 @@ impl Table {
 pub(crate) struct Indices(Vec<Index>);
 
 impl Indices {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self(vec![
             Index::new("Saturated"),
             Index::new("Monounsaturated"),
@@ -625,6 +1000,10 @@ impl Indices {
             Index::new("PolyunsaturatedToSaturated"),
             Index::new("UnsaturationIndex"),
             Index::new("IodineValue"),
+            Index::new("AllylicPositionEquivalent"),
+            Index::new("BisAllylicPositionEquivalent"),
+            Index::new("PeroxidizabilityIndex"),
+            Index::new("CalculatedOxidizability"),
         ])
     }
 }
@@ -684,6 +1063,47 @@ impl DerefMut for Indices {
     }
 }
 
+/// Indices plot settings
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct IndicesPlot {
+    pub(crate) view: View,
+    pub(crate) chart: Chart,
+    pub(crate) stereospecific_numbers: StereospecificNumbers,
+}
+
+impl IndicesPlot {
+    pub(crate) fn new() -> Self {
+        Self {
+            view: View::Table,
+            chart: Chart::Radar,
+            stereospecific_numbers: StereospecificNumbers::OneAndTwoAndTree,
+        }
+    }
+}
+
+/// Chart
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub(crate) enum Chart {
+    Radar,
+    Bars,
+}
+
+impl Text for Chart {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Radar => "Chart-Radar",
+            Self::Bars => "Chart-Bars",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Radar => "Chart-Radar.hover",
+            Self::Bars => "Chart-Bars.hover",
+        }
+    }
+}
+
 /// Index
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub(crate) struct Index {
@@ -4,8 +4,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub struct Windows {
     pub open_correlations: bool,
+    pub open_pca: bool,
+    pub open_clustering: bool,
+    pub open_factors: bool,
+    pub open_series: bool,
     pub open_sum: bool,
     pub open_biodiesel_sum: bool,
+    pub open_references: bool,
     pub open_settings: bool,
 }
 
@@ -13,8 +18,13 @@ impl Windows {
     pub fn new() -> Self {
         Self {
             open_correlations: false,
+            open_pca: false,
+            open_clustering: false,
+            open_factors: false,
+            open_series: false,
             open_sum: false,
             open_biodiesel_sum: false,
+            open_references: false,
             open_settings: false,
         }
     }
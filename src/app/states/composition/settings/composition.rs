@@ -6,7 +6,7 @@ use crate::text::Text;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-pub(crate) const COMPOSITIONS: [Composition; 15] = [
+pub(crate) const COMPOSITIONS: [Composition; 21] = [
     SPECIES_STEREO,
     SPECIES_POSITIONAL,
     SPECIES_MONO,
@@ -19,6 +19,12 @@ pub(crate) const COMPOSITIONS: [Composition; 15] = [
     ECN_STEREO,
     ECN_POSITIONAL,
     ECN_MONO,
+    CN_STEREO,
+    CN_POSITIONAL,
+    CN_MONO,
+    PN_STEREO,
+    PN_POSITIONAL,
+    PN_MONO,
     UNSATURATION_STEREO,
     UNSATURATION_POSITIONAL,
     UNSATURATION_MONO,
@@ -38,6 +44,20 @@ pub(crate) const ECN_POSITIONAL: Composition = EquivalentCarbonNumber(Some(Posit
 // Equivalent carbon number composition, stereospecific
 pub(crate) const ECN_STEREO: Composition = EquivalentCarbonNumber(Some(Stereo));
 
+// Carbon number composition, non-stereospecific
+pub(crate) const CN_MONO: Composition = CarbonNumber(None);
+// Carbon number composition, positional-specific
+pub(crate) const CN_POSITIONAL: Composition = CarbonNumber(Some(Positional));
+// Carbon number composition, stereospecific
+pub(crate) const CN_STEREO: Composition = CarbonNumber(Some(Stereo));
+
+// Partition number composition, non-stereospecific
+pub(crate) const PN_MONO: Composition = PartitionNumber(None);
+// Partition number composition, positional-specific
+pub(crate) const PN_POSITIONAL: Composition = PartitionNumber(Some(Positional));
+// Partition number composition, stereospecific
+pub(crate) const PN_STEREO: Composition = PartitionNumber(Some(Stereo));
+
 // Species composition, non-stereospecific
 pub(crate) const SPECIES_MONO: Composition = Species(None);
 // Species composition, non-stereospecific, positional-specific
@@ -62,8 +82,10 @@ pub(crate) const UNSATURATION_STEREO: Composition = Unsaturation(Some(Stereo));
 /// Composition
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub(crate) enum Composition {
+    CarbonNumber(Option<Stereospecificity>),
     EquivalentCarbonNumber(Option<Stereospecificity>),
     Mass(Option<Stereospecificity>),
+    PartitionNumber(Option<Stereospecificity>),
     Species(Option<Stereospecificity>),
     Type(Option<Stereospecificity>),
     Unsaturation(Option<Stereospecificity>),
@@ -76,8 +98,10 @@ impl Composition {
 
     pub(crate) fn stereospecificity(&self) -> Option<Stereospecificity> {
         match *self {
+            CarbonNumber(stereospecificity) => stereospecificity,
             EquivalentCarbonNumber(stereospecificity) => stereospecificity,
             Mass(stereospecificity) => stereospecificity,
+            PartitionNumber(stereospecificity) => stereospecificity,
             Species(stereospecificity) => stereospecificity,
             Type(stereospecificity) => stereospecificity,
             Unsaturation(stereospecificity) => stereospecificity,
@@ -86,12 +110,18 @@ impl Composition {
 
     pub(crate) fn abbreviation_text(&self) -> &'static str {
         match *self {
+            CN_MONO => "Composition_CarbonNumber_Monospecific.abbreviation",
+            CN_POSITIONAL => "Composition_CarbonNumber_Positionalspecific.abbreviation",
+            CN_STEREO => "Composition_CarbonNumber_Stereospecific.abbreviation",
             ECN_MONO => "Composition_EquivalentCarbonNumber_Monospecific.abbreviation",
             ECN_POSITIONAL => "Composition_EquivalentCarbonNumber_Positionalspecific.abbreviation",
             ECN_STEREO => "Composition_EquivalentCarbonNumber_Stereospecific.abbreviation",
             MASS_MONO => "Composition_Mass_Monospecific.abbreviation",
             MASS_POSITIONAL => "Composition_Mass_Positionalspecific.abbreviation",
             MASS_STEREO => "Composition_Mass_Stereospecific.abbreviation",
+            PN_MONO => "Composition_PartitionNumber_Monospecific.abbreviation",
+            PN_POSITIONAL => "Composition_PartitionNumber_Positionalspecific.abbreviation",
+            PN_STEREO => "Composition_PartitionNumber_Stereospecific.abbreviation",
             SPECIES_MONO => "Composition_Species_Monospecific.abbreviation",
             SPECIES_POSITIONAL => "Composition_Species_Positionalspecific.abbreviation",
             SPECIES_STEREO => "Composition_Species_Stereospecific.abbreviation",
@@ -108,12 +138,18 @@ impl Composition {
 impl Text for Composition {
     fn text(&self) -> &'static str {
         match *self {
+            CN_MONO => "Composition_CarbonNumber_Monospecific",
+            CN_POSITIONAL => "Composition_CarbonNumber_Positionalspecific",
+            CN_STEREO => "Composition_CarbonNumber_Stereospecific",
             ECN_MONO => "Composition_EquivalentCarbonNumber_Monospecific",
             ECN_POSITIONAL => "Composition_EquivalentCarbonNumber_Positionalspecific",
             ECN_STEREO => "Composition_EquivalentCarbonNumber_Stereospecific",
             MASS_MONO => "Composition_Mass_Monospecific",
             MASS_POSITIONAL => "Composition_Mass_Positionalspecific",
             MASS_STEREO => "Composition_Mass_Stereospecific",
+            PN_MONO => "Composition_PartitionNumber_Monospecific",
+            PN_POSITIONAL => "Composition_PartitionNumber_Positionalspecific",
+            PN_STEREO => "Composition_PartitionNumber_Stereospecific",
             SPECIES_MONO => "Composition_Species_Monospecific",
             SPECIES_POSITIONAL => "Composition_Species_Positionalspecific",
             SPECIES_STEREO => "Composition_Species_Stereospecific",
@@ -128,12 +164,18 @@ impl Text for Composition {
 
     fn hover_text(&self) -> &'static str {
         match *self {
+            CN_MONO => "Composition_CarbonNumber_Monospecific.hover",
+            CN_POSITIONAL => "Composition_CarbonNumber_Positionalspecific.hover",
+            CN_STEREO => "Composition_CarbonNumber_Stereospecific.hover",
             ECN_MONO => "Composition_EquivalentCarbonNumber_Monospecific.hover",
             ECN_POSITIONAL => "Composition_EquivalentCarbonNumber_Positionalspecific.hover",
             ECN_STEREO => "Composition_EquivalentCarbonNumber_Stereospecific.hover",
             MASS_MONO => "Composition_Mass_Monospecific.hover",
             MASS_POSITIONAL => "Composition_Mass_Positionalspecific.hover",
             MASS_STEREO => "Composition_Mass_Stereospecific.hover",
+            PN_MONO => "Composition_PartitionNumber_Monospecific.hover",
+            PN_POSITIONAL => "Composition_PartitionNumber_Positionalspecific.hover",
+            PN_STEREO => "Composition_PartitionNumber_Stereospecific.hover",
             SPECIES_MONO => "Composition_Species_Monospecific.hover",
             SPECIES_POSITIONAL => "Composition_Species_Positionalspecific.hover",
             SPECIES_STEREO => "Composition_Species_Stereospecific.hover",
@@ -150,12 +192,18 @@ impl Text for Composition {
 impl Display for Composition {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match *self {
+            CN_MONO => f.write_str("CarbonNumber_Monospecific"),
+            CN_POSITIONAL => f.write_str("CarbonNumber_Positionalspecific"),
+            CN_STEREO => f.write_str("CarbonNumber_Stereospecific"),
             ECN_MONO => f.write_str("EquivalentCarbonNumber_Monospecific"),
             ECN_POSITIONAL => f.write_str("EquivalentCarbonNumber_Positionalspecific"),
             ECN_STEREO => f.write_str("EquivalentCarbonNumber_Stereospecific"),
             MASS_MONO => f.write_str("Mass_Monospecific"),
             MASS_POSITIONAL => f.write_str("Mass_Positionalspecific"),
             MASS_STEREO => f.write_str("Mass_Stereospecific"),
+            PN_MONO => f.write_str("PartitionNumber_Monospecific"),
+            PN_POSITIONAL => f.write_str("PartitionNumber_Positionalspecific"),
+            PN_STEREO => f.write_str("PartitionNumber_Stereospecific"),
             SPECIES_MONO => f.write_str("Species_Monospecific"),
             SPECIES_POSITIONAL => f.write_str("Species_Positionalspecific"),
             SPECIES_STEREO => f.write_str("Species_Stereospecific"),
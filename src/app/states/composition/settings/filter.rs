@@ -1,26 +1,25 @@
 use super::{
-    ECN_MONO, ECN_STEREO, MASS_MONO, MASS_STEREO, SPECIES_MONO, SPECIES_POSITIONAL, SPECIES_STEREO,
-    Selection, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO, UNSATURATION_STEREO,
+    CN_MONO, CN_POSITIONAL, CN_STEREO, Composition, ECN_MONO, ECN_POSITIONAL, ECN_STEREO,
+    MASS_MONO, MASS_POSITIONAL, MASS_STEREO, PN_MONO, PN_POSITIONAL, PN_STEREO, SPECIES_MONO,
+    SPECIES_POSITIONAL, SPECIES_STEREO, TYPE_MONO, TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO,
+    UNSATURATION_POSITIONAL, UNSATURATION_STEREO,
 };
 use crate::text::Text;
-use ahash::RandomState;
 use egui::{
-    CentralPanel, DragValue, Grid, PopupCloseBehavior, Response, ScrollArea, Sense, Slider,
-    SliderClamping, TextStyle, TopBottomPanel, Ui, Widget,
-    containers::menu::{MenuButton, MenuConfig, SubMenuButton},
+    PopupCloseBehavior, Response, ScrollArea, Sense, Slider, SliderClamping, TextStyle, Ui, Widget,
+    containers::menu::{MenuButton, MenuConfig},
     emath::Float as _,
-    style::Widgets,
 };
 use egui_ext::LabeledSeparator as _;
 use egui_l20n::UiExt as _;
-use egui_phosphor::regular::{FUNNEL, FUNNEL_X, HASH, MINUS, PLUS};
+use egui_phosphor::regular::{FUNNEL, FUNNEL_X};
 use itertools::Itertools;
 use polars::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
     hash::{Hash, Hasher},
-    ops::{BitXor, Range},
 };
 use tracing::error;
 
@@ -40,32 +39,58 @@ const DEFAULT: [bool; 3] = [false; 3];
 // }
 
 /// Filter
-#[derive(Clone, Debug, Default)]
-pub struct Filter {
-    pub key: HashMap<AnyValue<'static>, [bool; 3]>,
-    pub value: f64,
+///
+/// Key values are excluded per stereospecific number, values less than the
+/// minimum value are excluded.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub(crate) struct Filter {
+    pub(crate) key: BTreeMap<String, [bool; 3]>,
+    pub(crate) value: f64,
 }
 
 impl Filter {
-    pub fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
-            key: HashMap::new(),
+            key: BTreeMap::new(),
             value: 0.0,
         }
     }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.key.is_empty() && self.value == 0.0
+    }
+
+    /// Excluded keys for the stereospecific number index
+    pub(crate) fn excluded(&self, index: usize) -> impl Iterator<Item = &str> {
+        self.key
+            .iter()
+            .filter(move |(_, value)| value[index])
+            .map(|(key, _)| key.as_str())
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for index in 0..3 {
+            let mut excluded = self.excluded(index).peekable();
+            if excluded.peek().is_some() {
+                write!(f, "{separator}sn-{} ≠ {}", index + 1, excluded.format(", "))?;
+                separator = "; ";
+            }
+        }
+        if self.value != 0.0 {
+            write!(f, "{separator}≥ {}", self.value)?;
+        }
+        Ok(())
+    }
 }
 
 impl Eq for Filter {}
 
 impl Hash for Filter {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        state.write_usize(self.key.len());
-        let hash = self
-            .key
-            .iter()
-            .map(|value| RandomState::with_seeds(1, 2, 3, 4).hash_one(value))
-            .fold(0, BitXor::bitxor);
-        state.write_u64(hash);
+        self.key.hash(state);
         self.value.ord().hash(state);
     }
 }
@@ -77,22 +102,28 @@ impl PartialEq for Filter {
 }
 
 /// Filter widget
-pub struct FilterWidget<'a> {
-    composition: &'a mut Selection,
+pub(crate) struct FilterWidget<'a> {
+    composition: Composition,
+    filter: &'a mut Filter,
     series: &'a Series,
     percent: bool,
 }
 
 impl<'a> FilterWidget<'a> {
-    pub fn new(composition: &'a mut Selection, series: &'a Series) -> Self {
+    pub(crate) fn new(
+        composition: Composition,
+        filter: &'a mut Filter,
+        series: &'a Series,
+    ) -> Self {
         Self {
             composition,
+            filter,
             series,
             percent: false,
         }
     }
 
-    pub fn percent(mut self, percent: bool) -> Self {
+    pub(crate) fn percent(mut self, percent: bool) -> Self {
         self.percent = percent;
         self
     }
@@ -100,71 +131,61 @@ impl<'a> FilterWidget<'a> {
 
 impl Widget for FilterWidget<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
-        let title = if self.composition.filter == Default::default() {
+        let title = if self.filter.is_empty() {
             FUNNEL_X
         } else {
             ui.visuals_mut().widgets.inactive = ui.visuals().widgets.active;
             FUNNEL
         };
-        let response = MenuButton::new(title)
+        let (response, inner_response) = MenuButton::new(title)
             .config(MenuConfig::new().close_behavior(PopupCloseBehavior::CloseOnClickOutside))
             .ui(ui, |ui| -> PolarsResult<()> {
                 ui.heading(format!(
                     "{} {}",
-                    ui.localize(&format!(
-                        "{}.abbreviation",
-                        self.composition.composition.text(),
-                    )),
+                    ui.localize(self.composition.abbreviation_text()),
                     ui.localize("Filter?case=lower"),
                 ));
                 // Key
                 ui.labeled_separator("Key");
-                match self.composition.composition {
-                    MASS_MONO | ECN_MONO | TYPE_MONO | UNSATURATION_MONO => {
-                        let series = self.series.unique()?.sort(Default::default())?;
-                        ui.add(ColumnWidget {
-                            indices: vec![0, 1, 2],
-                            selection: self.composition,
-                            series,
-                        });
-                    }
-                    SPECIES_MONO => {
-                        let fields = self.series.struct_()?.fields_as_series();
-                        let series = fields[0].unique()?.sort(Default::default())?;
+                let fields = self.series.struct_()?.fields_as_series();
+                match self.composition {
+                    MASS_MONO | ECN_MONO | CN_MONO | PN_MONO | SPECIES_MONO | TYPE_MONO
+                    | UNSATURATION_MONO => {
                         ui.add(ColumnWidget {
                             indices: vec![0, 1, 2],
-                            selection: self.composition,
-                            series,
+                            filter: self.filter,
+                            series: unique(&fields)?,
                         });
                     }
-                    SPECIES_POSITIONAL | TYPE_POSITIONAL => {
-                        let fields = self.series.struct_()?.fields_as_series();
+                    MASS_POSITIONAL
+                    | ECN_POSITIONAL
+                    | CN_POSITIONAL
+                    | PN_POSITIONAL
+                    | SPECIES_POSITIONAL
+                    | TYPE_POSITIONAL
+                    | UNSATURATION_POSITIONAL => {
                         ui.columns_const(|ui: &mut [Ui; 2]| -> PolarsResult<()> {
-                            let series = fields[0].unique()?.sort(Default::default())?;
                             ui[0].add(ColumnWidget {
                                 indices: vec![0, 2],
-                                selection: self.composition,
-                                series,
+                                filter: self.filter,
+                                series: unique(&[fields[0].clone(), fields[2].clone()])?,
                             });
-                            let series = fields[1].unique()?.sort(Default::default())?;
                             ui[1].add(ColumnWidget {
                                 indices: vec![1],
-                                selection: self.composition,
-                                series,
+                                filter: self.filter,
+                                series: unique(&fields[1..2])?,
                             });
                             Ok(())
                         })?;
                     }
-                    MASS_STEREO | ECN_STEREO | SPECIES_STEREO | TYPE_STEREO
-                    | UNSATURATION_STEREO => {
-                        let fields = self.series.struct_()?.fields_as_series();
+                    MASS_STEREO | ECN_STEREO | CN_STEREO | PN_STEREO | SPECIES_STEREO
+                    | TYPE_STEREO | UNSATURATION_STEREO => {
                         ui.columns_const(|ui: &mut [Ui; 3]| -> PolarsResult<()> {
                             for index in 0..3 {
-                                let series = fields[index].unique()?.sort(Default::default())?;
                                 ui[index].add(ColumnWidget {
                                     indices: vec![index],
-                                    selection: self.composition,
-                                    series,
+                                    filter: self.filter,
+                                    series: unique(&fields[index..index + 1])?,
                                 });
                             }
                             Ok(())
@@ -176,7 +197,7 @@ impl Widget for FilterWidget<'_> {
                 ui.horizontal(|ui| {
                     ui.label("Value");
                     ui.add(
-                        Slider::new(&mut self.composition.filter.value, 0.0..=1.0)
+                        Slider::new(&mut self.filter.value, 0.0..=1.0)
                             .clamping(SliderClamping::Always)
                             .logarithmic(true)
                             .custom_formatter(|mut value, _| {
@@ -195,8 +216,15 @@ impl Widget for FilterWidget<'_> {
                     );
                 });
                 Ok(())
-            })
-            .0;
+            });
+        if let Some(Err(error)) = inner_response.map(|inner_response| inner_response.inner) {
+            error!(%error);
+        }
+        let response = if self.filter.is_empty() {
+            response
+        } else {
+            response.on_hover_text(self.filter.to_string())
+        };
         // let response = ui
         //     .menu_button(title, |ui| -> PolarsResult<()> {
         //         ui.heading(format!(
@@ -359,14 +387,35 @@ impl Widget for FilterWidget<'_> {
     }
 }
 
+/// Sorted unique string values of the fields
+fn unique(fields: &[Series]) -> PolarsResult<Series> {
+    let mut series = Series::new_empty(PlSmallStr::EMPTY, &DataType::String);
+    for field in fields {
+        series.append(&field.cast(&DataType::String)?)?;
+    }
+    series.unique()?.sort(Default::default())
+}
+
 struct ColumnWidget<'a> {
     indices: Vec<usize>,
-    selection: &'a mut Selection,
+    filter: &'a mut Filter,
     series: Series,
 }
 
 impl<'a> Widget for ColumnWidget<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
+        // Keep excluded keys selectable when they are absent in the series
+        let mut keys = self
+            .series
+            .str()
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>();
+        keys.extend(self.filter.excluded(self.indices[0]).map(ToOwned::to_owned));
+        keys.sort();
+        keys.dedup();
         let stereospecific_numbers = self.indices.iter().map(|index| index + 1).format(",");
         ui.heading(format!("sn-{stereospecific_numbers}"));
         ui.separator();
@@ -378,52 +427,41 @@ impl<'a> Widget for ColumnWidget<'a> {
         if let Err(error) = ScrollArea::vertical()
             .id_salt(ui.next_auto_id())
             .max_height(max_scroll_height)
-            .show_rows(
-                ui,
-                height,
-                self.series.len(),
-                |ui, range| -> PolarsResult<()> {
-                    for index in range {
-                        let key = self.series.get(index)?.into_static();
-                        let text = key.str_value();
-                        let value = self.selection.filter.key.entry(key.clone()).or_default();
-                        let first = self.indices[0];
-                        let response = ui.toggle_value(&mut value[first], text);
-                        for &index in &self.indices[1..] {
-                            value[index] = value[first];
-                        }
-                        if *value == DEFAULT {
-                            self.selection.filter.key.remove(&key);
-                        }
-                        response.context_menu(|ui| {
-                            if ui.button(format!("{FUNNEL} Select all")).clicked() {
-                                for key in self.series.iter() {
-                                    let value = self
-                                        .selection
-                                        .filter
-                                        .key
-                                        .entry(key.into_static())
-                                        .or_default();
-                                    for &index in &self.indices {
-                                        value[index] = true;
-                                    }
+            .show_rows(ui, height, keys.len(), |ui, range| -> PolarsResult<()> {
+                for index in range {
+                    let key = &keys[index];
+                    let value = self.filter.key.entry(key.clone()).or_default();
+                    let first = self.indices[0];
+                    let response = ui.toggle_value(&mut value[first], key);
+                    for &index in &self.indices[1..] {
+                        value[index] = value[first];
+                    }
+                    if *value == DEFAULT {
+                        self.filter.key.remove(key);
+                    }
+                    response.context_menu(|ui| {
+                        if ui.button(format!("{FUNNEL} Select all")).clicked() {
+                            for key in &keys {
+                                let value = self.filter.key.entry(key.clone()).or_default();
+                                for &index in &self.indices {
+                                    value[index] = true;
                                 }
-                                ui.close();
-                            }
-                            if ui.button(format!("{FUNNEL_X} Unselect all")).clicked() {
-                                self.selection.filter.key.retain(|_, value| {
-                                    for &index in &self.indices {
-                                        value[index] = false;
-                                    }
-                                    *value != DEFAULT
-                                });
-                                ui.close();
                             }
-                        });
-                    }
-                    Ok(())
-                },
-            )
+                            ui.close();
+                        }
+                        if ui.button(format!("{FUNNEL_X} Unselect all")).clicked() {
+                            self.filter.key.retain(|_, value| {
+                                for &index in &self.indices {
+                                    value[index] = false;
+                                }
+                                *value != DEFAULT
+                            });
+                            ui.close();
+                        }
+                    });
+                }
+                Ok(())
+            })
             .inner
         {
             error!(%error);
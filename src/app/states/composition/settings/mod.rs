@@ -1,8 +1,10 @@
 pub(crate) use self::composition::{
-    COMPOSITIONS, Composition, ECN_MONO, ECN_POSITIONAL, ECN_STEREO, MASS_MONO, MASS_POSITIONAL,
-    MASS_STEREO, SPECIES_MONO, SPECIES_POSITIONAL, SPECIES_STEREO, Stereospecificity, TYPE_MONO,
+    CN_MONO, CN_POSITIONAL, CN_STEREO, COMPOSITIONS, Composition, ECN_MONO, ECN_POSITIONAL,
+    ECN_STEREO, MASS_MONO, MASS_POSITIONAL, MASS_STEREO, PN_MONO, PN_POSITIONAL, PN_STEREO,
+    SPECIES_MONO, SPECIES_POSITIONAL, SPECIES_STEREO, Stereospecificity, TYPE_MONO,
     TYPE_POSITIONAL, TYPE_STEREO, UNSATURATION_MONO, UNSATURATION_POSITIONAL, UNSATURATION_STEREO,
 };
+pub(crate) use self::filter::{Filter, FilterWidget};
 
 use crate::{
     app::{
@@ -27,6 +29,7 @@ use polars::prelude::*;
 use polars_utils::format_list_truncated;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     hash::{Hash, Hasher},
     iter::zip,
 };
@@ -44,6 +47,7 @@ pub(crate) struct Settings {
     pub(crate) sticky_columns: usize,
 
     pub(crate) view: View,
+    pub(crate) plot: Plot,
 
     // Parameters
     pub(crate) ddof: u8,
@@ -52,12 +56,18 @@ pub(crate) struct Settings {
     pub(crate) order: Order,
     pub(crate) round_mass: u32,
     pub(crate) compositions: Vec<Composition>,
+    pub(crate) filters: BTreeMap<Composition, Filter>,
     pub(crate) sort: Sort,
     pub(crate) threshold: Threshold,
     // Gunstone method
     pub(crate) discriminants: Discriminants,
 
     pub(crate) symmetry: Symmetry,
+    pub(crate) class: Class,
+    pub(crate) comparison: Composition,
+    pub(crate) coverage: Coverage,
+    pub(crate) diff: Diff,
+    pub(crate) ion: Ion,
 }
 
 impl Settings {
@@ -75,11 +85,13 @@ impl Settings {
             sticky_columns: 0,
 
             view: View::Table,
+            plot: Plot::new(),
 
             // Parameters
             ddof: 1,
             adduct: 0.0,
             compositions,
+            filters: BTreeMap::new(),
             method: Method::VanderWal,
             order: Order::Descending,
             round_mass: 2,
@@ -89,6 +101,11 @@ impl Settings {
             discriminants: Discriminants::new(),
 
             symmetry: Symmetry::new(),
+            class: Class::Symmetry,
+            comparison: SPECIES_MONO,
+            coverage: Coverage::new(),
+            diff: Diff::new(),
+            ion: Ion::Ammoniated,
         }
     }
 
@@ -102,6 +119,14 @@ impl Settings {
             self.standard_deviation(ui);
             self.sticky_columns(ui);
 
+            // Plot
+            if self.view == View::Plot {
+                ui.labeled_separator(ui.localize("Plot"));
+                self.show_legend(ui);
+                self.allow_drag(ui);
+                self.allow_scroll(ui);
+            }
+
             // // Filter
             // ui.label(ui.localize("Filter?case=title"));
             // for (index, selection) in &mut self.unconfirmed.selections.iter_mut().enumerate() {
@@ -215,6 +240,35 @@ impl Settings {
         });
     }
 
+    /// Show legend
+    fn show_legend(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("ShowLegend"))
+                .on_hover_localized("ShowLegend.hover");
+            ui.checkbox(&mut self.plot.show_legend, ());
+        });
+    }
+
+    /// Allow drag
+    fn allow_drag(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("AllowDrag"))
+                .on_hover_localized("AllowDrag.hover");
+            ui.checkbox(&mut self.plot.allow_drag.x, "x");
+            ui.checkbox(&mut self.plot.allow_drag.y, "y");
+        });
+    }
+
+    /// Allow scroll
+    fn allow_scroll(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(ui.localize("AllowScroll"))
+                .on_hover_localized("AllowScroll.hover");
+            ui.checkbox(&mut self.plot.allow_scroll.x, "x");
+            ui.checkbox(&mut self.plot.allow_scroll.y, "y");
+        });
+    }
+
     /// Method
     fn method(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
@@ -831,6 +885,112 @@ impl Symmetry {
     }
 }
 
+/// Coverage
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) struct Coverage {
+    pub(crate) composition: Composition,
+    /// Level, %
+    pub(crate) level: u8,
+}
+
+impl Coverage {
+    pub(crate) const LEVELS: [u8; 3] = [90, 95, 99];
+
+    pub(crate) fn new() -> Self {
+        Self {
+            composition: SPECIES_STEREO,
+            level: 95,
+        }
+    }
+}
+
+/// Diff
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) struct Diff {
+    pub(crate) composition: Composition,
+    pub(crate) left: Operand,
+    pub(crate) right: Operand,
+}
+
+impl Diff {
+    pub(crate) fn new() -> Self {
+        Self {
+            composition: SPECIES_STEREO,
+            left: Operand {
+                index: None,
+                method: Method::VanderWal,
+            },
+            right: Operand {
+                index: None,
+                method: Method::Gunstone,
+            },
+        }
+    }
+}
+
+/// Diff operand
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) struct Operand {
+    /// Sample index, mean of all samples if none
+    pub(crate) index: Option<usize>,
+    pub(crate) method: Method,
+}
+
+/// Triacylglycerol class
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) enum Class {
+    /// AAA, ABA, AAB(BAA), ABC
+    Symmetry,
+    /// SSS, SSU(USS), SUS, USU, UUS(SUU), UUU
+    Saturation,
+    /// ω-family of the fatty acid in each position
+    Omega,
+}
+
+impl Text for Class {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Symmetry => "Class-Symmetry",
+            Self::Saturation => "Class-Saturation",
+            Self::Omega => "Class-Omega",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Symmetry => "Class-Symmetry.hover",
+            Self::Saturation => "Class-Saturation.hover",
+            Self::Omega => "Class-Omega.hover",
+        }
+    }
+}
+
+/// Ion
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub(crate) enum Ion {
+    Protonated,
+    Ammoniated,
+    Sodiated,
+}
+
+impl Text for Ion {
+    fn text(&self) -> &'static str {
+        match self {
+            Self::Protonated => "Ion-Protonated",
+            Self::Ammoniated => "Ion-Ammoniated",
+            Self::Sodiated => "Ion-Sodiated",
+        }
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Self::Protonated => "Ion-Protonated.hover",
+            Self::Ammoniated => "Ion-Ammoniated.hover",
+            Self::Sodiated => "Ion-Sodiated.hover",
+        }
+    }
+}
+
 /// View
 #[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub(crate) enum View {
@@ -872,6 +1032,7 @@ impl Text for View {
 }
 
 /// Plot
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub(crate) struct Plot {
     pub(crate) allow_drag: Vec2b,
     pub(crate) allow_scroll: Vec2b,
@@ -889,4 +1050,4 @@ impl Plot {
 }
 
 mod composition;
-// pub(super) mod filter;
+mod filter;
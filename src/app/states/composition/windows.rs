@@ -3,6 +3,12 @@ use serde::{Deserialize, Serialize};
 /// Composition windows
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub struct Windows {
+    pub open_comparison: bool,
+    pub open_coverage: bool,
+    pub open_diff: bool,
+    pub open_fit: bool,
+    pub open_mass_spectrometry: bool,
+    pub open_quality: bool,
     pub open_settings: bool,
     pub open_sum: bool,
 }
@@ -10,6 +16,12 @@ pub struct Windows {
 impl Windows {
     pub fn new() -> Self {
         Self {
+            open_comparison: false,
+            open_coverage: false,
+            open_diff: false,
+            open_fit: false,
+            open_mass_spectrometry: false,
+            open_quality: false,
             open_settings: false,
             open_sum: false,
         }
@@ -0,0 +1,62 @@
+use crate::{r#const::design::KEYS, utils::metadata::Design};
+use egui::{Grid, Response, RichText, Ui};
+use egui_l20n::{ResponseExt as _, UiExt as _};
+use egui_phosphor::regular::MAGIC_WAND;
+use metadata::{Metadata, NAME};
+
+/// Experimental design widget
+///
+/// Species, cultivar, tissue, treatment, replicate, instrument and batch
+/// fields of the metadata.
+pub(crate) struct DesignWidget<'a> {
+    meta: &'a mut Metadata,
+}
+
+impl<'a> DesignWidget<'a> {
+    pub(crate) fn new(meta: &'a mut Metadata) -> Self {
+        Self { meta }
+    }
+
+    pub(crate) fn show(self, ui: &mut Ui) -> Response {
+        let mut response = Grid::new(ui.auto_id_with("Design"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                let mut changed = false;
+                for key in KEYS {
+                    ui.label(ui.localize(key));
+                    let mut text = self.meta.get(key).cloned().unwrap_or_default();
+                    if ui.text_edit_singleline(&mut text).changed() {
+                        if text.trim().is_empty() {
+                            self.meta.retain(|other, _| other != key);
+                        } else {
+                            self.meta.insert(key.to_owned(), text);
+                        }
+                        changed = true;
+                    }
+                    ui.end_row();
+                }
+                changed
+            });
+        // Fill the empty fields from the name
+        let name = self.meta.get(NAME).cloned();
+        let fill = ui
+            .add_enabled_ui(name.is_some(), |ui| {
+                ui.button((
+                    RichText::new(MAGIC_WAND),
+                    RichText::new(ui.localize("FillFromName")),
+                ))
+                .on_hover_localized("FillFromName.hover")
+            })
+            .inner;
+        if let Some(name) = name
+            && fill.clicked()
+        {
+            Design::parse(&name).fill(self.meta);
+            response.inner = true;
+        }
+        if response.inner {
+            response.response.mark_changed();
+        }
+        response.response
+    }
+}
@@ -1,5 +1,6 @@
 pub(super) use self::{
     about::About,
+    design::DesignWidget,
     fatty_acid::FattyAcidWidget,
     float::FloatWidget,
     github::Github,
@@ -8,8 +9,10 @@ pub(super) use self::{
 
 pub(super) mod about;
 pub(super) mod butons;
+pub(super) mod design;
 pub(super) mod fatty_acid;
 pub(super) mod float;
 pub(super) mod github;
 pub(super) mod label;
 pub(super) mod mean_and_standard_deviation;
+pub(super) mod reference;
@@ -0,0 +1,532 @@
+//! Index definitions and example compositions
+
+use crate::{
+    app::states::calculation::settings::Indices,
+    r#const::{MEAN, NAME, THRESHOLD},
+    utils::HashedDataFrame,
+};
+use lipid::prelude::*;
+use polars::prelude::*;
+use polars_ext::prelude::*;
+use std::sync::LazyLock;
+
+/// Index definitions
+pub(crate) const DEFINITIONS: &[Definition] = &[
+    Definition::new("Saturated", variable(Variable::Saturated), &[]),
+    Definition::new("Monounsaturated", variable(Variable::Monounsaturated), &[]),
+    Definition::new("Polyunsaturated", variable(Variable::Polyunsaturated), &[]),
+    Definition::new("Unsaturated", variable(Variable::Unsaturated), &[]),
+    Definition::new(
+        "Unsaturated-9",
+        variable(Variable::Omega(9)),
+        &[MOHAMED2022],
+    ),
+    Definition::new("Unsaturated-6", variable(Variable::Omega(6)), &[]),
+    Definition::new("Unsaturated-3", variable(Variable::Omega(3)), &[]),
+    Definition::new("Unsaturated9", variable(Variable::Delta(9)), &[]),
+    Definition::new("Conjugated", variable(Variable::Conjugated), &[]),
+    Definition::new("Trans", variable(Variable::Trans), &[PAIVA2016, FILIP2011]),
+    Definition::new(
+        "EicosapentaenoicAndDocosahexaenoic",
+        Expression::Sum(&[EICOSAPENTAENOIC, DOCOSAHEXAENOIC]),
+        &[RINCON_CERVERA2020, FERNANDES2014],
+    ),
+    Definition::new(
+        "FishLipidQuality",
+        Expression::Ratio(
+            &Expression::Sum(&[EICOSAPENTAENOIC, DOCOSAHEXAENOIC]),
+            &variable(Variable::Total),
+        ),
+        &[LUCZYNSKA2019, SENSO2007],
+    ),
+    Definition::new(
+        "HealthPromotingIndex",
+        Expression::Ratio(&variable(Variable::Unsaturated), &ATHEROGENIC),
+        &[CHEN2004],
+    ),
+    Definition::new(
+        "HypocholesterolemicToHypercholesterolemic",
+        Expression::Ratio(
+            &Expression::Sum(&[
+                variable(Variable::FattyAcid(18, 1, Some(9))),
+                variable(Variable::Polyunsaturated),
+            ]),
+            &Expression::Sum(&[LAURIC, MYRISTIC, PALMITIC]),
+        ),
+        &[SANTOS_SILVA2002],
+    ),
+    Definition::new(
+        "IndexOfAtherogenicity",
+        Expression::Ratio(&ATHEROGENIC, &variable(Variable::Unsaturated)),
+        &[ULBRICHT1991],
+    ),
+    Definition::new(
+        "IndexOfThrombogenicity",
+        Expression::Ratio(
+            &Expression::Sum(&[MYRISTIC, PALMITIC, STEARIC]),
+            &Expression::Sum(&[
+                Expression::Product(&[
+                    Expression::Constant(0.5),
+                    variable(Variable::Monounsaturated),
+                ]),
+                Expression::Product(&[
+                    Expression::Constant(0.5),
+                    variable(Variable::PolyunsaturatedOmega(6)),
+                ]),
+                Expression::Product(&[
+                    Expression::Constant(3.0),
+                    variable(Variable::PolyunsaturatedOmega(3)),
+                ]),
+                Expression::Ratio(
+                    &variable(Variable::PolyunsaturatedOmega(3)),
+                    &variable(Variable::PolyunsaturatedOmega(6)),
+                ),
+            ]),
+        ),
+        &[ULBRICHT1991],
+    ),
+    Definition::new(
+        "LinoleicToAlphaLinolenic",
+        Expression::Ratio(
+            &variable(Variable::FattyAcid(18, 2, Some(6))),
+            &variable(Variable::FattyAcid(18, 3, Some(3))),
+        ),
+        &[MAJDOUB_MATHLOUTHI2015],
+    ),
+    Definition::new(
+        "Polyunsaturated-6ToPolyunsaturated-3",
+        Expression::Ratio(
+            &variable(Variable::PolyunsaturatedOmega(6)),
+            &variable(Variable::PolyunsaturatedOmega(3)),
+        ),
+        &[],
+    ),
+    Definition::new(
+        "PolyunsaturatedToSaturated",
+        Expression::Ratio(
+            &variable(Variable::Polyunsaturated),
+            &variable(Variable::Saturated),
+        ),
+        &[KUMAR2011, CHAN2017],
+    ),
+    Definition::new(
+        "UnsaturationIndex",
+        variable(Variable::UnsaturationIndex),
+        &[LABUDA2018],
+    ),
+    Definition::new(
+        "IodineValue",
+        variable(Variable::IodineValue),
+        &[AOCS_CD_1C_85],
+    ),
+    Definition::new(
+        "AllylicPositionEquivalent",
+        Expression::Sum(&[
+            Expression::Product(&[Expression::Constant(2.0), variable(Variable::BisAllylic(0))]),
+            Expression::Product(&[
+                Expression::Constant(4.0),
+                variable(Variable::MoreBisAllylic(1)),
+            ]),
+        ]),
+        &[KNOTHE2002],
+    ),
+    Definition::new(
+        "BisAllylicPositionEquivalent",
+        variable(Variable::BisAllylicPositions),
+        &[KNOTHE2002],
+    ),
+    Definition::new(
+        "PeroxidizabilityIndex",
+        Expression::Sum(&[
+            Expression::Product(&[
+                Expression::Constant(0.025),
+                variable(Variable::BisAllylic(0)),
+            ]),
+            variable(Variable::BisAllylic(1)),
+            Expression::Product(&[Expression::Constant(2.0), variable(Variable::BisAllylic(2))]),
+            Expression::Product(&[Expression::Constant(4.0), variable(Variable::BisAllylic(3))]),
+            Expression::Product(&[Expression::Constant(6.0), variable(Variable::BisAllylic(4))]),
+            Expression::Product(&[
+                Expression::Constant(8.0),
+                variable(Variable::MoreBisAllylic(5)),
+            ]),
+        ]),
+        &[COSGROVE1987],
+    ),
+    Definition::new(
+        "CalculatedOxidizability",
+        Expression::Ratio(
+            &Expression::Sum(&[
+                variable(Variable::BisAllylic(0)),
+                Expression::Product(&[
+                    Expression::Constant(10.3),
+                    variable(Variable::BisAllylic(1)),
+                ]),
+                Expression::Product(&[
+                    Expression::Constant(21.6),
+                    variable(Variable::MoreBisAllylic(2)),
+                ]),
+            ]),
+            &Expression::Constant(100.0),
+        ),
+        &[FATEMI1980],
+    ),
+];
+
+/// Example compositions
+pub(crate) static EXAMPLES: LazyLock<Vec<Example>> =
+    LazyLock::new(|| examples().expect("example compositions"));
+
+/// All indices of the examples
+pub(crate) static INDICES: LazyLock<Indices> = LazyLock::new(Indices::new);
+
+const LAURIC: Expression = variable(Variable::FattyAcid(12, 0, None));
+const MYRISTIC: Expression = variable(Variable::FattyAcid(14, 0, None));
+const PALMITIC: Expression = variable(Variable::FattyAcid(16, 0, None));
+const STEARIC: Expression = variable(Variable::FattyAcid(18, 0, None));
+const EICOSAPENTAENOIC: Expression = variable(Variable::FattyAcid(20, 5, Some(3)));
+const DOCOSAHEXAENOIC: Expression = variable(Variable::FattyAcid(22, 6, Some(3)));
+const ATHEROGENIC: Expression = Expression::Sum(&[
+    LAURIC,
+    Expression::Product(&[Expression::Constant(4.0), MYRISTIC]),
+    PALMITIC,
+]);
+
+const AOCS_CD_1C_85: Reference = Reference::new("Reference-AocsCd1c85");
+const CHAN2017: Reference = Reference::doi("Reference-Chan2017", "10.1016/j.foodchem.2016.10.066");
+const CHEN2004: Reference = Reference::doi("Reference-Chen2004", "10.1021/jf035193z");
+const COSGROVE1987: Reference = Reference::new("Reference-Cosgrove1987");
+const FATEMI1980: Reference = Reference::new("Reference-Fatemi1980");
+const FERNANDES2014: Reference =
+    Reference::doi("Reference-Fernandes2014", "10.1016/j.foodchem.2014.03.055");
+const FILIP2011: Reference = Reference::doi("Reference-Filip2011", "10.1002/ejlt.200900231");
+const KNOTHE2002: Reference = Reference::doi("Reference-Knothe2002", "10.1007/s11746-002-0569-4");
+const KUMAR2011: Reference = Reference::doi("Reference-Kumar2011", "10.1007/s10811-010-9578-7");
+const LABUDA2018: Reference = Reference::doi("Reference-Labuda2018", "10.1351/goldbook.09864");
+const LUCZYNSKA2019: Reference =
+    Reference::doi("Reference-Luczynska2019", "10.3390/ijerph16193780");
+const MAJDOUB_MATHLOUTHI2015: Reference = Reference::doi(
+    "Reference-MajdoubMathlouthi2015",
+    "10.1017/S1751731115001731",
+);
+const MOHAMED2022: Reference =
+    Reference::doi("Reference-Mohamed2022", "10.1186/s43141-022-00329-0");
+const PAIVA2016: Reference = Reference::doi("Reference-Paiva2016", "10.1016/j.foodres.2016.08.007");
+const RINCON_CERVERA2020: Reference =
+    Reference::doi("Reference-RinconCervera2020", "10.3390/foods9020233");
+const SANTOS_SILVA2002: Reference =
+    Reference::doi("Reference-SantosSilva2002", "10.1016/S0301-6226(02)00059-3");
+const SENSO2007: Reference =
+    Reference::doi("Reference-Senso2007", "10.1016/j.foodchem.2006.01.036");
+const ULBRICHT1991: Reference =
+    Reference::doi("Reference-Ulbricht1991", "10.1016/0140-6736(91)91846-M");
+
+/// Definition
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Definition {
+    pub(crate) name: &'static str,
+    pub(crate) formula: Expression,
+    pub(crate) references: &'static [Reference],
+}
+
+impl Definition {
+    const fn new(
+        name: &'static str,
+        formula: Expression,
+        references: &'static [Reference],
+    ) -> Self {
+        Self {
+            name,
+            formula,
+            references,
+        }
+    }
+}
+
+/// Find definition by index name
+pub(crate) fn definition(name: &str) -> Option<&'static Definition> {
+    DEFINITIONS
+        .iter()
+        .find(|definition| definition.name == name)
+}
+
+/// Reference
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Reference {
+    /// Title message
+    pub(crate) title: &'static str,
+    pub(crate) doi: Option<&'static str>,
+}
+
+impl Reference {
+    const fn new(title: &'static str) -> Self {
+        Self { title, doi: None }
+    }
+
+    const fn doi(title: &'static str, doi: &'static str) -> Self {
+        Self {
+            title,
+            doi: Some(doi),
+        }
+    }
+}
+
+/// Expression
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Expression {
+    Constant(f64),
+    Variable(Variable),
+    Sum(&'static [Expression]),
+    Product(&'static [Expression]),
+    Ratio(&'static Expression, &'static Expression),
+}
+
+impl Expression {
+    /// Formula text, the variable names are localized by the function
+    pub(crate) fn text(&self, localize: &impl Fn(&str) -> String) -> String {
+        let operand = |expression: &Expression| {
+            if expression.is_compound() {
+                format!("({})", expression.text(localize))
+            } else {
+                expression.text(localize)
+            }
+        };
+        match self {
+            Self::Constant(constant) => constant.to_string(),
+            Self::Variable(variable) => variable.text(localize),
+            Self::Sum(expressions) => expressions
+                .iter()
+                .map(|expression| expression.text(localize))
+                .collect::<Vec<_>>()
+                .join(" + "),
+            Self::Product(expressions) => expressions
+                .iter()
+                .map(operand)
+                .collect::<Vec<_>>()
+                .join(" · "),
+            Self::Ratio(numerator, denominator) => {
+                format!("{} / {}", operand(numerator), operand(denominator))
+            }
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Self::Sum(_) | Self::Ratio(..))
+    }
+}
+
+const fn variable(variable: Variable) -> Expression {
+    Expression::Variable(variable)
+}
+
+/// Variable
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Variable {
+    Total,
+    Saturated,
+    Monounsaturated,
+    Polyunsaturated,
+    Unsaturated,
+    Omega(u8),
+    PolyunsaturatedOmega(u8),
+    Delta(u8),
+    Conjugated,
+    Trans,
+    /// Carbon, double bounds, omega
+    FattyAcid(u8, u8, Option<u8>),
+    /// Unsaturated with the exact number of bis-allylic positions
+    BisAllylic(u8),
+    /// Unsaturated with the minimum number of bis-allylic positions
+    MoreBisAllylic(u8),
+    UnsaturationIndex,
+    IodineValue,
+    BisAllylicPositions,
+}
+
+impl Variable {
+    /// Variable text, the fatty acids are not localized
+    fn text(&self, localize: &impl Fn(&str) -> String) -> String {
+        match *self {
+            Self::Total => localize("Variable-Total"),
+            Self::Saturated => localize("Variable-Saturated"),
+            Self::Monounsaturated => localize("Variable-Monounsaturated"),
+            Self::Polyunsaturated => localize("Variable-Polyunsaturated"),
+            Self::Unsaturated => localize("Variable-Unsaturated"),
+            Self::Omega(omega) => localize(&format!("Variable-Omega?number={omega}")),
+            Self::PolyunsaturatedOmega(omega) => {
+                localize(&format!("Variable-PolyunsaturatedOmega?number={omega}"))
+            }
+            Self::Delta(delta) => localize(&format!("Variable-Delta?number={delta}")),
+            Self::Conjugated => localize("Variable-Conjugated"),
+            Self::Trans => localize("Variable-Trans"),
+            Self::FattyAcid(carbon, double_bounds, Some(omega)) => {
+                format!("C{carbon}:{double_bounds}(n-{omega})")
+            }
+            Self::FattyAcid(carbon, double_bounds, None) => format!("C{carbon}:{double_bounds}"),
+            Self::BisAllylic(positions) => {
+                localize(&format!("Variable-BisAllylic?number={positions}"))
+            }
+            Self::MoreBisAllylic(positions) => {
+                localize(&format!("Variable-MoreBisAllylic?number={positions}"))
+            }
+            Self::UnsaturationIndex => localize("Variable-UnsaturationIndex"),
+            Self::IodineValue => localize("Variable-IodineValue"),
+            Self::BisAllylicPositions => localize("Variable-BisAllylicPositions"),
+        }
+    }
+}
+
+/// Example composition
+///
+/// The calculated frame of the composition, the examples have no positional
+/// composition, so all stereospecific numbers hold the total composition.
+#[derive(Clone, Debug)]
+pub(crate) struct Example {
+    pub(crate) name: &'static str,
+    pub(crate) frame: HashedDataFrame,
+}
+
+impl Example {
+    /// Example composition from the fatty acids and their content, the content
+    /// is normalized, so the profiles that miss minor fatty acids sum to one
+    fn new(name: &'static str, data_frame: DataFrame) -> PolarsResult<Self> {
+        let value =
+            || col(STEREOSPECIFIC_NUMBERS123) / col(STEREOSPECIFIC_NUMBERS123).sum();
+        let data_frame = data_frame
+            .lazy()
+            .select([
+                col(FATTY_ACID),
+                concat_arr(vec![value().alias(STEREOSPECIFIC_NUMBERS123)])?,
+                concat_arr(vec![value().alias(STEREOSPECIFIC_NUMBERS13)])?,
+                concat_arr(vec![value().alias(STEREOSPECIFIC_NUMBERS2)])?,
+                concat_arr(vec![value().alias(STEREOSPECIFIC_NUMBERS1)])?,
+                concat_arr(vec![value().alias(STEREOSPECIFIC_NUMBERS3)])?,
+                lit(true).alias(THRESHOLD),
+            ])
+            .collect()?;
+        Ok(Self {
+            name,
+            frame: HashedDataFrame::new(data_frame)?,
+        })
+    }
+}
+
+/// Value of the index in the calculated properties of the example
+pub(crate) fn value(data_frame: &DataFrame, name: &str) -> PolarsResult<Option<f64>> {
+    let Some(row) = data_frame[NAME]
+        .str()?
+        .iter()
+        .position(|index| index == Some(name))
+    else {
+        return Ok(None);
+    };
+    Ok(data_frame[STEREOSPECIFIC_NUMBERS123]
+        .struct_()?
+        .field_by_name(MEAN)?
+        .f64()?
+        .get(row))
+}
+
+fn examples() -> PolarsResult<Vec<Example>> {
+    Ok(vec![
+        Example::new(
+            "Sunflower",
+            df! {
+                FATTY_ACID => [
+                    fatty_acid!(C16 { })?,
+                    fatty_acid!(C18 { })?,
+                    fatty_acid!(C18 { 9 => C })?,
+                    fatty_acid!(C18 { 9 => C, 12 => C })?,
+                    fatty_acid!(C18 { 9 => C, 12 => C, 15 => C })?,
+                    fatty_acid!(C20 { })?,
+                    fatty_acid!(C22 { })?,
+                    fatty_acid!(C24 { })?,
+                ],
+                STEREOSPECIFIC_NUMBERS123 => [6.2, 3.7, 25.2, 63.1, 0.2, 0.3, 0.7, 0.2],
+            }?,
+        )?,
+        Example::new(
+            "Linseed",
+            df! {
+                FATTY_ACID => [
+                    fatty_acid!(C16 { })?,
+                    fatty_acid!(C18 { })?,
+                    fatty_acid!(C18 { 9 => C })?,
+                    fatty_acid!(C18 { 9 => C, 12 => C })?,
+                    fatty_acid!(C18 { 9 => C, 12 => C, 15 => C })?,
+                ],
+                STEREOSPECIFIC_NUMBERS123 => [5.5, 3.5, 19.0, 15.0, 57.0],
+            }?,
+        )?,
+        Example::new(
+            "Fish",
+            df! {
+                FATTY_ACID => [
+                    fatty_acid!(C14 { })?,
+                    fatty_acid!(C16 { })?,
+                    fatty_acid!(C16 { 9 => C })?,
+                    fatty_acid!(C18 { })?,
+                    fatty_acid!(C18 { 9 => C })?,
+                    fatty_acid!(C18 { 9 => C, 12 => C })?,
+                    fatty_acid!(C18 { 9 => C, 12 => C, 15 => C })?,
+                    fatty_acid!(C20 { 11 => C })?,
+                    fatty_acid!(C20 { 5 => C, 8 => C, 11 => C, 14 => C })?,
+                    fatty_acid!(C20 { 5 => C, 8 => C, 11 => C, 14 => C, 17 => C })?,
+                    fatty_acid!(C22 { 7 => C, 10 => C, 13 => C, 16 => C, 19 => C })?,
+                    fatty_acid!(C22 { 4 => C, 7 => C, 10 => C, 13 => C, 16 => C, 19 => C })?,
+                ],
+                STEREOSPECIFIC_NUMBERS123 => [
+                    5.0, 18.0, 7.0, 4.0, 20.0, 3.0, 1.0, 6.0, 1.0, 9.0, 2.0, 12.0,
+                ],
+            }?,
+        )?,
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn text() {
+        let text = |name| {
+            definition(name)
+                .unwrap()
+                .formula
+                .text(&|key: &str| key.to_owned())
+        };
+        assert_eq!(
+            text("IndexOfAtherogenicity"),
+            "(C12:0 + 4 · C14:0 + C16:0) / Variable-Unsaturated",
+        );
+        assert_eq!(
+            text("Polyunsaturated-6ToPolyunsaturated-3"),
+            "Variable-PolyunsaturatedOmega?number=6 / Variable-PolyunsaturatedOmega?number=3",
+        );
+        assert_eq!(
+            text("CalculatedOxidizability"),
+            "(Variable-BisAllylic?number=0 + 10.3 · Variable-BisAllylic?number=1 + 21.6 · \
+             Variable-MoreBisAllylic?number=2) / 100",
+        );
+    }
+
+    #[test]
+    fn examples() -> PolarsResult<()> {
+        for example in EXAMPLES.iter() {
+            let data_frame = example
+                .frame
+                .data_frame
+                .clone()
+                .lazy()
+                .select([col(STEREOSPECIFIC_NUMBERS123).arr().sum().sum()])
+                .collect()?;
+            let total = data_frame[STEREOSPECIFIC_NUMBERS123].f64()?.get(0);
+            assert!(
+                total.is_some_and(|total| (total - 1.0).abs() < 1e-9),
+                "{}: {total:?}",
+                example.name,
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use self::definitions::{EXAMPLES, INDICES, definition, value};
+
+use crate::{
+    app::{
+        computers::calculation::sum::properties::{
+            Computed as PropertiesComputed, Key as PropertiesKey,
+        },
+        states::calculation::settings::Indices,
+    },
+    r#const::EM_DASH,
+};
+use egui::{Grid, Response, RichText, ScrollArea, Ui, Widget};
+use egui_l20n::prelude::*;
+
+/// Index reference widget
+pub(crate) struct Reference<'a> {
+    name: &'a str,
+}
+
+impl<'a> Reference<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        Self { name }
+    }
+}
+
+impl Widget for Reference<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.vertical(|ui| {
+            ui.heading(ui.localize(self.name))
+                .on_hover_localized(&format!("{}.hover", self.name));
+            let Some(definition) = definition(self.name) else {
+                ui.label(EM_DASH);
+                return;
+            };
+            ui.separator();
+            // Formula
+            ui.label(RichText::new(ui.localize("Formula")).strong());
+            let formula = definition.formula.text(&|key: &str| ui.localize(key));
+            ui.label(RichText::new(formula).monospace());
+            // References
+            ui.label(RichText::new(ui.localize("Reference?PluralCategory=other")).strong());
+            if definition.references.is_empty() {
+                ui.label(EM_DASH);
+            }
+            for reference in definition.references {
+                ui.horizontal(|ui| {
+                    ui.label(ui.localize(reference.title));
+                    if let Some(doi) = reference.doi {
+                        ui.hyperlink_to(format!("doi:{doi}"), format!("https://doi.org/{doi}"));
+                    }
+                });
+            }
+            // Examples
+            ui.label(RichText::new(ui.localize("Example?PluralCategory=other")).strong())
+                .on_hover_localized("Example.hover");
+            Grid::new(ui.auto_id_with("Examples")).show(ui, |ui| {
+                for example in EXAMPLES.iter() {
+                    ui.label(ui.localize(example.name));
+                    let data_frame = ui.memory_mut(|memory| {
+                        memory
+                            .caches
+                            .cache::<PropertiesComputed>()
+                            .get(PropertiesKey {
+                                frame: &example.frame,
+                                ddof: 1,
+                                indices: &INDICES,
+                                precision: 2,
+                                significant: false,
+                                threshold_filter: false,
+                            })
+                    });
+                    match value(&data_frame, definition.name) {
+                        Ok(Some(value)) if value.is_finite() => ui.label(value.to_string()),
+                        _ => ui.label(EM_DASH),
+                    };
+                    ui.end_row();
+                }
+            });
+        })
+        .response
+    }
+}
+
+/// Index references browser widget
+pub(crate) struct References<'a> {
+    indices: &'a Indices,
+}
+
+impl<'a> References<'a> {
+    pub(crate) fn new(indices: &'a Indices) -> Self {
+        Self { indices }
+    }
+}
+
+impl Widget for References<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let id = ui.id().with("References");
+        let mut selected = ui
+            .data(|data| data.get_temp::<String>(id))
+            .or_else(|| Some(self.indices.first()?.name.clone()))
+            .unwrap_or_default();
+        let response = ui
+            .horizontal_top(|ui| {
+                ScrollArea::vertical()
+                    .id_salt("Indices")
+                    .auto_shrink([true, false])
+                    .show(ui, |ui| {
+                        ui.vertical(|ui| {
+                            for index in self.indices.iter() {
+                                let text = ui.localize(&index.name);
+                                ui.selectable_value(&mut selected, index.name.clone(), text);
+                            }
+                        });
+                    });
+                ui.separator();
+                ScrollArea::vertical()
+                    .id_salt("Reference")
+                    .show(ui, |ui| ui.add(Reference::new(&selected)));
+            })
+            .response;
+        ui.data_mut(|data| data.insert_temp(id, selected));
+        response
+    }
+}
+
+mod definitions;
@@ -1,6 +1,8 @@
 use crate::utils::{HashedDataFrame, HashedMetaDataFrame};
 use anyhow::Result;
+use lipid::prelude::*;
 use metadata::polars::MetaDataFrame;
+use serde::Deserialize;
 use std::sync::LazyLock;
 
 macro ron($name:literal) {
@@ -16,3 +18,43 @@ fn parse(bytes: &[u8]) -> Result<HashedMetaDataFrame> {
 }
 
 pub(crate) static CHRISTIE: LazyLock<HashedMetaDataFrame> = ron!("Christie.ron");
+
+/// Reference factors of the plant oils, only the cited ones
+pub(crate) static REFERENCE_FACTORS: LazyLock<Vec<Reference>> = LazyLock::new(|| {
+    ron::de::from_bytes(include_bytes!("Factors.ron")).expect("ron asset Factors.ron")
+});
+
+/// Reference ranges of the enrichment factor (SN-2 / SN-1,2,3) and the
+/// selectivity factor of the fatty acid
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct Reference {
+    pub(crate) name: String,
+    pub(crate) carbon: u8,
+    /// Indices of the cis double bounds
+    pub(crate) indices: Vec<u8>,
+    pub(crate) enrichment: (f64, f64),
+    pub(crate) selectivity: (f64, f64),
+    /// Source of the range
+    pub(crate) source: String,
+    pub(crate) doi: String,
+}
+
+impl Reference {
+    /// Whether the fatty acid is the reference one
+    ///
+    /// The indices of all double bounds must be known, triple and trans bounds
+    /// never match.
+    pub(crate) fn matches(&self, fatty_acid: &FattyAcid) -> bool {
+        fatty_acid.carbon == self.carbon
+            && fatty_acid.unsaturated.len() == self.indices.len()
+            && fatty_acid
+                .unsaturated
+                .iter()
+                .zip(&self.indices)
+                .all(|(unsaturated, &index)| {
+                    unsaturated.index == Some(index)
+                        && unsaturated.triple == Some(false)
+                        && unsaturated.parity != Some(true)
+                })
+    }
+}
@@ -1,30 +1,73 @@
 pub(crate) const EM_DASH: &str = "—";
 pub(crate) const NO_BREAK_SPACE: &str = " ";
 
+pub(crate) const CALCULATED_OXIDIZABILITY: &str = "CalculatedOxidizability";
 pub(crate) const CALCULATION: &str = "Calculation";
+pub(crate) const CARBON: &str = "Carbon";
+pub(crate) const COEFFICIENT_OF_DETERMINATION: &str = "CoefficientOfDetermination";
+pub(crate) const COMPONENT: &str = "Component";
+pub(crate) const CORRELATION: &str = "Correlation";
+pub(crate) const COUNT: &str = "Count";
+pub(crate) const CUMULATIVE: &str = "Cumulative";
+pub(crate) const DIFFERENCE: &str = "Difference";
+pub(crate) const EIGENVALUE: &str = "Eigenvalue";
 pub(crate) const ENRICHMENT: &str = "Enrichment";
+pub(crate) const EXPLAINED: &str = "Explained";
 pub(crate) const FACTOR: &str = "Factor";
 pub(crate) const FACTORS: &str = "Factors";
+pub(crate) const FILTERS: &str = "Filters";
 pub(crate) const GROUP: &str = "Group";
 pub(crate) const IODINE_VALUE: &str = "IodineValue";
 pub(crate) const KEY: &str = "Key";
 pub(crate) const KEYS: &str = "Keys";
+pub(crate) const LEFT: &str = "Left";
+pub(crate) const LOG_RATIO: &str = "LogRatio";
 pub(crate) const MASK: &str = "Mask";
 pub(crate) const MEAN: &str = "Mean";
+pub(crate) const MEASURED: &str = "Measured";
 pub(crate) const NAME: &str = "Name";
+pub(crate) const ONLY: &str = "Only";
+pub(crate) const ORDER: &str = "Order";
+pub(crate) const POSITION: &str = "Position";
+pub(crate) const P_VALUE: &str = "PValue";
+pub(crate) const PREDICTED: &str = "Predicted";
 pub(crate) const PROPERTIES: &str = "Properties";
+pub(crate) const Q_VALUE: &str = "QValue";
 pub(crate) const RELATIVE_ATOMIC_MASS: &str = "RelativeAtomicMass";
+pub(crate) const RELATIVE_DIFFERENCE: &str = "RelativeDifference";
+pub(crate) const RESIDUAL: &str = "Residual";
+pub(crate) const RIGHT: &str = "Right";
+pub(crate) const ROOT_MEAN_SQUARE_ERROR: &str = "RootMeanSquareError";
 pub(crate) const SAMPLE: &str = "Sample";
 pub(crate) const SELECTIVITY: &str = "Selectivity";
 pub(crate) const SPECIES: &str = "Species";
+pub(crate) const STACK: &str = "Stack";
 pub(crate) const STANDARD_DEVIATION: &str = "StandardDeviation";
+pub(crate) const STANDARD_ERROR: &str = "StandardError";
 pub(crate) const STANDARD: &str = "Standard";
 pub(crate) const STEREOSPECIFIC_NUMBERS: &str = "StereospecificNumbers";
 pub(crate) const THRESHOLD: &str = "Threshold";
+pub(crate) const UNSATURATION: &str = "Unsaturation";
 pub(crate) const VALUE: &str = "Value";
 pub(crate) const VALUES: &str = "Values";
 pub(crate) const TRIACYLGLYCEROLS: &str = "Triacylglycerols";
 
+/// Experimental design metadata keys
+pub(crate) mod design {
+    pub(crate) const BATCH: &str = "Batch";
+    pub(crate) const CULTIVAR: &str = "Cultivar";
+    pub(crate) const INSTRUMENT: &str = "Instrument";
+    pub(crate) const REPLICATE: &str = "Replicate";
+    pub(crate) const SPECIES: &str = "Species";
+    pub(crate) const TISSUE: &str = "Tissue";
+    pub(crate) const TREATMENT: &str = "Treatment";
+
+    /// Experimental design metadata keys in the display order
+    pub(crate) const KEYS: [&str; 7] = [
+        SPECIES, CULTIVAR, TISSUE, TREATMENT, REPLICATE, INSTRUMENT, BATCH,
+    ];
+}
+
 #[cfg(feature = "markdown")]
 #[rustfmt::skip]
 pub(crate) mod markdown {
@@ -53,11 +96,16 @@ pub(crate) mod markdown {
 
     // Biodiesel properties
     pub const CETANE_NUMBER: &str = asset!("/doc/en/Properties/CetaneNumber.md");
+    pub const CLOUD_POINT: &str = asset!("/doc/en/Properties/CloudPoint.md");
     pub const COLD_FILTER_PLUGGING_POINT: &str = asset!("/doc/en/Properties/ColdFilterPluggingPoint.md");
     pub const DEGREE_OF_UNSATURATION: &str = asset!("/doc/en/Properties/DegreeOfUnsaturation.md");
+    pub const DENSITY: &str = asset!("/doc/en/Properties/Density.md");
+    pub const HIGHER_HEATING_VALUE: &str = asset!("/doc/en/Properties/HigherHeatingValue.md");
     pub const IODINE_VALUE: &str = asset!("/doc/en/Properties/IodineValue.md");
+    pub const KINEMATIC_VISCOSITY: &str = asset!("/doc/en/Properties/KinematicViscosity.md");
     pub const LONG_CHAIN_SATURATED_FACTOR: &str = asset!("/doc/en/Properties/LongChainSaturatedFactor.md");
     pub const OXIDATION_STABILITY: &str = asset!("/doc/en/Properties/OxidationStability.md");
+    pub const POUR_POINT: &str = asset!("/doc/en/Properties/PourPoint.md");
 }
 
 #[rustfmt::skip]
@@ -65,6 +113,7 @@ pub(crate) mod relative_atomic_mass {
     use atom::prelude::isotopes::*;
 
     pub(crate) const C: f64 = C::Twelve.relative_atomic_mass().value;
+    pub(crate) const ELECTRON: f64 = 0.000_548_579_909;
     pub(crate) const H: f64 = H::One.relative_atomic_mass().value;
     pub(crate) const LI: f64 = Li::Seven.relative_atomic_mass().value;
     pub(crate) const N: f64 = N::Fourteen.relative_atomic_mass().value;
@@ -5,9 +5,9 @@ use rust_xlsxwriter::{Format, Table, TableColumn, Workbook, worksheet::Worksheet
 use tracing::instrument;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use self::native::save;
+pub use self::native::{save, save_sheets};
 #[cfg(target_arch = "wasm32")]
-pub use self::web::save;
+pub use self::web::{save, save_sheets};
 
 #[cfg(not(target_arch = "wasm32"))]
 mod native {
@@ -21,6 +21,16 @@ mod native {
         workbook.save(name)?;
         Ok(())
     }
+
+    #[instrument(skip(sheets), err)]
+    pub fn save_sheets(sheets: &[(&str, &DataFrame)], name: &str) -> Result<()> {
+        let mut workbook = Workbook::new();
+        for (sheet, data_frame) in sheets {
+            write(data_frame, workbook.add_worksheet().set_name(*sheet)?)?;
+        }
+        workbook.save(name)?;
+        Ok(())
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -39,6 +49,19 @@ mod web {
         }
         Ok(())
     }
+
+    #[instrument(skip(sheets), err)]
+    pub fn save_sheets(sheets: &[(&str, &DataFrame)], name: &str) -> Result<()> {
+        let mut workbook = Workbook::new();
+        for (sheet, data_frame) in sheets {
+            write(data_frame, workbook.add_worksheet().set_name(*sheet)?)?;
+        }
+        let buffer = workbook.save_to_buffer()?;
+        if let Err(error) = download(&buffer, XLSX, name) {
+            bail!("save: {error:?}");
+        }
+        Ok(())
+    }
 }
 
 fn write(data_frame: &DataFrame, worksheet: &mut Worksheet) -> Result<()> {
@@ -40,6 +40,7 @@ mod sources {
         asset!("/ftl/en/menu.ftl"),
         asset!("/ftl/en/names.ftl"),
         asset!("/ftl/en/properties.ftl"),
+        asset!("/ftl/en/references.ftl"),
     ];
 
     pub(super) const RU: &[&str] = &[
@@ -50,6 +51,7 @@ mod sources {
         asset!("/ftl/ru/menu.ftl"),
         asset!("/ftl/ru/names.ftl"),
         asset!("/ftl/ru/properties.ftl"),
+        asset!("/ftl/ru/references.ftl"),
         asset!("/ftl/ru/settings.ftl"),
     ];
 }
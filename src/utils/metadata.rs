@@ -1,7 +1,10 @@
-use crate::utils::HashedMetaDataFrame;
+use crate::{
+    r#const::design::{CULTIVAR, REPLICATE, SPECIES, TISSUE, TREATMENT},
+    utils::HashedMetaDataFrame,
+};
 use chrono::NaiveDate;
 use itertools::Itertools as _;
-use metadata::{AUTHORS, DATE, DESCRIPTION, NAME};
+use metadata::{AUTHORS, DATE, DESCRIPTION, Metadata, NAME};
 
 const DATE_FORMAT: &str = "%Y-%m-%d";
 
@@ -51,6 +54,150 @@ pub(crate) fn name(frames: &[HashedMetaDataFrame]) -> String {
     }
 }
 
+/// Name stem, the name without the replicate number (`Acer rubrum[2]` ->
+/// `Acer rubrum`)
+pub(crate) fn stem(name: &str) -> &str {
+    replicate(name).0
+}
+
+/// Name stem and replicate number (`Acer rubrum[2]` -> `Acer rubrum`, `2`)
+fn replicate(name: &str) -> (&str, Option<u32>) {
+    if let Some((stem, replicate)) = name
+        .strip_suffix(']')
+        .and_then(|prefix| prefix.rsplit_once('['))
+        && !replicate.is_empty()
+        && replicate.chars().all(|char| char.is_ascii_digit())
+        && let Ok(replicate) = replicate.parse()
+    {
+        return (stem.trim_end(), Some(replicate));
+    }
+    (name, None)
+}
+
+/// Treatments from the name (`C-70{NaCl;SN-2}` -> `NaCl`, `SN-2`)
+pub(crate) fn treatments(name: &str) -> Vec<&str> {
+    let Some((_, suffix)) = name.split_once('{') else {
+        return Vec::new();
+    };
+    let Some((treatments, _)) = suffix.rsplit_once('}') else {
+        return Vec::new();
+    };
+    treatments
+        .split(';')
+        .map(str::trim)
+        .filter(|treatment| !treatment.is_empty())
+        .collect()
+}
+
+/// Experimental design parsed from the name
+/// (`Helianthus annuus 'Peredovik'{Control;3day}[2]`)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Design<'a> {
+    pub(crate) species: &'a str,
+    pub(crate) cultivar: Option<&'a str>,
+    pub(crate) treatments: Vec<&'a str>,
+    pub(crate) replicate: Option<u32>,
+}
+
+impl<'a> Design<'a> {
+    pub(crate) fn parse(name: &'a str) -> Self {
+        let (stem, replicate) = self::replicate(name);
+        let prefix = stem.split_once('{').map_or(stem, |(prefix, _)| prefix);
+        let (species, cultivar) = match prefix
+            .split_once('\'')
+            .and_then(|(species, suffix)| Some((species, suffix.split_once('\'')?.0)))
+        {
+            Some((species, cultivar)) => (species.trim(), Some(cultivar.trim())),
+            None => (prefix.trim(), None),
+        };
+        Self {
+            species,
+            cultivar,
+            treatments: treatments(stem),
+            replicate,
+        }
+    }
+
+    /// Fills the empty experimental design fields of the metadata
+    pub(crate) fn fill(&self, meta: &mut Metadata) {
+        let mut fill = |key: &str, value: String| {
+            if !value.is_empty() && field(meta, key).is_none() {
+                meta.insert(key.to_owned(), value);
+            }
+        };
+        fill(SPECIES, self.species.to_owned());
+        fill(CULTIVAR, self.cultivar.unwrap_or_default().to_owned());
+        fill(TREATMENT, self.treatments.join(";"));
+        fill(
+            REPLICATE,
+            self.replicate
+                .map_or_else(String::new, |replicate| replicate.to_string()),
+        );
+    }
+}
+
+/// Non-empty value of the metadata field
+pub(crate) fn field<'a>(meta: &'a Metadata, key: &str) -> Option<&'a str> {
+    meta.get(key)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// Sample of the frame, the replicates are the same sample: the species,
+/// cultivar, tissue and treatment fields or, without them, the name stem
+pub(crate) fn sample(frame: &HashedMetaDataFrame) -> String {
+    let fields: Vec<_> = [SPECIES, CULTIVAR, TISSUE]
+        .into_iter()
+        .filter_map(|key| field(&frame.meta, key))
+        .collect();
+    if fields.is_empty() && field(&frame.meta, TREATMENT).is_none() {
+        return frame
+            .meta
+            .get(NAME)
+            .map_or_default(|name| stem(name).to_owned());
+    }
+    let mut sample = fields.join(" ");
+    if let Some(treatment) = field(&frame.meta, TREATMENT) {
+        sample.push_str(&format!("{{{treatment}}}"));
+    }
+    sample
+}
+
+/// Treatments of the frame: the treatment field (`;` separated) or, without
+/// it, the treatments from the name
+pub(crate) fn frame_treatments(frame: &HashedMetaDataFrame) -> Vec<&str> {
+    match field(&frame.meta, TREATMENT) {
+        Some(treatment) => treatment
+            .split(';')
+            .map(str::trim)
+            .filter(|treatment| !treatment.is_empty())
+            .collect(),
+        None => frame.meta.get(NAME).map_or_default(|name| treatments(name)),
+    }
+}
+
+/// Ordering value of the sample in the series: the number of the metadata
+/// value or, without the key, the first numeric treatment
+/// (`H-626{Control;3day}` -> `3`)
+pub(crate) fn ordering(frame: &HashedMetaDataFrame, key: Option<&str>) -> Option<f64> {
+    match key {
+        Some(key) => frame.meta.get(key).and_then(|value| number(value)),
+        None => frame_treatments(frame).into_iter().find_map(number),
+    }
+}
+
+/// Leading number of the value (`3day` -> `3`, `0,5 M` -> `0.5`)
+pub(crate) fn number(value: &str) -> Option<f64> {
+    let value = value.trim().replace(',', ".");
+    let end = value
+        .char_indices()
+        .find(|&(index, char)| {
+            !(char.is_ascii_digit() || char == '.' || (index == 0 && (char == '-' || char == '+')))
+        })
+        .map_or(value.len(), |(index, _)| index);
+    value[..end].parse().ok()
+}
+
 pub fn longest_common_prefix(strings: Vec<&str>) -> &str {
     if strings.is_empty() {
         return "";
@@ -68,3 +215,43 @@ pub fn longest_common_prefix(strings: Vec<&str>) -> &str {
     }
     prefix
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn design() {
+        assert_eq!(
+            Design::parse("Acer rubrum[2]"),
+            Design {
+                species: "Acer rubrum",
+                cultivar: None,
+                treatments: Vec::new(),
+                replicate: Some(2),
+            },
+        );
+        assert_eq!(
+            Design::parse("C-108{-N}[3]"),
+            Design {
+                species: "C-108",
+                cultivar: None,
+                treatments: vec!["-N"],
+                replicate: Some(3),
+            },
+        );
+        assert_eq!(
+            Design::parse("Helianthus annuus 'Peredovik'{Control;3day}"),
+            Design {
+                species: "Helianthus annuus",
+                cultivar: Some("Peredovik"),
+                treatments: vec!["Control", "3day"],
+                replicate: None,
+            },
+        );
+        assert_eq!(stem("Acer rubrum[a]"), "Acer rubrum[a]");
+        assert_eq!(number("0,5 M"), Some(0.5));
+        assert_eq!(number("3day"), Some(3.0));
+        assert_eq!(number("NaCl"), None);
+    }
+}
@@ -8,6 +8,7 @@ pub mod egui;
 pub mod hash;
 pub mod metadata;
 pub mod polars;
+pub mod statistics;
 pub mod ui;
 
 mod spawn;
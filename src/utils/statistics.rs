@@ -0,0 +1,331 @@
+use std::{
+    cmp::Ordering,
+    f64::consts::{PI, SQRT_2},
+};
+
+/// Two-sided p-value of the correlation coefficient `r` of `n` pairs
+/// (Student's t-test with `n - 2` degrees of freedom)
+pub(crate) fn correlation_p_value(r: f64, n: u32) -> Option<f64> {
+    if n < 3 || !r.is_finite() {
+        return None;
+    }
+    if r.abs() >= 1.0 {
+        return Some(0.0);
+    }
+    let degrees_of_freedom = (n - 2) as f64;
+    let t = r * (degrees_of_freedom / (1.0 - r * r)).sqrt();
+    Some(student_t_p_value(t, degrees_of_freedom))
+}
+
+/// Two-sided p-value of the Student's t distribution
+pub(crate) fn student_t_p_value(t: f64, degrees_of_freedom: f64) -> f64 {
+    let x = degrees_of_freedom / (degrees_of_freedom + t * t);
+    regularized_incomplete_beta(degrees_of_freedom / 2.0, 0.5, x)
+}
+
+/// Bonferroni correction
+pub(crate) fn bonferroni(p_values: &[Option<f64>]) -> Vec<Option<f64>> {
+    let m = p_values.iter().flatten().count() as f64;
+    p_values
+        .iter()
+        .map(|p_value| Some(((*p_value)? * m).min(1.0)))
+        .collect()
+}
+
+/// Benjamini–Hochberg correction (false discovery rate)
+pub(crate) fn benjamini_hochberg(p_values: &[Option<f64>]) -> Vec<Option<f64>> {
+    let mut indices: Vec<_> = p_values
+        .iter()
+        .enumerate()
+        .filter_map(|(index, p_value)| Some((index, (*p_value)?)))
+        .collect();
+    indices.sort_by(|(_, left), (_, right)| left.total_cmp(right));
+    let m = indices.len() as f64;
+    let mut q_values = vec![None; p_values.len()];
+    let mut minimum = 1.0f64;
+    for (rank, (index, p_value)) in indices.into_iter().enumerate().rev() {
+        minimum = minimum.min(p_value * m / (rank + 1) as f64);
+        q_values[index] = Some(minimum);
+    }
+    q_values
+}
+
+/// Least squares slope of the points
+pub(crate) fn slope(points: &[[f64; 2]]) -> Option<f64> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let x_mean = points.iter().map(|[x, _]| x).sum::<f64>() / n;
+    let y_mean = points.iter().map(|[_, y]| y).sum::<f64>() / n;
+    let (mut covariance, mut variance) = (0.0, 0.0);
+    for [x, y] in points {
+        covariance += (x - x_mean) * (y - y_mean);
+        variance += (x - x_mean).powi(2);
+    }
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Mann–Kendall trend test of the ordered values, Kendall's tau and the
+/// two-sided p-value (normal approximation with the tie and continuity
+/// corrections)
+pub(crate) fn mann_kendall(values: &[f64]) -> Option<(f64, f64)> {
+    let n = values.len();
+    if n < 3 {
+        return None;
+    }
+    let mut s = 0.0;
+    for i in 0..n {
+        for j in i + 1..n {
+            s += match values[j].total_cmp(&values[i]) {
+                Ordering::Greater => 1.0,
+                Ordering::Less => -1.0,
+                Ordering::Equal => 0.0,
+            };
+        }
+    }
+    let variance = |t: f64| t * (t - 1.0) * (2.0 * t + 5.0);
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let ties: f64 = sorted
+        .chunk_by(|left, right| left == right)
+        .map(|tie| variance(tie.len() as f64))
+        .sum();
+    let n = n as f64;
+    let tau = s / (n * (n - 1.0) / 2.0);
+    let standard_deviation = ((variance(n) - ties) / 18.0).sqrt();
+    if standard_deviation == 0.0 {
+        return Some((tau, 1.0));
+    }
+    let z = if s > 0.0 {
+        (s - 1.0) / standard_deviation
+    } else if s < 0.0 {
+        (s + 1.0) / standard_deviation
+    } else {
+        0.0
+    };
+    Some((tau, normal_p_value(z)))
+}
+
+/// Two-sided p-value of the standard normal distribution
+pub(crate) fn normal_p_value(z: f64) -> f64 {
+    erfc(z.abs() / SQRT_2).min(1.0)
+}
+
+/// Eigenvalues and eigenvectors of the symmetric matrix (cyclic Jacobi
+/// method), sorted by descending eigenvalues. The eigenvectors are the columns
+/// of the returned matrix.
+pub(crate) fn symmetric_eigen(mut matrix: Vec<Vec<f64>>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    const SWEEPS: usize = 100;
+
+    let n = matrix.len();
+    let mut vectors: Vec<Vec<f64>> = (0..n)
+        .map(|row| (0..n).map(|column| (row == column) as u8 as f64).collect())
+        .collect();
+    let norm: f64 = matrix.iter().flatten().map(|value| value * value).sum();
+    for _ in 0..SWEEPS {
+        let mut off = 0.0;
+        for p in 0..n {
+            for q in p + 1..n {
+                off += matrix[p][q] * matrix[p][q];
+            }
+        }
+        if off <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if matrix[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (kp, kq) = (matrix[k][p], matrix[k][q]);
+                    matrix[k][p] = c * kp - s * kq;
+                    matrix[k][q] = s * kp + c * kq;
+                }
+                for k in 0..n {
+                    let (pk, qk) = (matrix[p][k], matrix[q][k]);
+                    matrix[p][k] = c * pk - s * qk;
+                    matrix[q][k] = s * pk + c * qk;
+                }
+                for vector in &mut vectors {
+                    let (kp, kq) = (vector[p], vector[q]);
+                    vector[p] = c * kp - s * kq;
+                    vector[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+    let mut indices: Vec<_> = (0..n).collect();
+    indices.sort_by(|&left, &right| matrix[right][right].total_cmp(&matrix[left][left]));
+    let values = indices.iter().map(|&index| matrix[index][index]).collect();
+    let vectors = vectors
+        .iter()
+        .map(|vector| indices.iter().map(|&index| vector[index]).collect())
+        .collect();
+    (values, vectors)
+}
+
+/// Regularized incomplete beta function I_x(a, b)
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction of the incomplete beta function (modified Lentz's
+/// method)
+fn continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-14;
+    const ITERATIONS: usize = 256;
+    const MIN: f64 = 1e-300;
+
+    let clamp = |value: f64| if value.abs() < MIN { MIN } else { value };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..=ITERATIONS {
+        let m = m as f64;
+        // Even step
+        let numerator = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / clamp(1.0 + numerator * d);
+        c = clamp(1.0 + numerator / c);
+        h *= d * c;
+        // Odd step
+        let numerator = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / clamp(1.0 + numerator * d);
+        c = clamp(1.0 + numerator / c);
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Complementary error function (Chebyshev approximation, the fractional
+/// error is less than 1.2e-7)
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let value = t
+        * (-z * z - 1.265_512_23
+            + t * (1.000_023_68
+                + t * (0.374_091_96
+                    + t * (0.096_784_18
+                        + t * (-0.186_288_06
+                            + t * (0.278_868_07
+                                + t * (-1.135_203_98
+                                    + t * (1.488_515_87
+                                        + t * (-0.822_152_23 + t * 0.170_872_77)))))))))
+            .exp();
+    if x >= 0.0 { value } else { 2.0 - value }
+}
+
+/// Natural logarithm of the gamma function (Lanczos approximation)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let mut sum = COEFFICIENTS[0];
+    for (index, coefficient) in COEFFICIENTS.into_iter().enumerate().skip(1) {
+        sum += coefficient / (x + index as f64);
+    }
+    let t = x + 7.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn p_value() {
+        assert!((correlation_p_value(0.5, 10).unwrap() - 0.1411).abs() < 1e-4);
+        assert_eq!(correlation_p_value(1.0, 10), Some(0.0));
+        assert_eq!(correlation_p_value(0.5, 2), None);
+        assert!((student_t_p_value(0.0, 5.0) - 1.0).abs() < 1e-12);
+        assert!((regularized_incomplete_beta(2.0, 2.0, 0.5) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn trend() {
+        assert_eq!(slope(&[[0.0, 1.0], [1.0, 3.0], [2.0, 5.0]]), Some(2.0));
+        assert_eq!(slope(&[[1.0, 1.0], [1.0, 3.0]]), None);
+        let (tau, p_value) = mann_kendall(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(tau, 1.0);
+        assert!((p_value - 0.0275).abs() < 1e-4);
+        let (tau, p_value) = mann_kendall(&[2.0, 2.0, 2.0]).unwrap();
+        assert_eq!((tau, p_value), (0.0, 1.0));
+        assert_eq!(mann_kendall(&[1.0, 2.0]), None);
+        assert!((normal_p_value(1.959_964) - 0.05).abs() < 1e-6);
+    }
+
+    #[test]
+    fn eigen() {
+        let (values, vectors) = symmetric_eigen(vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+        assert!((values[0] - 3.0).abs() < 1e-12);
+        assert!((values[1] - 1.0).abs() < 1e-12);
+        assert!((vectors[0][0].abs() - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!((vectors[0][0] - vectors[1][0]).abs() < 1e-12);
+        let (values, _) = symmetric_eigen(vec![
+            vec![4.0, 1.0, 2.0],
+            vec![1.0, 3.0, 0.0],
+            vec![2.0, 0.0, 5.0],
+        ]);
+        assert!((values.iter().sum::<f64>() - 12.0).abs() < 1e-12);
+        assert!((values[0] - 6.669_079_088_282_286).abs() < 1e-9);
+    }
+
+    #[test]
+    fn correction() {
+        let p_values = [Some(0.01), Some(0.04), Some(0.03), None, Some(0.2)];
+        let q_values = benjamini_hochberg(&p_values);
+        let expected = [
+            Some(0.04),
+            Some(0.16 / 3.0),
+            Some(0.16 / 3.0),
+            None,
+            Some(0.2),
+        ];
+        for (q_value, expected) in q_values.into_iter().zip(expected) {
+            match (q_value, expected) {
+                (Some(q_value), Some(expected)) => assert!((q_value - expected).abs() < 1e-12),
+                (q_value, expected) => assert_eq!(q_value, expected),
+            }
+        }
+        assert_eq!(
+            bonferroni(&p_values),
+            [Some(0.04), Some(0.16), Some(0.12), None, Some(0.8)],
+        );
+    }
+}
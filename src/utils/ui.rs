@@ -1,4 +1,6 @@
-use egui::{Align, Color32, FontId, TextFormat, TextStyle, Ui, Visuals, text::LayoutJob};
+use egui::{
+    Align, Color32, FontId, TextFormat, TextStyle, Ui, Visuals, ecolor::Hsva, text::LayoutJob,
+};
 
 // struct CompositeText {
 //     color: Color32,
@@ -73,3 +75,10 @@ pub struct SubscriptedTextFormat {
     pub small_font_id: Option<FontId>,
     pub widget: bool,
 }
+
+/// Distinct color by index
+pub(crate) fn color(index: usize) -> Color32 {
+    // Golden ratio
+    let hue = (index as f32 * 0.618_034) % 1.0;
+    Hsva::new(hue, 0.85, 0.5, 1.0).into()
+}